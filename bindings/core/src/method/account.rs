@@ -13,13 +13,15 @@ use iota_sdk::{
         secret::GenerateAddressOptions,
     },
     types::block::{
-        output::{dto::OutputDto, OutputId, TokenId},
+        address::Bech32Address,
+        output::{dto::OutputDto, NftId, OutputId, TokenId},
         payload::transaction::TransactionId,
     },
     wallet::{
         account::{
-            CreateAliasParamsDto, FilterOptions, MintNativeTokenParamsDto, MintNftParamsDto, OutputParamsDto,
-            OutputsToClaim, SyncOptions, TransactionOptionsDto,
+            types::BalancePoint, CollectionNftParams, CreateAliasParamsDto, ExportOptions, FilterOptions,
+            HistoryOptions, InvoiceParams, Irc27Metadata, MintNativeTokenParamsDto, MintNftParamsDto, OutputParamsDto,
+            OutputsToClaim, ScheduledIntentKind, SyncOptions, TransactionOptionsDto,
         },
         SendAmountParams, SendNativeTokensParams, SendNftParams,
     },
@@ -31,6 +33,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "name", content = "data", rename_all = "camelCase")]
 pub enum AccountMethod {
+    /// Add a tag to an output of the account.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    AddOutputTag { output_id: OutputId, tag: String },
     /// List addresses.
     /// Expected response: [`Addresses`](crate::Response::Addresses)
     Addresses,
@@ -38,16 +44,44 @@ pub enum AccountMethod {
     /// Expected response:
     /// [`AddressesWithUnspentOutputs`](crate::Response::AddressesWithUnspentOutputs)
     AddressesWithUnspentOutputs,
+    /// Cancel a pending transaction by replacing it with a conflicting transaction that sends its inputs back to the
+    /// account.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    CancelTransaction {
+        transaction_id: TransactionId,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Claim outputs.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
     ClaimOutputs { output_ids_to_claim: Vec<OutputId> },
+    /// Lists the unspent NFTs of a collection.
+    /// Expected response: [`CollectionNfts`](crate::Response::CollectionNfts)
+    #[serde(rename_all = "camelCase")]
+    CollectionNfts { issuer_nft_id: NftId },
+    /// Create an invoice, outputs paying it are matched while syncing.
+    /// Expected response: [`Invoice`](crate::Response::Invoice)
+    CreateInvoice { params: InvoiceParams },
     /// Removes a previously registered participation event from local storage.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[serde(rename_all = "camelCase")]
     DeregisterParticipationEvent { event_id: ParticipationEventId },
+    /// Run input selection and the semantic validation for a transaction, without signing it or locking its inputs.
+    /// Expected response: [`TransactionDryRun`](crate::Response::TransactionDryRun)
+    DryRunTransaction {
+        outputs: Vec<OutputDto>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Execute the scheduled intents whose conditions are met.
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    ExecuteScheduledIntents,
+    /// Export every balance movement of the sent and received transactions of the account, one row per transaction
+    /// and token.
+    /// Expected response: [`ExportRows`](crate::Response::ExportRows)
+    ExportHistory { options: Option<ExportOptions> },
     /// Generate new Ed25519 addresses.
     /// Expected response: [`GeneratedEd25519Addresses`](crate::Response::GeneratedEd25519Addresses)
     GenerateEd25519Addresses {
@@ -61,11 +95,22 @@ pub enum AccountMethod {
     /// Expected response: [`Output`](crate::Response::Output)
     #[serde(rename_all = "camelCase")]
     GetFoundryOutput { token_id: TokenId },
+    /// Get the balance of the account at a past milestone or time.
+    /// Expected response: [`HistoricalBalance`](crate::Response::HistoricalBalance)
+    GetHistoricalBalance { point: BalancePoint },
     /// Get the transaction with inputs of an incoming transaction stored in the account
     /// List might not be complete, if the node pruned the data already
     /// Expected response: [`Transaction`](crate::Response::Transaction)
     #[serde(rename_all = "camelCase")]
     GetIncomingTransaction { transaction_id: TransactionId },
+    /// Get an invoice of the account.
+    /// Expected response: [`Invoice`](crate::Response::Invoice)
+    #[serde(rename_all = "camelCase")]
+    GetInvoice { invoice_id: u32 },
+    /// Get the payment URI of an invoice, to be shown as a QR code.
+    /// Expected response: [`InvoiceUri`](crate::Response::InvoiceUri)
+    #[serde(rename_all = "camelCase")]
+    GetInvoiceUri { invoice_id: u32 },
     /// Get the [`OutputData`](iota_sdk::wallet::account::types::OutputData) of an output stored in the account
     /// Expected response: [`OutputData`](crate::Response::OutputData)
     #[serde(rename_all = "camelCase")]
@@ -116,18 +161,44 @@ pub enum AccountMethod {
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     GetVotingPower,
+    /// Returns the history of the sent and received transactions of the account, newest first.
+    /// Expected response: [`History`](crate::Response::History)
+    History { options: Option<HistoryOptions> },
     /// Returns all incoming transactions of the account
     /// Expected response:
     /// [`Transactions`](crate::Response::Transactions)
     IncomingTransactions,
+    /// Returns the invoices of the account.
+    /// Expected response: [`Invoices`](crate::Response::Invoices)
+    Invoices,
+    /// Returns the address book and the labels of the account.
+    /// Expected response: [`Labels`](crate::Response::Labels)
+    Labels,
     /// Calculate the minimum required storage deposit for an output.
     /// Expected response:
     /// [`MinimumRequiredStorageDeposit`](crate::Response::MinimumRequiredStorageDeposit)
     MinimumRequiredStorageDeposit { output: OutputDto },
+    /// Mint the issuer NFT of a collection and wait until it's included.
+    /// Expected response: [`NftId`](crate::Response::NftId)
+    MintCollectionIssuer {
+        metadata: Box<Irc27Metadata>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Mint NFTs of a collection, split into as many transactions as needed.
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    #[serde(rename_all = "camelCase")]
+    MintCollectionNfts {
+        issuer_nft_id: NftId,
+        nfts: Vec<CollectionNftParams>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Returns all outputs of the account
     /// Expected response: [`OutputsData`](crate::Response::OutputsData)
     #[serde(rename_all = "camelCase")]
     Outputs { filter_options: Option<FilterOptions> },
+    /// Parses a payment URI and validates it against the protocol parameters of the network.
+    /// Expected response: [`PaymentUri`](crate::Response::PaymentUri)
+    ParsePaymentUri { uri: String },
     /// Returns all pending transactions of the account
     /// Expected response: [`Transactions`](crate::Response::Transactions)
     PendingTransactions,
@@ -257,6 +328,34 @@ pub enum AccountMethod {
     RegisterParticipationEvents {
         options: ParticipationEventRegistrationOptions,
     },
+    /// Remove a contact from the address book.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    RemoveContact { address: Bech32Address },
+    /// Remove an invoice.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    RemoveInvoice { invoice_id: u32 },
+    /// Remove a tag from an output of the account.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    RemoveOutputTag { output_id: OutputId, tag: String },
+    /// Remove a scheduled intent.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    RemoveScheduledIntent { intent_id: u32 },
+    /// Replace a pending transaction with a conflicting transaction that spends its inputs and creates the provided
+    /// outputs instead.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    ReplaceTransaction {
+        transaction_id: TransactionId,
+        outputs: Vec<OutputDto>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Send the transactions which conflicted because a transaction they depended on conflicted again, with new
+    /// inputs.
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    ReplanTransactions,
     /// Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
     /// included (referenced by a milestone). Returns the included block id.
     /// Expected response: [`BlockId`](crate::Response::BlockId)
@@ -269,6 +368,15 @@ pub enum AccountMethod {
         /// Maximum attempts
         max_attempts: Option<u64>,
     },
+    /// Schedule an intent which is executed once its conditions are met.
+    /// Expected response: [`ScheduledIntent`](crate::Response::ScheduledIntent)
+    ScheduleIntent { kind: ScheduledIntentKind },
+    /// Returns the scheduled intents of the account.
+    /// Expected response: [`ScheduledIntents`](crate::Response::ScheduledIntents)
+    ScheduledIntents,
+    /// Returns the contacts, address labels and output tags containing the query, ignoring the case.
+    /// Expected response: [`LabelMatches`](crate::Response::LabelMatches)
+    SearchLabels { query: String },
     /// Send amount.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     SendAmount {
@@ -281,9 +389,23 @@ pub enum AccountMethod {
         outputs: Vec<OutputDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Set the amount of unused addresses which are kept after the last used address of each chain, addresses are
+    /// generated during syncing to keep it. `None` disables it.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetAddressGapLimit { address_gap_limit: Option<u32> },
+    /// Set the label of an address of the account, `None` removes it.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetAddressLabel {
+        address: Bech32Address,
+        label: Option<String>,
+    },
     /// Set the alias of the account.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetAlias { alias: String },
+    /// Add a contact to the address book, or rename it if the address is already known.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetContact { address: Bech32Address, name: String },
     /// Set the fallback SyncOptions for account syncing.
     /// If storage is enabled, will persist during restarts.
     /// Expected response: [`Ok`](crate::Response::Ok)
@@ -14,16 +14,19 @@ use iota_sdk::{
         node_manager::node::NodeAuth,
         secret::SecretManagerDto,
     },
-    types::block::{
-        address::{Bech32Address, Hrp},
-        output::{
-            dto::{NativeTokenDto, TokenSchemeDto},
-            feature::dto::FeatureDto,
-            unlock_condition::dto::UnlockConditionDto,
-            AliasId, FoundryId, NftId, OutputId,
+    types::{
+        api::core::request::PruneDatabaseRequest,
+        block::{
+            address::{Bech32Address, Hrp},
+            output::{
+                dto::{NativeTokenDto, TokenSchemeDto},
+                feature::dto::FeatureDto,
+                unlock_condition::dto::UnlockConditionDto,
+                AliasId, FoundryId, NftId, OutputId,
+            },
+            payload::{dto::PayloadDto, milestone::MilestoneId, transaction::TransactionId},
+            BlockDto, BlockId,
         },
-        payload::{dto::PayloadDto, milestone::MilestoneId, transaction::TransactionId},
-        BlockDto, BlockId,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -124,6 +127,8 @@ pub enum ClientMethod {
     GetLocalPow,
     /// Get fallback to local proof of work timeout
     GetFallbackToLocalPow,
+    /// Returns the metrics of the latest submitted blocks
+    GetBlockSubmissionMetrics,
     /// Returns the unhealthy nodes.
     #[cfg(not(target_family = "wasm"))]
     UnhealthyNodes,
@@ -169,6 +174,44 @@ pub enum ClientMethod {
     GetInfo,
     /// Get peers
     GetPeers,
+    /// Get a peer by its ID
+    #[serde(rename_all = "camelCase")]
+    GetPeer {
+        /// Peer ID
+        peer_id: String,
+    },
+    /// Add a peer to the node
+    #[serde(rename_all = "camelCase")]
+    AddPeer {
+        /// Multiaddress of the peer
+        multi_address: String,
+        /// Alias of the peer
+        alias: Option<String>,
+    },
+    /// Remove a peer from the node
+    #[serde(rename_all = "camelCase")]
+    RemovePeer {
+        /// Peer ID
+        peer_id: String,
+    },
+    /// Prune the database of the node
+    PruneDatabase {
+        /// Pruning criterion
+        request: PruneDatabaseRequest,
+    },
+    /// Create a snapshot at the given milestone index
+    CreateSnapshots {
+        /// Milestone index
+        index: u32,
+    },
+    /// Exchange the dashboard credentials for a JWT
+    DashboardAuth {
+        /// Dashboard user
+        user: String,
+        /// Dashboard password
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+    },
     /// Get tips
     GetTips,
     /// Post block (JSON)
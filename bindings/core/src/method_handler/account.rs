@@ -15,9 +15,9 @@ use iota_sdk::{
     },
     wallet::{
         account::{
-            types::{BalanceDto, TransactionDto},
+            types::{BalanceDto, HistoricalBalanceDto, TransactionDto},
             Account, CreateAliasParams, OutputDataDto, OutputParams, PreparedMintTokenTransactionDto,
-            TransactionOptions,
+            TransactionDryRunDto, TransactionOptions,
         },
         MintNativeTokenParams, MintNftParams,
     },
@@ -28,6 +28,10 @@ use crate::{method::AccountMethod, Response, Result};
 
 pub(crate) async fn call_account_method_internal(account: &Account, method: AccountMethod) -> Result<Response> {
     let response = match method {
+        AccountMethod::AddOutputTag { output_id, tag } => {
+            account.add_output_tag(output_id, tag).await?;
+            Response::Ok
+        }
         AccountMethod::Addresses => {
             let addresses = account.addresses().await?;
             Response::Addresses(addresses)
@@ -36,15 +40,55 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let addresses = account.addresses_with_unspent_outputs().await?;
             Response::AddressesWithUnspentOutputs(addresses)
         }
+        AccountMethod::CancelTransaction {
+            transaction_id,
+            options,
+        } => {
+            let transaction = account
+                .cancel_transaction(
+                    &transaction_id,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
         AccountMethod::ClaimOutputs { output_ids_to_claim } => {
             let transaction = account.claim_outputs(output_ids_to_claim.to_vec()).await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::CollectionNfts { issuer_nft_id } => {
+            Response::CollectionNfts(account.collection_nfts(issuer_nft_id).await?)
+        }
+        AccountMethod::CreateInvoice { params } => {
+            let invoice = account.create_invoice(params).await?;
+            Response::Invoice(Some(Box::new(invoice)))
+        }
         #[cfg(feature = "participation")]
         AccountMethod::DeregisterParticipationEvent { event_id } => {
             account.deregister_participation_event(&event_id).await?;
             Response::Ok
         }
+        AccountMethod::DryRunTransaction { outputs, options } => {
+            let token_supply = account.client().get_token_supply().await?;
+            let dry_run = account
+                .dry_run_transaction(
+                    outputs
+                        .iter()
+                        .map(|o| Ok(Output::try_from_dto(o, token_supply)?))
+                        .collect::<Result<Vec<Output>>>()?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::TransactionDryRun(TransactionDryRunDto::from(&dry_run))
+        }
+        AccountMethod::ExecuteScheduledIntents => {
+            let transactions = account.execute_scheduled_intents().await?;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
+        AccountMethod::ExportHistory { options } => {
+            let rows = account.export_history(options.unwrap_or_default()).await?;
+            Response::ExportRows(rows)
+        }
         AccountMethod::GenerateEd25519Addresses { amount, options } => {
             let address = account.generate_ed25519_addresses(amount, options).await?;
             Response::GeneratedAccountAddresses(address)
@@ -54,6 +98,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let output = account.get_foundry_output(token_id).await?;
             Response::Output(OutputDto::from(&output))
         }
+        AccountMethod::GetHistoricalBalance { point } => {
+            let historical_balance = account.historical_balance(point).await?;
+            Response::HistoricalBalance(HistoricalBalanceDto::from(&historical_balance))
+        }
         AccountMethod::GetIncomingTransaction { transaction_id } => {
             let transaction = account.get_incoming_transaction(&transaction_id).await;
 
@@ -62,6 +110,16 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 |transaction| Response::Transaction(Some(Box::new(TransactionDto::from(&transaction)))),
             )
         }
+        AccountMethod::GetInvoice { invoice_id } => {
+            Response::Invoice(account.get_invoice(invoice_id).await.map(Box::new))
+        }
+        AccountMethod::GetInvoiceUri { invoice_id } => {
+            let invoice = account
+                .get_invoice(invoice_id)
+                .await
+                .ok_or_else(|| iota_sdk::wallet::Error::Invoice(format!("invoice {invoice_id} not found")))?;
+            Response::InvoiceUri(invoice.to_uri()?)
+        }
         AccountMethod::GetOutput { output_id } => {
             let output_data = account.get_output(&output_id).await;
             Response::OutputData(output_data.as_ref().map(OutputDataDto::from).map(Box::new))
@@ -106,10 +164,16 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let voting_power = account.get_voting_power().await?;
             Response::VotingPower(voting_power.to_string())
         }
+        AccountMethod::History { options } => {
+            let history = account.history(options.unwrap_or_default()).await?;
+            Response::History(history)
+        }
         AccountMethod::IncomingTransactions => {
             let transactions = account.incoming_transactions().await;
             Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
         }
+        AccountMethod::Invoices => Response::Invoices(account.invoices().await),
+        AccountMethod::Labels => Response::Labels(account.labels().await),
         AccountMethod::MinimumRequiredStorageDeposit { output } => {
             let output = Output::try_from_dto(&output, account.client().get_token_supply().await?)?;
             let rent_structure = account.client().get_rent_structure().await?;
@@ -118,10 +182,36 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
 
             Response::MinimumRequiredStorageDeposit(minimum_storage_deposit.to_string())
         }
+        AccountMethod::MintCollectionIssuer { metadata, options } => {
+            let nft_id = account
+                .mint_collection_issuer(
+                    *metadata,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::NftId(nft_id)
+        }
+        AccountMethod::MintCollectionNfts {
+            issuer_nft_id,
+            nfts,
+            options,
+        } => {
+            let transactions = account
+                .mint_collection_nfts(
+                    issuer_nft_id,
+                    nfts,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
         AccountMethod::Outputs { filter_options } => {
             let outputs = account.outputs(filter_options).await?;
             Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
         }
+        AccountMethod::ParsePaymentUri { uri } => {
+            Response::PaymentUri(Box::new(account.parse_payment_uri(&uri).await?))
+        }
         AccountMethod::PendingTransactions => {
             let transactions = account.pending_transactions().await;
             Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
@@ -294,6 +384,44 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let events = account.register_participation_events(&options).await?;
             Response::ParticipationEvents(events)
         }
+        AccountMethod::RemoveContact { address } => {
+            account.remove_contact(address).await?;
+            Response::Ok
+        }
+        AccountMethod::RemoveInvoice { invoice_id } => {
+            account.remove_invoice(invoice_id).await?;
+            Response::Ok
+        }
+        AccountMethod::RemoveOutputTag { output_id, tag } => {
+            account.remove_output_tag(output_id, &tag).await?;
+            Response::Ok
+        }
+        AccountMethod::RemoveScheduledIntent { intent_id } => {
+            account.remove_scheduled_intent(intent_id).await?;
+            Response::Ok
+        }
+        AccountMethod::ReplaceTransaction {
+            transaction_id,
+            outputs,
+            options,
+        } => {
+            let token_supply = account.client().get_token_supply().await?;
+            let transaction = account
+                .replace_transaction(
+                    &transaction_id,
+                    outputs
+                        .iter()
+                        .map(|o| Ok(Output::try_from_dto(o, token_supply)?))
+                        .collect::<Result<Vec<Output>>>()?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
+        AccountMethod::ReplanTransactions => {
+            let transactions = account.replan_transactions().await?;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
         AccountMethod::RetryTransactionUntilIncluded {
             transaction_id,
             interval,
@@ -304,6 +432,12 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::BlockId(block_id)
         }
+        AccountMethod::ScheduleIntent { kind } => {
+            let intent = account.schedule_intent(kind).await?;
+            Response::ScheduledIntent(intent)
+        }
+        AccountMethod::ScheduledIntents => Response::ScheduledIntents(account.scheduled_intents().await),
+        AccountMethod::SearchLabels { query } => Response::LabelMatches(account.search_labels(&query).await),
         AccountMethod::SendAmount { params, options } => {
             let transaction = account
                 .send_amount(
@@ -326,10 +460,22 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::SetAddressGapLimit { address_gap_limit } => {
+            account.set_address_gap_limit(address_gap_limit).await?;
+            Response::Ok
+        }
+        AccountMethod::SetAddressLabel { address, label } => {
+            account.set_address_label(address, label).await?;
+            Response::Ok
+        }
         AccountMethod::SetAlias { alias } => {
             account.set_alias(&alias).await?;
             Response::Ok
         }
+        AccountMethod::SetContact { address, name } => {
+            account.set_contact(address, name).await?;
+            Response::Ok
+        }
         AccountMethod::SetDefaultSyncOptions { options } => {
             account.set_default_sync_options(options).await?;
             Response::Ok
@@ -225,6 +225,9 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         }
         ClientMethod::GetLocalPow => Response::Bool(client.get_local_pow().await),
         ClientMethod::GetFallbackToLocalPow => Response::Bool(client.get_fallback_to_local_pow().await),
+        ClientMethod::GetBlockSubmissionMetrics => {
+            Response::BlockSubmissionMetrics(client.get_block_submission_metrics().await)
+        }
         ClientMethod::PrepareTransaction {
             secret_manager,
             options,
@@ -286,6 +289,17 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::GetNodeInfo { url, auth } => Response::NodeInfo(Client::get_node_info(&url, auth).await?),
         ClientMethod::GetInfo => Response::Info(client.get_info().await?),
         ClientMethod::GetPeers => Response::Peers(client.get_peers().await?),
+        ClientMethod::GetPeer { peer_id } => Response::Peer(client.get_peer(&peer_id).await?),
+        ClientMethod::AddPeer { multi_address, alias } => Response::Peer(client.add_peer(multi_address, alias).await?),
+        ClientMethod::RemovePeer { peer_id } => {
+            client.remove_peer(&peer_id).await?;
+            Response::Ok
+        }
+        ClientMethod::PruneDatabase { request } => Response::PruneDatabase(client.prune_database(request).await?),
+        ClientMethod::CreateSnapshots { index } => Response::CreateSnapshots(client.create_snapshots(index).await?),
+        ClientMethod::DashboardAuth { user, password } => {
+            Response::DashboardAuth(client.dashboard_auth(user, password).await?)
+        }
         ClientMethod::GetTips => Response::Tips(client.get_tips().await?),
         ClientMethod::PostBlockRaw { block_bytes } => Response::BlockId(
             client
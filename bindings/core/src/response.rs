@@ -9,7 +9,7 @@ use derivative::Derivative;
 use iota_sdk::client::secret::LedgerNanoStatus;
 use iota_sdk::{
     client::{
-        api::{PreparedTransactionDataDto, SignedTransactionDataDto},
+        api::{BlockSubmissionMetrics, PreparedTransactionDataDto, SignedTransactionDataDto},
         node_manager::node::Node,
         NetworkInfoDto, NodeInfoWrapper,
     },
@@ -18,7 +18,8 @@ use iota_sdk::{
             core::{
                 dto::{PeerDto, ReceiptDto},
                 response::{
-                    BlockMetadataResponse, InfoResponse as NodeInfo, OutputWithMetadataResponse, TreasuryResponse,
+                    BlockMetadataResponse, CreateSnapshotsResponse, DashboardAuthResponse, InfoResponse as NodeInfo,
+                    OutputWithMetadataResponse, PruneDatabaseResponse, TreasuryResponse,
                     UtxoChangesResponse as MilestoneUTXOChanges,
                 },
             },
@@ -43,8 +44,12 @@ use iota_sdk::{
     },
     wallet::{
         account::{
-            types::{AccountAddress, AddressWithUnspentOutputs, BalanceDto, OutputDataDto, TransactionDto},
-            PreparedMintTokenTransactionDto,
+            types::{
+                AccountAddress, AddressWithUnspentOutputs, BalanceDto, HistoricalBalanceDto, OutputDataDto,
+                TransactionDto,
+            },
+            CollectionNft, ExportRow, HistoryPage, Invoice, LabelMatch, Labels, PaymentUri,
+            PreparedMintTokenTransactionDto, ScheduledIntent, TransactionDryRunDto,
         },
         message_interface::dtos::AccountDetailsDto,
     },
@@ -89,6 +94,9 @@ pub enum Response {
     /// - [`GetTipsInterval`](crate::method::ClientMethod::GetTipsInterval)
     TipsInterval(u64),
     /// Response for:
+    /// - [`GetBlockSubmissionMetrics`](crate::method::ClientMethod::GetBlockSubmissionMetrics)
+    BlockSubmissionMetrics(Vec<BlockSubmissionMetrics>),
+    /// Response for:
     /// - [`GetProtocolParameters`](crate::method::ClientMethod::GetProtocolParameters)
     ProtocolParameters(ProtocolParametersDto),
     /// Response for:
@@ -121,6 +129,19 @@ pub enum Response {
     /// - [`GetPeers`](crate::method::ClientMethod::GetPeers)
     Peers(Vec<PeerDto>),
     /// Response for:
+    /// - [`GetPeer`](crate::method::ClientMethod::GetPeer)
+    /// - [`AddPeer`](crate::method::ClientMethod::AddPeer)
+    Peer(PeerDto),
+    /// Response for:
+    /// - [`PruneDatabase`](crate::method::ClientMethod::PruneDatabase)
+    PruneDatabase(PruneDatabaseResponse),
+    /// Response for:
+    /// - [`CreateSnapshots`](crate::method::ClientMethod::CreateSnapshots)
+    CreateSnapshots(CreateSnapshotsResponse),
+    /// Response for:
+    /// - [`DashboardAuth`](crate::method::ClientMethod::DashboardAuth)
+    DashboardAuth(DashboardAuthResponse),
+    /// Response for:
     /// - [`GetTips`](crate::method::ClientMethod::GetTips)
     Tips(Vec<BlockId>),
     /// Response for:
@@ -216,6 +237,7 @@ pub enum Response {
     AliasId(AliasId),
     /// Response for:
     /// - [`ComputeNftId`](crate::method::UtilsMethod::ComputeNftId)
+    /// - [`MintCollectionIssuer`](crate::method::AccountMethod::MintCollectionIssuer)
     NftId(NftId),
     /// Response for:
     /// - [`ComputeFoundryId`](crate::method::UtilsMethod::ComputeFoundryId)
@@ -273,6 +295,12 @@ pub enum Response {
     /// - [`ClearStrongholdPassword`](crate::method::WalletMethod::ClearStrongholdPassword),
     /// - [`DeregisterParticipationEvent`](crate::method::AccountMethod::DeregisterParticipationEvent),
     /// - [`SetAlias`](crate::method::AccountMethod::SetAlias),
+    /// - [`SetContact`](crate::method::AccountMethod::SetContact),
+    /// - [`RemoveContact`](crate::method::AccountMethod::RemoveContact),
+    /// - [`RemoveInvoice`](crate::method::AccountMethod::RemoveInvoice),
+    /// - [`SetAddressLabel`](crate::method::AccountMethod::SetAddressLabel),
+    /// - [`AddOutputTag`](crate::method::AccountMethod::AddOutputTag),
+    /// - [`RemoveOutputTag`](crate::method::AccountMethod::RemoveOutputTag),
     /// - [`SetDefaultSyncOptions`](crate::method::AccountMethod::SetDefaultSyncOptions),
     /// - [`RestoreBackup`](crate::method::WalletMethod::RestoreBackup),
     /// - [`SetClientOptions`](crate::method::WalletMethod::SetClientOptions),
@@ -284,6 +312,7 @@ pub enum Response {
     /// - [`EmitTestEvent`](crate::method::WalletMethod::EmitTestEvent),
     /// - [`ClearListeners`](crate::method::WalletMethod::ClearListeners)
     /// - [`StoreMnemonic`](crate::method::WalletMethod::StoreMnemonic)
+    /// - [`RemovePeer`](crate::method::ClientMethod::RemovePeer)
     Ok,
     /// Response for any method that returns an error.
     Error(Error),
@@ -343,8 +372,48 @@ pub enum Response {
     /// - [`Transactions`](crate::method::AccountMethod::Transactions),
     /// - [`PendingTransactions`](crate::method::AccountMethod::PendingTransactions),
     /// - [`IncomingTransactions`](crate::method::AccountMethod::IncomingTransactions)
+    /// - [`ExecuteScheduledIntents`](crate::method::AccountMethod::ExecuteScheduledIntents)
+    /// - [`ReplanTransactions`](crate::method::AccountMethod::ReplanTransactions)
+    /// - [`MintCollectionNfts`](crate::method::AccountMethod::MintCollectionNfts)
     Transactions(Vec<TransactionDto>),
     /// Response for
+    /// - [`DryRunTransaction`](crate::method::AccountMethod::DryRunTransaction)
+    TransactionDryRun(TransactionDryRunDto),
+    /// Response for
+    /// - [`History`](crate::method::AccountMethod::History)
+    History(HistoryPage),
+    /// Response for
+    /// - [`ExportHistory`](crate::method::AccountMethod::ExportHistory)
+    ExportRows(Vec<ExportRow>),
+    /// Response for
+    /// - [`CollectionNfts`](crate::method::AccountMethod::CollectionNfts)
+    CollectionNfts(Vec<CollectionNft>),
+    /// Response for
+    /// - [`Labels`](crate::method::AccountMethod::Labels)
+    Labels(Labels),
+    /// Response for
+    /// - [`SearchLabels`](crate::method::AccountMethod::SearchLabels)
+    LabelMatches(Vec<LabelMatch>),
+    /// Response for
+    /// - [`CreateInvoice`](crate::method::AccountMethod::CreateInvoice),
+    /// - [`GetInvoice`](crate::method::AccountMethod::GetInvoice)
+    Invoice(Option<Box<Invoice>>),
+    /// Response for
+    /// - [`Invoices`](crate::method::AccountMethod::Invoices)
+    Invoices(Vec<Invoice>),
+    /// Response for
+    /// - [`GetInvoiceUri`](crate::method::AccountMethod::GetInvoiceUri)
+    InvoiceUri(String),
+    /// Response for
+    /// - [`ParsePaymentUri`](crate::method::AccountMethod::ParsePaymentUri)
+    PaymentUri(Box<PaymentUri>),
+    /// Response for
+    /// - [`ScheduleIntent`](crate::method::AccountMethod::ScheduleIntent)
+    ScheduledIntent(ScheduledIntent),
+    /// Response for
+    /// - [`ScheduledIntents`](crate::method::AccountMethod::ScheduledIntents)
+    ScheduledIntents(Vec<ScheduledIntent>),
+    /// Response for
     /// - [`SignTransactionEssence`](crate::method::AccountMethod::SignTransactionEssence)
     SignedTransactionData(SignedTransactionDataDto),
     /// GenerateAddress response.
@@ -355,7 +424,12 @@ pub enum Response {
     /// - [`Sync`](crate::method::AccountMethod::Sync)
     Balance(BalanceDto),
     /// Response for
+    /// - [`GetHistoricalBalance`](crate::method::AccountMethod::GetHistoricalBalance)
+    HistoricalBalance(HistoricalBalanceDto),
+    /// Response for
+    /// - [`CancelTransaction`](crate::method::AccountMethod::CancelTransaction)
     /// - [`ClaimOutputs`](crate::method::AccountMethod::ClaimOutputs)
+    /// - [`ReplaceTransaction`](crate::method::AccountMethod::ReplaceTransaction)
     /// - [`SendAmount`](crate::method::AccountMethod::SendAmount)
     /// - [`SendOutputs`](crate::method::AccountMethod::SendOutputs)
     /// - [`SignAndSubmitTransaction`](crate::method::AccountMethod::SignAndSubmitTransaction)
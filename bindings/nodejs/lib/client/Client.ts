@@ -19,6 +19,7 @@ import type {
     AliasQueryParameter,
     OutputIdsResponse,
     IBip32Chain,
+    IBlockSubmissionMetrics,
 } from '../types/client';
 import type {
     IUTXOInput,
@@ -387,6 +388,17 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the metrics of the latest submitted blocks.
+     */
+    async getBlockSubmissionMetrics(): Promise<IBlockSubmissionMetrics[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getBlockSubmissionMetrics',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get health of node by input url.
      */
@@ -427,6 +439,101 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get a peer by its ID.
+     */
+    async getPeer(peerId: string): Promise<IPeer> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getPeer',
+            data: {
+                peerId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Add a peer to the node. Requires a JWT for the protected routes of the node.
+     */
+    async addPeer(multiAddress: string, alias?: string): Promise<IPeer> {
+        const response = await this.methodHandler.callMethod({
+            name: 'addPeer',
+            data: {
+                multiAddress,
+                alias,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Remove a peer from the node. Requires a JWT for the protected routes of the node.
+     */
+    async removePeer(peerId: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'removePeer',
+            data: {
+                peerId,
+            },
+        });
+    }
+
+    /**
+     * Prune the database of the node, exactly one criterion has to be provided.
+     * Requires a JWT for the protected routes of the node.
+     */
+    async pruneDatabase(
+        request:
+            | { index: number }
+            | { depth: number }
+            | { targetDatabaseSize: string },
+    ): Promise<{ index: number }> {
+        const response = await this.methodHandler.callMethod({
+            name: 'pruneDatabase',
+            data: {
+                request,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Create a snapshot at the given milestone index. Requires a JWT for the protected routes of the node.
+     */
+    async createSnapshots(
+        index: number,
+    ): Promise<{ index: number; filePath: string }> {
+        const response = await this.methodHandler.callMethod({
+            name: 'createSnapshots',
+            data: {
+                index,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Exchange the dashboard credentials for a JWT for the protected routes of the node.
+     */
+    async dashboardAuth(
+        user: string,
+        password: string,
+    ): Promise<{ jwt: string }> {
+        const response = await this.methodHandler.callMethod({
+            name: 'dashboardAuth',
+            data: {
+                user,
+                password,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Post block as raw bytes, returns the block ID.
      */
@@ -161,6 +161,10 @@ export interface __GetFallbackToLocalPowMethod__ {
     name: 'getFallbackToLocalPow';
 }
 
+export interface __GetBlockSubmissionMetricsMethod__ {
+    name: 'getBlockSubmissionMetrics';
+}
+
 export interface __GetHealthMethod__ {
     name: 'getHealth';
     data: {
@@ -180,6 +184,53 @@ export interface __GetPeersMethod__ {
     name: 'getPeers';
 }
 
+export interface __GetPeerMethod__ {
+    name: 'getPeer';
+    data: {
+        peerId: string;
+    };
+}
+
+export interface __AddPeerMethod__ {
+    name: 'addPeer';
+    data: {
+        multiAddress: string;
+        alias?: string;
+    };
+}
+
+export interface __RemovePeerMethod__ {
+    name: 'removePeer';
+    data: {
+        peerId: string;
+    };
+}
+
+export interface __PruneDatabaseMethod__ {
+    name: 'pruneDatabase';
+    data: {
+        request:
+            | { index: number }
+            | { depth: number }
+            | { targetDatabaseSize: string };
+    };
+}
+
+export interface __CreateSnapshotsMethod__ {
+    name: 'createSnapshots';
+    data: {
+        index: number;
+    };
+}
+
+export interface __DashboardAuthMethod__ {
+    name: 'dashboardAuth';
+    data: {
+        user: string;
+        password: string;
+    };
+}
+
 export interface __PostBlockRawMethod__ {
     name: 'postBlockRaw';
     data: {
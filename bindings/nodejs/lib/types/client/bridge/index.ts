@@ -22,9 +22,16 @@ import type {
     __GetProtocolParametersMethod__,
     __GetLocalPowMethod__,
     __GetFallbackToLocalPowMethod__,
+    __GetBlockSubmissionMetricsMethod__,
     __GetHealthMethod__,
     __GetNodeInfoMethod__,
     __GetPeersMethod__,
+    __GetPeerMethod__,
+    __AddPeerMethod__,
+    __RemovePeerMethod__,
+    __PruneDatabaseMethod__,
+    __CreateSnapshotsMethod__,
+    __DashboardAuthMethod__,
     __PostBlockRawMethod__,
     __GetBlockRawMethod__,
     __GetReceiptsMethod__,
@@ -90,9 +97,16 @@ export type __ClientMethods__ =
     | __GetProtocolParametersMethod__
     | __GetLocalPowMethod__
     | __GetFallbackToLocalPowMethod__
+    | __GetBlockSubmissionMetricsMethod__
     | __GetHealthMethod__
     | __GetNodeInfoMethod__
     | __GetPeersMethod__
+    | __GetPeerMethod__
+    | __AddPeerMethod__
+    | __RemovePeerMethod__
+    | __PruneDatabaseMethod__
+    | __CreateSnapshotsMethod__
+    | __DashboardAuthMethod__
     | __PostBlockRawMethod__
     | __GetBlockRawMethod__
     | __GetMilestoneByIdMethod__
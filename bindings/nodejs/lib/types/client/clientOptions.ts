@@ -1,6 +1,12 @@
 // Copyright 2021-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
-import type { IMqttBrokerOptions, INetworkInfo, INode } from './network';
+import type {
+    IMqttBrokerOptions,
+    INetworkInfo,
+    INode,
+    IProxyOptions,
+    ITlsOptions,
+} from './network';
 
 /** Options for the client builder */
 export interface IClientOptions {
@@ -34,6 +40,41 @@ export interface IClientOptions {
     powWorkerCount?: number;
     /** Whether the PoW should be done locally or remotely. */
     localPow?: boolean;
+    /** Proxy through which all requests are sent, unless a node provides its own */
+    proxy?: IProxyOptions;
+    /** TLS options for all requests, unless a node provides its own */
+    tls?: ITlsOptions;
+    /** Options for building and posting blocks */
+    blockSubmissionOptions?: IBlockSubmissionOptions;
+}
+
+/** Options for building and posting blocks */
+export interface IBlockSubmissionOptions {
+    /** How the proof of work is done, `Configured` uses the local PoW option */
+    powStrategy?: 'Configured' | 'Local' | 'Remote' | 'Fastest';
+    /** How the parents are chosen, if they're not provided */
+    parentsStrategy?:
+        | { strategy: 'NodeTips' }
+        | { strategy: 'OwnBlocks' }
+        | { strategy: 'Custom'; value: string[] };
+    /** How often a block is built again with new tips, if the node rejects its parents as too old */
+    maxRetips?: number;
+}
+
+/** Metrics of a submitted block */
+export interface IBlockSubmissionMetrics {
+    blockId: string;
+    /** The parents the block was built with, the node replaces them when it does the proof of work */
+    parents: string[];
+    localPow: boolean;
+    /** Milliseconds it took to build the block, including local proof of work */
+    buildDuration: number;
+    /** Milliseconds it took to post the block, including remote proof of work */
+    postDuration: number;
+    /** How often the block was built again with new tips */
+    retips: number;
+    /** Milliseconds since the unix epoch when the block was posted */
+    timestamp: number;
 }
 
 /** Time duration */
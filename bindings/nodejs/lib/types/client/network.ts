@@ -19,6 +19,27 @@ export interface IAuth {
     basicAuthNamePwd?: [string, string];
 }
 
+/**
+ * Proxy through which the requests to a node are sent.
+ */
+export interface IProxyOptions {
+    /** Proxy url, `http://`, `https://` or `socks5://` */
+    url: string;
+    basicAuthNamePwd?: [string, string];
+}
+
+/**
+ * TLS options for the connection to a node.
+ */
+export interface ITlsOptions {
+    /** PEM encoded root certificates that are trusted in addition to the built-in ones */
+    rootCertificates?: string[];
+    /** If only the provided root certificates should be trusted */
+    disableBuiltInRootCertificates?: boolean;
+    /** Path to a PEM file with the client certificate and private key, used for mutual TLS */
+    clientIdentityPath?: string;
+}
+
 /**
  * Options for the MQTT broker.
  */
@@ -29,6 +50,12 @@ export interface IMqttBrokerOptions {
     useWs?: boolean;
     port?: number;
     maxReconnectionAttempts?: number;
+    /** delay before the first reconnection attempt, doubled after each failed attempt up to one minute */
+    reconnectionBackoff?: IDuration;
+    /** fetch the `milestones` and `outputs/*` events missed while disconnected from the REST API */
+    backfill?: boolean;
+    /** maximum number of milestones to backfill after a reconnection, 0 is unlimited */
+    maxBackfillMilestones?: number;
 }
 
 /**
@@ -38,6 +65,8 @@ export interface INode {
     url: string;
     auth?: IAuth;
     disabled?: boolean;
+    proxy?: IProxyOptions;
+    tls?: ITlsOptions;
 }
 
 /**
@@ -21,9 +21,9 @@ export interface IPreparedTransactionData {
      */
     inputsData: IInputSigningData[];
     /**
-     * Optional remainder output information
+     * Remainder output information, the remainder holding the native tokens comes first
      */
-    remainder?: IRemainder;
+    remainders: IRemainder[];
 }
 
 /**
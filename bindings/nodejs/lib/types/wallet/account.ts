@@ -61,6 +61,31 @@ export interface NativeTokenBalance {
     available: HexEncodedAmount;
 }
 
+/** A past point of the ledger */
+export type BalancePoint =
+    | {
+          type: 'milestone';
+          /** Right after the milestone with this index was confirmed */
+          index: number;
+      }
+    | {
+          type: 'timestamp';
+          /** Seconds since the unix epoch, like milestone timestamps */
+          timestampSeconds: number;
+      };
+
+/** The balance of an account at a past point */
+export interface HistoricalBalance {
+    /** The point of the balance */
+    point: BalancePoint;
+    /** The balance, reconstructed from the outputs of the account booked before and spent after the point */
+    balance: Balance;
+    /** Spent outputs booked before the point, whose spending milestone is unknown because their metadata was pruned */
+    unknownSpentOutputs: string[];
+    /** The point is before the first sync of the account, outputs which were spent and pruned before aren't known */
+    beforeFirstSync: boolean;
+}
+
 /** Sync options for an account */
 export interface SyncOptions {
     /**
@@ -99,6 +124,12 @@ export interface SyncOptions {
     syncOnlyMostBasicOutputs?: boolean;
     /** Sync native token foundries, so their metadata can be returned in the balance. Default: false. */
     syncNativeTokenFoundries?: boolean;
+    /** Only apply the UTXO changes of the milestones confirmed since the last sync of all addresses, instead of requesting the outputs of every address. Falls back to a full sync if needed. Default: false. */
+    incremental?: boolean;
+    /** Maximum amount of milestones whose UTXO changes are applied by an incremental sync, otherwise a full sync is done. Default: 30. */
+    incrementalMaxMilestones?: number;
+    /** Send transactions which conflicted because a transaction they depended on conflicted again, after each sync of the background syncing. Default: false. */
+    replanTransactions?: boolean;
 }
 
 /** Specifies what outputs should be synced for the ed25519 addresses from the account. */
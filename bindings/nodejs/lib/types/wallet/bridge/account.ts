@@ -1,5 +1,5 @@
 import type { HexEncodedAmount } from '@iota/types';
-import type { SyncOptions, FilterOptions } from '../account';
+import type { BalancePoint, SyncOptions, FilterOptions } from '../account';
 import type {
     SendAmountParams,
     SendNativeTokensParams,
@@ -13,8 +13,13 @@ import type {
     BuildNftOutputData,
 } from '../buildOutputData';
 import type { INode, IPreparedTransactionData } from '../../client';
+import type { ExportOptions } from '../export';
+import type { HistoryOptions } from '../history';
+import type { InvoiceParams } from '../invoice';
+import type { CollectionNftParams, Irc27Metadata } from '../nftCollection';
 import type { OutputParams } from '../outputParams';
 import type { OutputsToClaim } from '../output';
+import type { ScheduledIntentKind } from '../scheduledIntent';
 import type { SignedTransactionEssence } from '../signedTransactionEssence';
 import type {
     AliasOutputParams,
@@ -58,6 +63,14 @@ export type __PrepareBurnMethod__ = {
     };
 };
 
+export type __CancelTransactionMethod__ = {
+    name: 'cancelTransaction';
+    data: {
+        transactionId: string;
+        options?: TransactionOptions;
+    };
+};
+
 export type __ClaimOutputsMethod__ = {
     name: 'claimOutputs';
     data: {
@@ -65,6 +78,20 @@ export type __ClaimOutputsMethod__ = {
     };
 };
 
+export type __CollectionNftsMethod__ = {
+    name: 'collectionNfts';
+    data: {
+        issuerNftId: string;
+    };
+};
+
+export type __CreateInvoiceMethod__ = {
+    name: 'createInvoice';
+    data: {
+        params: InvoiceParams;
+    };
+};
+
 export type __PrepareConsolidateOutputsMethod__ = {
     name: 'prepareConsolidateOutputs';
     data: {
@@ -97,6 +124,25 @@ export type __DeregisterParticipationEventMethod__ = {
     };
 };
 
+export type __DryRunTransactionMethod__ = {
+    name: 'dryRunTransaction';
+    data: {
+        outputs: Output[];
+        options?: TransactionOptions;
+    };
+};
+
+export type __ExecuteScheduledIntentsMethod__ = {
+    name: 'executeScheduledIntents';
+};
+
+export type __ExportHistoryMethod__ = {
+    name: 'exportHistory';
+    data: {
+        options?: ExportOptions;
+    };
+};
+
 export type __GenerateEd25519AddressesMethod__ = {
     name: 'generateEd25519Addresses';
     data: {
@@ -109,6 +155,13 @@ export type __GetBalanceMethod__ = {
     name: 'getBalance';
 };
 
+export type __GetHistoricalBalanceMethod__ = {
+    name: 'getHistoricalBalance';
+    data: {
+        point: BalancePoint;
+    };
+};
+
 export type __GetIncomingTransactionMethod__ = {
     name: 'getIncomingTransaction';
     data: {
@@ -116,6 +169,20 @@ export type __GetIncomingTransactionMethod__ = {
     };
 };
 
+export type __GetInvoiceMethod__ = {
+    name: 'getInvoice';
+    data: {
+        invoiceId: number;
+    };
+};
+
+export type __GetInvoiceUriMethod__ = {
+    name: 'getInvoiceUri';
+    data: {
+        invoiceId: number;
+    };
+};
+
 export type __GetOutputMethod__ = {
     name: 'getOutput';
     data: {
@@ -144,6 +211,14 @@ export type __GetTransactionMethod__ = {
     };
 };
 
+export type __AddOutputTagMethod__ = {
+    name: 'addOutputTag';
+    data: {
+        outputId: string;
+        tag: string;
+    };
+};
+
 export type __AddressesMethod__ = {
     name: 'addresses';
 };
@@ -159,14 +234,36 @@ export type __OutputsMethod__ = {
     };
 };
 
+export type __ParsePaymentUriMethod__ = {
+    name: 'parsePaymentUri';
+    data: {
+        uri: string;
+    };
+};
+
 export type __PendingTransactionsMethod__ = {
     name: 'pendingTransactions';
 };
 
+export type __HistoryMethod__ = {
+    name: 'history';
+    data: {
+        options?: HistoryOptions;
+    };
+};
+
 export type __IncomingTransactionsMethod__ = {
     name: 'incomingTransactions';
 };
 
+export type __InvoicesMethod__ = {
+    name: 'invoices';
+};
+
+export type __LabelsMethod__ = {
+    name: 'labels';
+};
+
 export type __TransactionsMethod__ = {
     name: 'transactions';
 };
@@ -185,6 +282,23 @@ export type __MinimumRequiredStorageDepositMethod__ = {
     };
 };
 
+export type __MintCollectionIssuerMethod__ = {
+    name: 'mintCollectionIssuer';
+    data: {
+        metadata: Irc27Metadata;
+        options?: TransactionOptions;
+    };
+};
+
+export type __MintCollectionNftsMethod__ = {
+    name: 'mintCollectionNfts';
+    data: {
+        issuerNftId: string;
+        nfts: CollectionNftParams[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __PrepareIncreaseNativeTokenSupplyMethod__ = {
     name: 'prepareIncreaseNativeTokenSupply';
     data: {
@@ -241,6 +355,48 @@ export type __RegisterParticipationEventsMethod__ = {
     };
 };
 
+export type __RemoveContactMethod__ = {
+    name: 'removeContact';
+    data: {
+        address: string;
+    };
+};
+
+export type __RemoveInvoiceMethod__ = {
+    name: 'removeInvoice';
+    data: {
+        invoiceId: number;
+    };
+};
+
+export type __RemoveOutputTagMethod__ = {
+    name: 'removeOutputTag';
+    data: {
+        outputId: string;
+        tag: string;
+    };
+};
+
+export type __RemoveScheduledIntentMethod__ = {
+    name: 'removeScheduledIntent';
+    data: {
+        intentId: number;
+    };
+};
+
+export type __ReplaceTransactionMethod__ = {
+    name: 'replaceTransaction';
+    data: {
+        transactionId: string;
+        outputs: Output[];
+        options?: TransactionOptions;
+    };
+};
+
+export type __ReplanTransactionsMethod__ = {
+    name: 'replanTransactions';
+};
+
 export type __RetryTransactionUntilIncludedMethod__ = {
     name: 'retryTransactionUntilIncluded';
     data: {
@@ -250,6 +406,24 @@ export type __RetryTransactionUntilIncludedMethod__ = {
     };
 };
 
+export type __ScheduleIntentMethod__ = {
+    name: 'scheduleIntent';
+    data: {
+        kind: ScheduledIntentKind;
+    };
+};
+
+export type __ScheduledIntentsMethod__ = {
+    name: 'scheduledIntents';
+};
+
+export type __SearchLabelsMethod__ = {
+    name: 'searchLabels';
+    data: {
+        query: string;
+    };
+};
+
 export type __SendAmountMethod__ = {
     name: 'sendAmount';
     data: {
@@ -282,6 +456,21 @@ export type __SendOutputsMethod__ = {
     };
 };
 
+export type __SetAddressGapLimitMethod__ = {
+    name: 'setAddressGapLimit';
+    data: {
+        addressGapLimit?: number;
+    };
+};
+
+export type __SetAddressLabelMethod__ = {
+    name: 'setAddressLabel';
+    data: {
+        address: string;
+        label?: string;
+    };
+};
+
 export type __SetAliasMethod__ = {
     name: 'setAlias';
     data: {
@@ -289,6 +478,14 @@ export type __SetAliasMethod__ = {
     };
 };
 
+export type __SetContactMethod__ = {
+    name: 'setContact';
+    data: {
+        address: string;
+        name: string;
+    };
+};
+
 export type __SetDefaultSyncOptionsMethod__ = {
     name: 'setDefaultSyncOptions';
     data: {
@@ -5,25 +5,41 @@ import type {
     __BuildFoundryOutputMethod__,
     __BuildNftOutputMethod__,
     __PrepareBurnMethod__,
+    __CancelTransactionMethod__,
     __ClaimOutputsMethod__,
+    __CollectionNftsMethod__,
+    __CreateInvoiceMethod__,
     __PrepareConsolidateOutputsMethod__,
     __PrepareCreateAliasOutputMethod__,
     __PrepareDecreaseNativeTokenSupplyMethod__,
     __DeregisterParticipationEventMethod__,
+    __DryRunTransactionMethod__,
+    __ExecuteScheduledIntentsMethod__,
+    __ExportHistoryMethod__,
     __GenerateEd25519AddressesMethod__,
     __GetBalanceMethod__,
+    __GetHistoricalBalanceMethod__,
+    __GetInvoiceMethod__,
+    __GetInvoiceUriMethod__,
     __GetOutputMethod__,
     __GetFoundryOutputMethod__,
     __GetOutputsWithAdditionalUnlockConditionsMethod__,
     __GetTransactionMethod__,
+    __AddOutputTagMethod__,
     __AddressesMethod__,
     __AddressesWithUnspentOutputsMethod__,
     __OutputsMethod__,
+    __ParsePaymentUriMethod__,
     __PendingTransactionsMethod__,
+    __HistoryMethod__,
     __IncomingTransactionsMethod__,
+    __InvoicesMethod__,
+    __LabelsMethod__,
     __TransactionsMethod__,
     __UnspentOutputsMethod__,
     __MinimumRequiredStorageDepositMethod__,
+    __MintCollectionIssuerMethod__,
+    __MintCollectionNftsMethod__,
     __PrepareIncreaseNativeTokenSupplyMethod__,
     __PrepareMintNativeTokenMethod__,
     __PrepareMintNftsMethod__,
@@ -31,12 +47,24 @@ import type {
     __PrepareSendAmountMethod__,
     __PrepareTransactionMethod__,
     __RegisterParticipationEventsMethod__,
+    __RemoveContactMethod__,
+    __RemoveInvoiceMethod__,
+    __RemoveOutputTagMethod__,
+    __RemoveScheduledIntentMethod__,
+    __ReplaceTransactionMethod__,
+    __ReplanTransactionsMethod__,
     __RetryTransactionUntilIncludedMethod__,
+    __ScheduleIntentMethod__,
+    __ScheduledIntentsMethod__,
+    __SearchLabelsMethod__,
     __SendAmountMethod__,
     __PrepareSendNativeTokensMethod__,
     __PrepareSendNftMethod__,
     __SendOutputsMethod__,
+    __SetAddressGapLimitMethod__,
+    __SetAddressLabelMethod__,
     __SetAliasMethod__,
+    __SetContactMethod__,
     __SetDefaultSyncOptionsMethod__,
     __SignTransactionEssenceMethod__,
     __SignAndSubmitTransactionMethod__,
@@ -85,12 +113,21 @@ export type __AccountMethod__ =
     | __BuildFoundryOutputMethod__
     | __BuildNftOutputMethod__
     | __PrepareBurnMethod__
+    | __CancelTransactionMethod__
     | __ClaimOutputsMethod__
+    | __CollectionNftsMethod__
+    | __CreateInvoiceMethod__
     | __PrepareConsolidateOutputsMethod__
     | __PrepareCreateAliasOutputMethod__
     | __DeregisterParticipationEventMethod__
+    | __DryRunTransactionMethod__
+    | __ExecuteScheduledIntentsMethod__
+    | __ExportHistoryMethod__
     | __GenerateEd25519AddressesMethod__
     | __GetBalanceMethod__
+    | __GetHistoricalBalanceMethod__
+    | __GetInvoiceMethod__
+    | __GetInvoiceUriMethod__
     | __GetOutputMethod__
     | __GetIncomingTransactionMethod__
     | __GetFoundryOutputMethod__
@@ -100,15 +137,22 @@ export type __AccountMethod__ =
     | __GetParticipationEventsMethod__
     | __GetParticipationEventStatusMethod__
     | __GetTransactionMethod__
+    | __AddOutputTagMethod__
     | __AddressesMethod__
     | __AddressesWithUnspentOutputsMethod__
     | __OutputsMethod__
+    | __ParsePaymentUriMethod__
     | __PendingTransactionsMethod__
+    | __HistoryMethod__
     | __IncomingTransactionsMethod__
+    | __InvoicesMethod__
+    | __LabelsMethod__
     | __TransactionsMethod__
     | __UnspentOutputsMethod__
     | __PrepareDecreaseNativeTokenSupplyMethod__
     | __MinimumRequiredStorageDepositMethod__
+    | __MintCollectionIssuerMethod__
+    | __MintCollectionNftsMethod__
     | __PrepareIncreaseNativeTokenSupplyMethod__
     | __PrepareMintNativeTokenMethod__
     | __PrepareMintNftsMethod__
@@ -116,12 +160,24 @@ export type __AccountMethod__ =
     | __PrepareSendAmountMethod__
     | __PrepareTransactionMethod__
     | __RegisterParticipationEventsMethod__
+    | __RemoveContactMethod__
+    | __RemoveInvoiceMethod__
+    | __RemoveOutputTagMethod__
+    | __RemoveScheduledIntentMethod__
+    | __ReplaceTransactionMethod__
+    | __ReplanTransactionsMethod__
     | __RetryTransactionUntilIncludedMethod__
+    | __ScheduleIntentMethod__
+    | __ScheduledIntentsMethod__
+    | __SearchLabelsMethod__
     | __SendAmountMethod__
     | __PrepareSendNativeTokensMethod__
     | __PrepareSendNftMethod__
     | __SendOutputsMethod__
+    | __SetAddressGapLimitMethod__
+    | __SetAddressLabelMethod__
     | __SetAliasMethod__
+    | __SetContactMethod__
     | __SetDefaultSyncOptionsMethod__
     | __SignTransactionEssenceMethod__
     | __SignAndSubmitTransactionMethod__
@@ -5,6 +5,7 @@ import type {
 } from '@iota/types';
 import type { OutputData } from './output';
 import type { InclusionState } from './transaction';
+import type { ScheduledIntent, ScheduledIntentAction } from './scheduledIntent';
 import { IInputSigningData, IRemainder } from '../client';
 
 export type TransactionId = string;
@@ -43,6 +44,8 @@ enum WalletEventType {
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    ScheduledIntent = 6,
+    SyncProgress = 7,
 }
 
 abstract class WalletEvent {
@@ -86,16 +89,25 @@ class NewOutputWalletEvent extends WalletEvent {
     output: OutputData;
     transaction?: ITransactionPayload;
     transactionInputs?: [IOutputResponse];
+    addressLabel?: string;
+    tags?: string[];
+    invoiceId?: number;
 
     constructor(
         output: OutputData,
         transaction?: ITransactionPayload,
         transactionInputs?: [IOutputResponse],
+        addressLabel?: string,
+        tags?: string[],
+        invoiceId?: number,
     ) {
         super(WalletEventType.NewOutput);
         this.output = output;
         this.transaction = transaction;
         this.transactionInputs = transactionInputs;
+        this.addressLabel = addressLabel;
+        this.tags = tags;
+        this.invoiceId = invoiceId;
     }
 
     /**
@@ -118,14 +130,39 @@ class NewOutputWalletEvent extends WalletEvent {
     getTransactionInputs(): [IOutputResponse] | undefined {
         return this.transactionInputs;
     }
+
+    /**
+     * The label of the address that owns the output.
+     */
+    getAddressLabel(): string | undefined {
+        return this.addressLabel;
+    }
+
+    /**
+     * The tags of the output.
+     */
+    getTags(): string[] {
+        return this.tags ?? [];
+    }
+
+    /**
+     * The ID of the invoice paid by the output.
+     */
+    getInvoiceId(): number | undefined {
+        return this.invoiceId;
+    }
 }
 
 class SpentOutputWalletEvent extends WalletEvent {
     output: OutputData;
+    addressLabel?: string;
+    tags?: string[];
 
-    constructor(output: OutputData) {
+    constructor(output: OutputData, addressLabel?: string, tags?: string[]) {
         super(WalletEventType.SpentOutput);
         this.output = output;
+        this.addressLabel = addressLabel;
+        this.tags = tags;
     }
 
     /**
@@ -134,6 +171,20 @@ class SpentOutputWalletEvent extends WalletEvent {
     getOutput(): OutputData {
         return this.output;
     }
+
+    /**
+     * The label of the address that owned the output.
+     */
+    getAddressLabel(): string | undefined {
+        return this.addressLabel;
+    }
+
+    /**
+     * The tags of the output.
+     */
+    getTags(): string[] {
+        return this.tags ?? [];
+    }
 }
 
 class TransactionInclusionWalletEvent extends WalletEvent {
@@ -161,6 +212,75 @@ class TransactionInclusionWalletEvent extends WalletEvent {
     }
 }
 
+class ScheduledIntentWalletEvent extends WalletEvent {
+    intent: ScheduledIntent;
+    action: ScheduledIntentAction;
+
+    constructor(intent: ScheduledIntent, action: ScheduledIntentAction) {
+        super(WalletEventType.ScheduledIntent);
+        this.intent = intent;
+        this.action = action;
+    }
+
+    /**
+     * The evaluated intent.
+     */
+    getIntent(): ScheduledIntent {
+        return this.intent;
+    }
+
+    /**
+     * What happened.
+     */
+    getAction(): ScheduledIntentAction {
+        return this.action;
+    }
+}
+
+/**
+ * All of the sync stages.
+ */
+enum SyncStage {
+    AddressesScanned = 'addressesScanned',
+    OutputIdsFound = 'outputIdsFound',
+    OutputsFetched = 'outputsFetched',
+    TransactionsResolved = 'transactionsResolved',
+}
+
+class SyncProgressWalletEvent extends WalletEvent {
+    stage: SyncStage;
+    processed: number;
+    total?: number;
+
+    constructor(stage: SyncStage, processed: number, total?: number) {
+        super(WalletEventType.SyncProgress);
+        this.stage = stage;
+        this.processed = processed;
+        this.total = total;
+    }
+
+    /**
+     * The stage of the sync.
+     */
+    getStage(): SyncStage {
+        return this.stage;
+    }
+
+    /**
+     * How many items of the stage were processed so far.
+     */
+    getProcessed(): number {
+        return this.processed;
+    }
+
+    /**
+     * How many items the stage has in total, if known.
+     */
+    getTotal(): number | undefined {
+        return this.total;
+    }
+}
+
 /**
  * All of the transaction progress types.
  */
@@ -172,6 +292,7 @@ enum TransactionProgressType {
     SigningTransaction = 4,
     PerformingPow = 5,
     Broadcasting = 6,
+    TransactionPlanStep = 7,
 }
 
 class TransactionProgressWalletEvent extends WalletEvent {
@@ -223,17 +344,17 @@ class GeneratingRemainderDepositAddressProgress extends TransactionProgress {
 class PreparedTransactionProgress extends TransactionProgress {
     essence: ITransactionEssence;
     inputsData: IInputSigningData[];
-    remainder?: IRemainder;
+    remainders: IRemainder[];
 
     constructor(
         essence: ITransactionEssence,
         inputsData: IInputSigningData[],
-        remainder?: IRemainder,
+        remainders: IRemainder[],
     ) {
         super(TransactionProgressType.PreparedTransaction);
         this.essence = essence;
         this.inputsData = inputsData;
-        this.remainder = remainder;
+        this.remainders = remainders;
     }
 }
 
@@ -271,6 +392,40 @@ class BroadcastingProgress extends TransactionProgress {
     }
 }
 
+class TransactionPlanStepProgress extends TransactionProgress {
+    private step: number;
+    private stepCount: number;
+    private consolidation: boolean;
+
+    constructor(step: number, stepCount: number, consolidation: boolean) {
+        super(TransactionProgressType.TransactionPlanStep);
+        this.step = step;
+        this.stepCount = stepCount;
+        this.consolidation = consolidation;
+    }
+
+    /**
+     * The index of the executed step.
+     */
+    getStep(): number {
+        return this.step;
+    }
+
+    /**
+     * The number of steps of the plan.
+     */
+    getStepCount(): number {
+        return this.stepCount;
+    }
+
+    /**
+     * Whether the step consolidates outputs or sends a batch of outputs.
+     */
+    isConsolidation(): boolean {
+        return this.consolidation;
+    }
+}
+
 export {
     Event,
     WalletEventType,
@@ -281,6 +436,9 @@ export {
     SpentOutputWalletEvent,
     TransactionInclusionWalletEvent,
     TransactionProgressWalletEvent,
+    ScheduledIntentWalletEvent,
+    SyncStage,
+    SyncProgressWalletEvent,
     TransactionProgress,
     SelectingInputsProgress,
     GeneratingRemainderDepositAddressProgress,
@@ -289,4 +447,5 @@ export {
     SigningTransactionProgress,
     PerformingPowProgress,
     BroadcastingProgress,
+    TransactionPlanStepProgress,
 };
@@ -0,0 +1,43 @@
+import type { FilterOptions } from './account';
+import type { HistoryEntryKind } from './history';
+
+/** Options to export the history of an account */
+export interface ExportOptions {
+    /** Only export transactions with a timestamp at or after this one, in milliseconds since the unix epoch */
+    lowerBoundTimestamp?: number;
+    /** Only export transactions with a timestamp at or before this one, in milliseconds since the unix epoch */
+    upperBoundTimestamp?: number;
+    /** Only export transactions which consumed or created an output of the account matching these filters */
+    filter?: FilterOptions;
+}
+
+/** Whether the balance of a token increased or decreased */
+export type ExportDirection = 'incoming' | 'outgoing';
+
+/** A balance movement of a single token caused by a transaction */
+export interface ExportRow {
+    /** Milliseconds since the unix epoch when the transaction was created or received */
+    timestamp: string;
+    /** Index of the milestone which confirmed the transaction, if known from the outputs of the account */
+    milestoneIndex?: number;
+    /** The id of the transaction */
+    transactionId: string;
+    /** Whether the balance increased or decreased */
+    direction: ExportDirection;
+    /** What the transaction did for the account */
+    kind: HistoryEntryKind;
+    /** Bech32 encoded counterparties of the transaction, separated by `;` */
+    counterparty?: string;
+    /** Names of the counterparties in the address book, separated by `;` */
+    counterpartyName?: string;
+    /** The native token, not set for the base coin */
+    tokenId?: string;
+    /** Decimal amount by which the balance changed */
+    amount: string;
+    /** Decimal part of a base coin amount which only covers storage deposits or has to be returned */
+    storageDeposit: string;
+    /** The note of the transaction */
+    note?: string;
+    /** Tags of the outputs of the account consumed or created by the transaction, separated by `;` */
+    tags?: string;
+}
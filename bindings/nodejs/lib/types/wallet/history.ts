@@ -0,0 +1,62 @@
+import type { InclusionState } from './transaction';
+import type { TokenBalanceDelta } from './transactionDryRun';
+
+/** What a transaction did for the account */
+export type HistoryEntryKind =
+    | 'incoming'
+    | 'outgoing'
+    | 'selfTransfer'
+    | 'mint'
+    | 'burn'
+    | 'nftMove'
+    | 'aliasStateChange';
+
+/** An entry of the transaction history of an account */
+export interface HistoryEntry {
+    /** The id of the transaction */
+    transactionId: string;
+    /** The block the transaction was sent or included in, if known */
+    blockId?: string;
+    /** What the transaction did for the account */
+    kind: HistoryEntryKind;
+    /** The inclusion state of the transaction */
+    inclusionState: InclusionState;
+    /** Milliseconds since the unix epoch when the transaction was created or, if it's incoming, confirmed */
+    timestamp: string;
+    /** The senders of a received transaction or the recipients of a sent transaction */
+    counterparties: string[];
+    /** Balance change of the account per token, the base coin comes first */
+    balanceDelta: TokenBalanceDelta[];
+    /** The note of the transaction */
+    note?: string;
+    /** Whether inputs of the transaction are pruned, so the counterparties and the balance delta can be incomplete */
+    incomplete: boolean;
+}
+
+/** Options to filter and paginate the history of an account */
+export interface HistoryOptions {
+    /** Only return entries of these kinds */
+    kinds?: HistoryEntryKind[];
+    /** Only return entries with this counterparty */
+    counterparty?: string;
+    /** Only return entries changing the balance of this native token */
+    tokenId?: string;
+    /** Only return entries with a timestamp at or after this one, in milliseconds since the unix epoch */
+    lowerBoundTimestamp?: number;
+    /** Only return entries with a timestamp at or before this one, in milliseconds since the unix epoch */
+    upperBoundTimestamp?: number;
+    /** Amount of matching entries to skip, the newest entries come first */
+    offset?: number;
+    /** Maximum amount of returned entries */
+    limit?: number;
+    /** Request pruned incoming transactions and inputs again, preferring the configured permanodes */
+    fetchPruned?: boolean;
+}
+
+/** A page of the history of an account */
+export interface HistoryPage {
+    /** The entries of the page, newest first */
+    entries: HistoryEntry[];
+    /** The amount of entries matching the filters, across all pages */
+    total: number;
+}
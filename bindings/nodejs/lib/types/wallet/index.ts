@@ -4,11 +4,19 @@ export * from './address';
 export * from './bridge';
 export * from './buildOutputData';
 export * from './event';
+export * from './export';
+export * from './history';
+export * from './invoice';
+export * from './labels';
+export * from './nftCollection';
 export * from './output';
 export * from './outputParams';
 export * from './participation';
+export * from './paymentUri';
 export * from './preparedMintTokenTransactionData';
 export * from './preparedTransactionData';
+export * from './scheduledIntent';
 export * from './signedTransactionEssence';
 export * from './transaction';
+export * from './transactionDryRun';
 export * from './transactionOptions';
@@ -0,0 +1,51 @@
+import type { INativeToken } from '@iota/types';
+
+/** Parameters to create an invoice */
+export interface InvoiceParams {
+    /** The expected amount of the base coin */
+    amount: string;
+    /** The expected native tokens */
+    nativeTokens?: INativeToken[];
+    /** A reference the payment has to contain in a tag or metadata feature, without one the invoice gets a fresh address */
+    reference?: string;
+    /** Unix timestamp in seconds after which the invoice expires, if it's not paid */
+    expiration?: number;
+    /** A description for the payer */
+    description?: string;
+}
+
+/** The payment state of an invoice */
+export type InvoiceStatus =
+    | 'pending'
+    | 'underpaid'
+    | 'paid'
+    | 'overpaid'
+    | 'expired';
+
+/** A payment request of the account, outputs paying it are matched while syncing */
+export interface Invoice {
+    /** The identifier of the invoice, unique within the account */
+    id: number;
+    /** The address of the account the invoice has to be paid to */
+    address: string;
+    /** The expected amount of the base coin */
+    amount: string;
+    /** The expected native tokens */
+    nativeTokens: INativeToken[];
+    /** The reference the payment has to contain in a tag or metadata feature */
+    reference?: string;
+    /** Unix timestamp in seconds after which the invoice expires, if it's not paid */
+    expiration?: number;
+    /** A description for the payer */
+    description?: string;
+    /** Milliseconds since the unix epoch when the invoice was created */
+    timestamp: string;
+    /** The payment state */
+    status: InvoiceStatus;
+    /** The outputs that paid the invoice */
+    outputs: string[];
+    /** The received amount of the base coin, without storage deposits that have to be returned */
+    receivedAmount: string;
+    /** The received native tokens */
+    receivedNativeTokens: INativeToken[];
+}
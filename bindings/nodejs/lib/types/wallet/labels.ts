@@ -0,0 +1,31 @@
+/** An entry of the address book, a named address of someone else */
+export interface Contact {
+    /** Bech32 encoded address of the contact */
+    address: string;
+    /** Name of the contact */
+    name: string;
+}
+
+/** A label of an address of the account, e.g. "invoice #123" */
+export interface AddressLabel {
+    /** Bech32 encoded address of the account */
+    address: string;
+    /** The label */
+    label: string;
+}
+
+/** The address book and the labels of an account */
+export interface Labels {
+    /** Named addresses of others */
+    contacts: Contact[];
+    /** Labels of addresses of the account */
+    addressLabels: AddressLabel[];
+    /** Tags of outputs of the account, by output id */
+    outputTags: { [outputId: string]: string[] };
+}
+
+/** A contact, address label or output tag matching a search */
+export type LabelMatch =
+    | ({ type: 'contact' } & Contact)
+    | ({ type: 'addressLabel' } & AddressLabel)
+    | { type: 'outputTag'; outputId: string; tag: string };
@@ -0,0 +1,53 @@
+import type { HexEncodedString } from '@iota/types';
+
+/** NFT metadata following the IRC-27 standard */
+export interface Irc27Metadata {
+    /** The standard, always `IRC27` */
+    standard: 'IRC27';
+    /** The version of the standard, always `v1.0` */
+    version: 'v1.0';
+    /** The MIME type of the NFT, e.g. `image/png` */
+    type: string;
+    /** The URI of the NFT content */
+    uri: string;
+    /** The name of the NFT */
+    name: string;
+    /** The name of the collection */
+    collectionName?: string;
+    /** Shares of the sales for Bech32 encoded addresses, between 0 and 1 and at most 1 in total */
+    royalties?: { [address: string]: number };
+    /** The name of the issuer */
+    issuerName?: string;
+    /** A description of the NFT */
+    description?: string;
+    /** Attributes of the NFT */
+    attributes?: Irc27Attribute[];
+}
+
+/** An attribute of an NFT with IRC-27 metadata */
+export interface Irc27Attribute {
+    /** The name of the attribute */
+    trait_type: string;
+    /** The value of the attribute */
+    value: string | number;
+}
+
+/** An NFT of a collection to mint */
+export interface CollectionNftParams {
+    /** Bech32 encoded address to which the NFT will be minted, the first address of the account by default */
+    address?: string;
+    /** The immutable metadata of the NFT */
+    metadata: Irc27Metadata;
+}
+
+/** An unspent NFT of a collection */
+export interface CollectionNft {
+    /** The id of the NFT */
+    nftId: HexEncodedString;
+    /** The id of the output holding the NFT */
+    outputId: string;
+    /** Bech32 encoded address of the current owner */
+    address: string;
+    /** The immutable metadata, undefined if it isn't valid IRC-27 metadata */
+    metadata?: Irc27Metadata;
+}
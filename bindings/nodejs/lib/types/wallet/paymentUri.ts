@@ -0,0 +1,21 @@
+import type { HexEncodedString, INativeToken } from '@iota/types';
+
+/** A payment request, encoded as URI like `smr:<bech32 address>?amount=1000000&tag=invoice` */
+export interface PaymentUri {
+    /** The recipient */
+    address: string;
+    /** The amount of the base coin, `0` if not requested */
+    amount: string;
+    /** The native tokens */
+    nativeTokens: INativeToken[];
+    /** The NFT */
+    nftId?: HexEncodedString;
+    /** UTF-8 text of the tag feature */
+    tag?: string;
+    /** UTF-8 text of the metadata feature */
+    metadata?: string;
+    /** Unix timestamp in seconds after which the payment returns to the sender */
+    expiration?: number;
+    /** A message for the payer */
+    message?: string;
+}
@@ -0,0 +1,29 @@
+/** An intent that is executed by the account once its conditions are met */
+export interface ScheduledIntent {
+    /** The identifier of the intent, unique within the account */
+    id: number;
+    /** What should be done */
+    kind: ScheduledIntentKind;
+}
+
+/** The kind of a scheduled intent */
+export type ScheduledIntentKind =
+    | ClaimOutputIntent
+    | ReclaimExpiredOutputsIntent;
+
+/** Claim an output as soon as its timelock or expiration allows it */
+export interface ClaimOutputIntent {
+    type: 'claimOutput';
+    outputId: string;
+}
+
+/** Reclaim sent outputs whose expiration returned them to the account */
+export interface ReclaimExpiredOutputsIntent {
+    type: 'reclaimExpiredOutputs';
+}
+
+/** What happened when a scheduled intent was evaluated */
+export type ScheduledIntentAction =
+    | { type: 'executed'; transactionId: string }
+    | { type: 'failed'; error: string }
+    | { type: 'obsolete' };
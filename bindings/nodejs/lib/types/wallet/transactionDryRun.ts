@@ -0,0 +1,46 @@
+import type { HexEncodedAmount, OutputTypes } from '@iota/types';
+
+/** What a transaction would do if it was sent */
+export interface TransactionDryRun {
+    /** The inputs that would be consumed */
+    inputs: string[];
+    /** The outputs that would be created */
+    outputs: OutputTypes[];
+    /** Balance change of the account per token, the base coin comes first */
+    balanceDelta: TokenBalanceDelta[];
+    /** Alias, foundry and NFT chains that would be consumed without being transitioned into an output */
+    consumedChains: ChainId[];
+    /** Alias, foundry and NFT chains that would be created, new alias and NFT ids are reported as null ids */
+    createdChains: ChainId[];
+    /** Amount that would be locked as storage deposit in the created outputs */
+    storageDepositLocked: string;
+    /** Amount that recipients would have to return through storage deposit return unlock conditions */
+    storageDepositReturned: string;
+    /** Where the remainders would be placed, the one holding the native tokens comes first */
+    remainders: RemainderPlacement[];
+    /** Result of the semantic validation, `None` if the transaction would be valid */
+    conflict: string;
+}
+
+/** Change of the account balance for a single token */
+export interface TokenBalanceDelta {
+    /** The native token id, not set for the base coin */
+    tokenId?: string;
+    /** Amount of the consumed inputs */
+    consumed: HexEncodedAmount;
+    /** Amount of the created outputs that stay in the account */
+    created: HexEncodedAmount;
+}
+
+/** Placement of the remainder output of a transaction */
+export interface RemainderPlacement {
+    /** Index of the remainder in the outputs of the transaction */
+    outputIndex: number;
+    /** Bech32 address the remainder would be sent to */
+    address: string;
+    /** Amount of the remainder */
+    amount: string;
+}
+
+/** Id of an alias, foundry or NFT chain */
+export type ChainId = { Alias: string } | { Foundry: string } | { Nft: string };
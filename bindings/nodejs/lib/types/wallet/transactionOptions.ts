@@ -4,6 +4,8 @@ import type { Burn } from './burn';
 /** Options for the transaction creation */
 export interface TransactionOptions {
     remainderValueStrategy?: RemainderValueStrategy;
+    /** Split the remainder into multiple outputs, so they can be spent independently */
+    remainderSplitStrategy?: RemainderSplitStrategy;
     taggedDataPayload?: ITaggedDataPayload;
     /** Custom inputs that should be used for the transaction */
     customInputs?: string[];
@@ -12,8 +14,22 @@ export interface TransactionOptions {
     /** Optional note, that is only stored locally */
     note?: string;
     allowMicroAmount: boolean;
+    /** The strategy used to select the inputs of the transaction */
+    coinSelectionStrategy?: CoinSelectionStrategy;
+    /** Log the trace of the input selection if it fails, the error itself is returned unchanged */
+    inputSelectionDiagnostics?: boolean;
+    /** Also use outputs created by pending transactions of the account as inputs */
+    allowUnconfirmedInputs?: boolean;
 }
 
+/** The CoinSelectionStrategy */
+export type CoinSelectionStrategy =
+    | 'Default'
+    | 'MinimizeInputCount'
+    | 'ExactMatch'
+    | 'OldestFirst'
+    | 'AvoidAddressMixing';
+
 /** The RemainderValueStrategy */
 export type RemainderValueStrategy =
     | ChangeAddress
@@ -38,6 +54,30 @@ export type CustomAddress = {
     value: string;
 };
 
+/** The RemainderSplitStrategy */
+export type RemainderSplitStrategy =
+    | SingleRemainder
+    | RemainderOutputs
+    | RemainderDenominations;
+
+/** Single variant of RemainderSplitStrategy */
+export type SingleRemainder = {
+    strategy: 'Single';
+    value: null;
+};
+
+/** Outputs variant of RemainderSplitStrategy, splits the remainder into this many outputs */
+export type RemainderOutputs = {
+    strategy: 'Outputs';
+    value: number;
+};
+
+/** Denominations variant of RemainderSplitStrategy, splits the remainder into outputs of these amounts */
+export type RemainderDenominations = {
+    strategy: 'Denominations';
+    value: number[];
+};
+
 /** Native token options for minting */
 export interface MintNativeTokenParams {
     aliasId?: string;
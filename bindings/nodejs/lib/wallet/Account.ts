@@ -4,6 +4,7 @@
 import type { WalletMethodHandler } from './WalletMethodHandler';
 import {
     Balance,
+    BalancePoint,
     AccountMetadata,
     SyncOptions,
     AccountMeta,
@@ -13,15 +14,31 @@ import {
     SendNftParams,
     AddressWithUnspentOutputs,
     AliasOutputParams,
+    ExportOptions,
+    ExportRow,
     FilterOptions,
     GenerateAddressOptions,
+    HistoricalBalance,
+    HistoryOptions,
+    HistoryPage,
+    Invoice,
+    InvoiceParams,
+    Irc27Metadata,
+    LabelMatch,
+    Labels,
+    CollectionNft,
+    CollectionNftParams,
     MintNativeTokenParams,
     MintNftParams,
     OutputData,
     OutputParams,
     OutputsToClaim,
+    PaymentUri,
     PreparedTransactionData,
+    ScheduledIntent,
+    ScheduledIntentKind,
     Transaction,
+    TransactionDryRun,
     TransactionOptions,
     ParticipationOverview,
     ParticipationEventId,
@@ -202,6 +219,31 @@ export class Account {
         return new PreparedTransactionData(JSON.parse(response).payload, this);
     }
 
+    /**
+     * Cancel a pending transaction by replacing it with a conflicting
+     * transaction that sends its inputs back to the account.
+     * @param transactionId The ID of the pending transaction.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The replacement transaction.
+     */
+    async cancelTransaction(
+        transactionId: string,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'cancelTransaction',
+                data: {
+                    transactionId,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Claim basic or nft outputs that have additional unlock conditions
      * to their `AddressUnlockCondition` from the account.
@@ -313,6 +355,31 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Run input selection and the semantic validation for a transaction and
+     * report what it would do, without signing it or locking its inputs.
+     * @param outputs Outputs to use in the transaction.
+     * @param options The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The dry run report.
+     */
+    async dryRunTransaction(
+        outputs: Output[],
+        options?: TransactionOptions,
+    ): Promise<TransactionDryRun> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'dryRunTransaction',
+                data: {
+                    outputs,
+                    options,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Destroy an alias output.
      * @param aliasId The AliasId.
@@ -404,6 +471,26 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the account balance at a past milestone or time, reconstructed
+     * from the stored outputs.
+     * @param point The milestone index or timestamp in seconds.
+     * @returns The balance and the outputs or period the account has no data for.
+     */
+    async getHistoricalBalance(
+        point: BalancePoint,
+    ): Promise<HistoricalBalance> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getHistoricalBalance',
+                data: { point },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get the data for an output.
      * @param outputId The output to get.
@@ -616,6 +703,23 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the history of the sent and received transactions of the account.
+     * @param options Options to filter and paginate the entries.
+     * @returns The page of history entries, newest first.
+     */
+    async history(options?: HistoryOptions): Promise<HistoryPage> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'history',
+                data: { options },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * List all incoming transactions of the account.
      * @returns The incoming transactions with their inputs.
@@ -646,6 +750,275 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Schedule an intent which is executed once its conditions are met, on
+     * each background syncing tick or by calling `executeScheduledIntents`.
+     * @param kind What should be done.
+     * @returns The scheduled intent.
+     */
+    async scheduleIntent(kind: ScheduledIntentKind): Promise<ScheduledIntent> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'scheduleIntent',
+                data: { kind },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * List the scheduled intents of the account.
+     * @returns The scheduled intents.
+     */
+    async scheduledIntents(): Promise<ScheduledIntent[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'scheduledIntents',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Remove a scheduled intent.
+     * @param intentId The ID of the intent.
+     */
+    async removeScheduledIntent(intentId: number): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'removeScheduledIntent',
+            data: { intentId },
+        });
+    }
+
+    /**
+     * Execute the scheduled intents whose conditions are met.
+     * @returns The sent transactions.
+     */
+    async executeScheduledIntents(): Promise<Transaction[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'executeScheduledIntents',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Export every balance movement of the sent and received transactions
+     * of the account, one row per transaction and token.
+     * @param options Options to limit the exported transactions.
+     * @returns The rows, sorted by timestamp, transaction id, token id and direction.
+     */
+    async exportHistory(options?: ExportOptions): Promise<ExportRow[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'exportHistory',
+                data: { options },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Create an invoice, outputs paying it are matched while syncing.
+     * @param params The expected payment, without a reference the invoice
+     * gets a fresh address.
+     * @returns The created invoice.
+     */
+    async createInvoice(params: InvoiceParams): Promise<Invoice> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'createInvoice',
+                data: { params },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the invoices of the account.
+     * @returns The invoices.
+     */
+    async invoices(): Promise<Invoice[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'invoices',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get an invoice of the account.
+     * @param invoiceId The ID of the invoice.
+     * @returns The invoice, if it exists.
+     */
+    async getInvoice(invoiceId: number): Promise<Invoice | undefined> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getInvoice',
+                data: { invoiceId },
+            },
+        );
+        return JSON.parse(response).payload ?? undefined;
+    }
+
+    /**
+     * Get the payment URI of an invoice, to be shown as a QR code.
+     * @param invoiceId The ID of the invoice.
+     * @returns The payment URI.
+     */
+    async getInvoiceUri(invoiceId: number): Promise<string> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getInvoiceUri',
+                data: { invoiceId },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Parse a payment URI and validate it against the protocol parameters of
+     * the network. The payment can be prepared with `prepareOutput`.
+     * @param uri The payment URI, e.g. `smr:<address>?amount=1000000`.
+     * @returns The payment request.
+     */
+    async parsePaymentUri(uri: string): Promise<PaymentUri> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'parsePaymentUri',
+                data: { uri },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Remove an invoice.
+     * @param invoiceId The ID of the invoice.
+     */
+    async removeInvoice(invoiceId: number): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'removeInvoice',
+            data: { invoiceId },
+        });
+    }
+
+    /**
+     * Get the address book and the labels of the account.
+     * @returns The contacts, address labels and output tags.
+     */
+    async labels(): Promise<Labels> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'labels',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Add a contact to the address book, or rename it if the address is
+     * already known.
+     * @param address The Bech32 address of the contact.
+     * @param name The name of the contact.
+     */
+    async setContact(address: string, name: string): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setContact',
+            data: { address, name },
+        });
+    }
+
+    /**
+     * Remove a contact from the address book.
+     * @param address The Bech32 address of the contact.
+     */
+    async removeContact(address: string): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'removeContact',
+            data: { address },
+        });
+    }
+
+    /**
+     * Set the label of an address of the account.
+     * @param address The Bech32 address of the account.
+     * @param label The label, removes it if not provided.
+     */
+    async setAddressLabel(address: string, label?: string): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setAddressLabel',
+            data: { address, label },
+        });
+    }
+
+    /**
+     * Add a tag to an output of the account.
+     * @param outputId The ID of the output.
+     * @param tag The tag.
+     */
+    async addOutputTag(outputId: string, tag: string): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'addOutputTag',
+            data: { outputId, tag },
+        });
+    }
+
+    /**
+     * Remove a tag from an output of the account.
+     * @param outputId The ID of the output.
+     * @param tag The tag.
+     */
+    async removeOutputTag(outputId: string, tag: string): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'removeOutputTag',
+            data: { outputId, tag },
+        });
+    }
+
+    /**
+     * Search the contacts, address labels and output tags, ignoring the case.
+     * @param query The text to search for.
+     * @returns The matching contacts, address labels and output tags.
+     */
+    async searchLabels(query: string): Promise<LabelMatch[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'searchLabels',
+                data: { query },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Send the transactions which conflicted because a transaction they
+     * depended on conflicted again, with new inputs.
+     * @returns The sent transactions.
+     */
+    async replanTransactions(): Promise<Transaction[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'replanTransactions',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * List all the unspent outputs of the account.
      * @param filterOptions Options to filter the to be returned outputs.
@@ -776,6 +1149,78 @@ export class Account {
         return new PreparedTransactionData(JSON.parse(response).payload, this);
     }
 
+    /**
+     * Mint the issuer NFT of a collection, whose address is set as issuer of
+     * the NFTs of the collection, and wait until it's included.
+     * @param metadata The IRC-27 metadata of the collection.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The ID of the issuer NFT.
+     */
+    async mintCollectionIssuer(
+        metadata: Irc27Metadata,
+        transactionOptions?: TransactionOptions,
+    ): Promise<string> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'mintCollectionIssuer',
+                data: {
+                    metadata,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Mint NFTs of a collection with the issuer NFT set as issuer. The IRC-27
+     * metadata is validated first and the NFTs are split into as many
+     * transactions as needed, each one awaited before the next. If a batch
+     * fails after others were minted, the error contains their transaction IDs
+     * and the number of minted NFTs.
+     * @param issuerNftId The ID of the issuer NFT, owned by the account.
+     * @param nfts The NFTs to mint.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The minting transactions.
+     */
+    async mintCollectionNfts(
+        issuerNftId: string,
+        nfts: CollectionNftParams[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'mintCollectionNfts',
+                data: {
+                    issuerNftId,
+                    nfts,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * List the unspent NFTs of a collection.
+     * @param issuerNftId The ID of the issuer NFT.
+     * @returns The NFTs with their owner and metadata.
+     */
+    async collectionNfts(issuerNftId: string): Promise<CollectionNft[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'collectionNfts',
+                data: { issuerNftId },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Prepare an output for sending, useful for offline signing.
      * @param options The options for preparing an output. If the amount is
@@ -870,6 +1315,34 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Replace a pending transaction with a conflicting transaction that spends its
+     * inputs and creates the provided outputs instead.
+     * @param transactionId The ID of the pending transaction.
+     * @param outputs The outputs of the replacement transaction.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The replacement transaction.
+     */
+    async replaceTransaction(
+        transactionId: string,
+        outputs: Output[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'replaceTransaction',
+                data: {
+                    transactionId,
+                    outputs,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
      * included (referenced by a milestone). Returns the included block id.
@@ -991,6 +1464,20 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Set the amount of unused addresses which are kept after the last used address of each chain, addresses are
+     * generated during syncing to keep it.
+     * @param addressGapLimit The gap limit, `undefined` disables it.
+     */
+    async setAddressGapLimit(addressGapLimit?: number): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setAddressGapLimit',
+            data: {
+                addressGapLimit,
+            },
+        });
+    }
+
     /**
      * Set the alias for the account
      * @param alias The account alias to set.
@@ -33,6 +33,57 @@ class NodeCoreAPI():
         """
         return self._call_method('getPeers')
 
+    def get_peer(self, peer_id: str):
+        """Get a peer by its ID.
+        """
+        return self._call_method('getPeer', {
+            'peerId': peer_id
+        })
+
+    def add_peer(self, multi_address: str, alias: str = None):
+        """Add a peer to the node. Requires a JWT for the protected routes of the node.
+        """
+        return self._call_method('addPeer', {
+            'multiAddress': multi_address,
+            'alias': alias
+        })
+
+    def remove_peer(self, peer_id: str):
+        """Remove a peer from the node. Requires a JWT for the protected routes of the node.
+        """
+        return self._call_method('removePeer', {
+            'peerId': peer_id
+        })
+
+    def prune_database(self, index: int = None, depth: int = None, target_database_size: str = None):
+        """Prune the database of the node, exactly one criterion has to be provided.
+        Requires a JWT for the protected routes of the node.
+        """
+        if index is not None:
+            request = {'index': index}
+        elif depth is not None:
+            request = {'depth': depth}
+        else:
+            request = {'targetDatabaseSize': target_database_size}
+        return self._call_method('pruneDatabase', {
+            'request': request
+        })
+
+    def create_snapshots(self, index: int):
+        """Create a snapshot at the given milestone index. Requires a JWT for the protected routes of the node.
+        """
+        return self._call_method('createSnapshots', {
+            'index': index
+        })
+
+    def dashboard_auth(self, user: str, password: str):
+        """Exchange the dashboard credentials for a JWT for the protected routes of the node.
+        """
+        return self._call_method('dashboardAuth', {
+            'user': user,
+            'password': password
+        })
+
     def get_tips(self) -> List[HexStr]:
         """Get tips.
         """
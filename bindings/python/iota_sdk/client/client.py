@@ -42,6 +42,7 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         local_pow: Optional[bool] = None,
         fallback_to_local_pow: Optional[bool] = None,
         pow_worker_count: Optional[int] = None,
+        block_submission_options: Optional[Dict[str, Any]] = None,
         client_handle=None
     ):
         """Initialize the IOTA Client.
@@ -81,6 +82,9 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
             Fallback to local proof of work if the node doesn't support remote PoW.
         pow_worker_count : int
             The amount of threads to be used for proof of work.
+        block_submission_options : dict
+            How the proof of work is done (`powStrategy`), how parents are chosen (`parentsStrategy`) and how often a
+            block is built again with new tips (`maxRetips`).
         """
         client_config = dict(locals())
         del client_config['self']
@@ -486,6 +490,11 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         """
         return self._call_method('getFallbackToLocalPow')
 
+    def get_block_submission_metrics(self) -> List[Dict[str, Any]]:
+        """Returns the metrics of the latest submitted blocks.
+        """
+        return self._call_method('getBlockSubmissionMetrics')
+
     def unhealthy_nodes(self) -> List[Dict[str, Any]]:
         """Returns the unhealthy nodes.
         """
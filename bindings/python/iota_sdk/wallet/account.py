@@ -204,6 +204,17 @@ class Account:
             }
         )
 
+    def history(self, options=None):
+        """Returns the history of the sent and received transactions of the account, newest first. The options can
+        filter the entries by kind, counterparty, token and timestamp, paginate them with an offset and a limit and
+        request pruned data again with `fetchPruned`.
+        """
+        return self._call_account_method(
+            'history', {
+                'options': options
+            }
+        )
+
     def incoming_transactions(self):
         """Returns all incoming transactions of the account.
         """
@@ -218,6 +229,175 @@ class Account:
             'transactions'
         )
 
+    def schedule_intent(self, kind):
+        """Schedule an intent which is executed once its conditions are met, on each background syncing tick or by
+        calling execute_scheduled_intents.
+        """
+        return self._call_account_method(
+            'scheduleIntent', {
+                'kind': kind
+            }
+        )
+
+    def scheduled_intents(self):
+        """Returns the scheduled intents of the account.
+        """
+        return self._call_account_method(
+            'scheduledIntents'
+        )
+
+    def remove_scheduled_intent(self, intent_id: int):
+        """Remove a scheduled intent.
+        """
+        return self._call_account_method(
+            'removeScheduledIntent', {
+                'intentId': intent_id
+            }
+        )
+
+    def execute_scheduled_intents(self):
+        """Execute the scheduled intents whose conditions are met and return the sent transactions.
+        """
+        return self._call_account_method(
+            'executeScheduledIntents'
+        )
+
+    def export_history(self, options=None):
+        """Export every balance movement of the sent and received transactions of the account, one row per transaction
+        and token. The options can limit the exported transactions by timestamp and with output filter options.
+        """
+        return self._call_account_method(
+            'exportHistory', {
+                'options': options
+            }
+        )
+
+    def create_invoice(self, params):
+        """Create an invoice, outputs paying it are matched while syncing. Without a reference, the invoice gets a
+        freshly generated address.
+        """
+        return self._call_account_method(
+            'createInvoice', {
+                'params': params
+            }
+        )
+
+    def invoices(self):
+        """Returns the invoices of the account.
+        """
+        return self._call_account_method(
+            'invoices'
+        )
+
+    def get_invoice(self, invoice_id):
+        """Returns an invoice of the account.
+        """
+        return self._call_account_method(
+            'getInvoice', {
+                'invoiceId': invoice_id
+            }
+        )
+
+    def get_invoice_uri(self, invoice_id):
+        """Returns the payment URI of an invoice, to be shown as a QR code.
+        """
+        return self._call_account_method(
+            'getInvoiceUri', {
+                'invoiceId': invoice_id
+            }
+        )
+
+    def parse_payment_uri(self, uri):
+        """Parses a payment URI and validates it against the protocol parameters of the network.
+        """
+        return self._call_account_method(
+            'parsePaymentUri', {
+                'uri': uri
+            }
+        )
+
+    def remove_invoice(self, invoice_id):
+        """Remove an invoice.
+        """
+        return self._call_account_method(
+            'removeInvoice', {
+                'invoiceId': invoice_id
+            }
+        )
+
+    def labels(self):
+        """Returns the address book and the labels of the account.
+        """
+        return self._call_account_method(
+            'labels'
+        )
+
+    def set_contact(self, address, name):
+        """Add a contact to the address book, or rename it if the address is already known.
+        """
+        return self._call_account_method(
+            'setContact', {
+                'address': address,
+                'name': name
+            }
+        )
+
+    def remove_contact(self, address):
+        """Remove a contact from the address book.
+        """
+        return self._call_account_method(
+            'removeContact', {
+                'address': address
+            }
+        )
+
+    def set_address_label(self, address, label=None):
+        """Set the label of an address of the account, `None` removes it.
+        """
+        return self._call_account_method(
+            'setAddressLabel', {
+                'address': address,
+                'label': label
+            }
+        )
+
+    def add_output_tag(self, output_id, tag):
+        """Add a tag to an output of the account.
+        """
+        return self._call_account_method(
+            'addOutputTag', {
+                'outputId': output_id,
+                'tag': tag
+            }
+        )
+
+    def remove_output_tag(self, output_id, tag):
+        """Remove a tag from an output of the account.
+        """
+        return self._call_account_method(
+            'removeOutputTag', {
+                'outputId': output_id,
+                'tag': tag
+            }
+        )
+
+    def search_labels(self, query):
+        """Returns the contacts, address labels and output tags containing the query, ignoring the case.
+        """
+        return self._call_account_method(
+            'searchLabels', {
+                'query': query
+            }
+        )
+
+    def replan_transactions(self):
+        """Send the transactions which conflicted because a transaction they depended on conflicted again, with new
+        inputs, and return the sent transactions.
+        """
+        return self._call_account_method(
+            'replanTransactions'
+        )
+
     def pending_transactions(self):
         """Returns all pending transactions of the account.
         """
@@ -284,6 +464,36 @@ class Account:
         )
         return PreparedTransactionData(self, prepared)
 
+    def mint_collection_issuer(self, metadata, options=None):
+        """Mint the issuer NFT of a collection and wait until it's included, returns its NFT id.
+        """
+        return self._call_account_method(
+            'mintCollectionIssuer', {
+                'metadata': metadata,
+                'options': options
+            }
+        )
+
+    def mint_collection_nfts(self, issuer_nft_id, nfts, options=None):
+        """Mint NFTs of a collection with IRC-27 metadata, split into as many transactions as needed.
+        """
+        return self._call_account_method(
+            'mintCollectionNfts', {
+                'issuerNftId': issuer_nft_id,
+                'nfts': nfts,
+                'options': options
+            }
+        )
+
+    def collection_nfts(self, issuer_nft_id):
+        """List the unspent NFTs of a collection.
+        """
+        return self._call_account_method(
+            'collectionNfts', {
+                'issuerNftId': issuer_nft_id
+            }
+        )
+
     def get_balance(self):
         """Get account balance information.
         """
@@ -291,6 +501,16 @@ class Account:
             'getBalance'
         )
 
+    def get_historical_balance(self, point):
+        """Get the account balance at a past point, `{ 'type': 'milestone', 'index': 1234 }` or
+        `{ 'type': 'timestamp', 'timestamp': 1688000000 }`, reconstructed from the stored outputs.
+        """
+        return self._call_account_method(
+            'getHistoricalBalance', {
+                'point': point
+            }
+        )
+
     def prepare_output(self, output_options, transaction_options=None):
         """Prepare an output for sending
            If the amount is below the minimum required storage deposit, by default the remaining amount will automatically
@@ -328,6 +548,40 @@ class Account:
         )
         return PreparedTransactionData(self, prepared)
 
+    def dry_run_transaction(self, outputs, options=None):
+        """Run input selection and the semantic validation for a transaction and report what it would do, without
+        signing it or locking its inputs.
+        """
+        return self._call_account_method(
+            'dryRunTransaction', {
+                'outputs': outputs,
+                'options': options
+            }
+        )
+
+    def cancel_transaction(self, transaction_id: HexStr, options=None):
+        """Cancel a pending transaction by replacing it with a conflicting transaction that sends its inputs back to the
+        account.
+        """
+        return self._call_account_method(
+            'cancelTransaction', {
+                'transactionId': transaction_id,
+                'options': options
+            }
+        )
+
+    def replace_transaction(self, transaction_id: HexStr, outputs, options=None):
+        """Replace a pending transaction with a conflicting transaction that spends its inputs and creates the
+        provided outputs instead.
+        """
+        return self._call_account_method(
+            'replaceTransaction', {
+                'transactionId': transaction_id,
+                'outputs': outputs,
+                'options': options
+            }
+        )
+
     def retry_transaction_until_included(self, transaction_id: HexStr, interval=None, max_attempts=None):
         """Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
         included (referenced by a milestone). Returns the included block id.
@@ -383,6 +637,16 @@ class Account:
         )
         return PreparedTransactionData(self, prepared)
 
+    def set_address_gap_limit(self, address_gap_limit: Optional[int] = None):
+        """Set the amount of unused addresses which are kept after the last used address of each chain, addresses
+        are generated during syncing to keep it. `None` disables it.
+        """
+        return self._call_account_method(
+            'setAddressGapLimit', {
+                'addressGapLimit': address_gap_limit
+            }
+        )
+
     def set_alias(self, alias: str):
         """Set alias.
         """
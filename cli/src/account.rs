@@ -10,14 +10,16 @@ use crate::{
     account_completion::ACCOUNT_COMPLETION,
     account_history::AccountHistory,
     command::account::{
-        addresses_command, balance_command, burn_native_token_command, burn_nft_command, claim_command,
-        claimable_outputs_command, consolidate_command, create_alias_outputs_command, decrease_native_token_command,
-        decrease_voting_power_command, destroy_alias_command, destroy_foundry_command, faucet_command,
-        increase_native_token_command, increase_voting_power_command, mint_native_token_command, mint_nft_command,
-        new_address_command, output_command, outputs_command, participation_overview_command, send_command,
-        send_native_token_command, send_nft_command, stop_participating_command, sync_command, transaction_command,
-        transactions_command, unspent_outputs_command, vote_command, voting_output_command, voting_power_command,
-        AccountCli, AccountCommand,
+        addresses_command, balance_command, batch_payout_command, batch_payout_status_command,
+        burn_native_token_command, burn_nft_command, cancel_transaction_command, claim_command,
+        claimable_outputs_command, consolidate_command, contacts_command, create_alias_outputs_command,
+        decrease_native_token_command, decrease_voting_power_command, destroy_alias_command, destroy_foundry_command,
+        export_history_command, faucet_command, increase_native_token_command, increase_voting_power_command,
+        label_address_command, mint_native_token_command, mint_nft_command, new_address_command, output_command,
+        outputs_command, participation_overview_command, remove_contact_command, search_labels_command, send_command,
+        send_native_token_command, send_nft_command, set_contact_command, stop_participating_command, sync_command,
+        tag_output_command, transaction_command, transactions_command, unspent_outputs_command, untag_output_command,
+        vote_command, voting_output_command, voting_power_command, AccountCli, AccountCommand,
     },
     error::Error,
     helper::{bytes_from_hex_or_file, print_account_help},
@@ -46,10 +48,19 @@ pub async fn account_prompt_internal(account: Account, history: &mut AccountHist
         let account = account.details().await;
         account.alias().clone()
     };
+    let completion = ACCOUNT_COMPLETION.with_contacts(
+        account
+            .labels()
+            .await
+            .contacts
+            .into_iter()
+            .map(|contact| (contact.name, contact.address.to_string()))
+            .collect(),
+    );
     let command: String = Input::new()
         .with_prompt(format!("Account \"{}\"", alias).green().to_string())
         .history_with(history)
-        .completion_with(&ACCOUNT_COMPLETION)
+        .completion_with(&completion)
         .interact_text()?;
     match command.as_str() {
         "h" => print_account_help(),
@@ -70,13 +81,23 @@ pub async fn account_prompt_internal(account: Account, history: &mut AccountHist
             if let Err(err) = match account_cli.command {
                 AccountCommand::Addresses => addresses_command(&account).await,
                 AccountCommand::Balance { addresses } => balance_command(&account, addresses).await,
+                AccountCommand::BatchPayout { payout_id, file } => {
+                    batch_payout_command(&account, payout_id, file).await
+                }
+                AccountCommand::BatchPayoutStatus { payout_id } => {
+                    batch_payout_status_command(&account, payout_id).await
+                }
                 AccountCommand::BurnNativeToken { token_id, amount } => {
                     burn_native_token_command(&account, token_id, amount).await
                 }
                 AccountCommand::BurnNft { nft_id } => burn_nft_command(&account, nft_id).await,
+                AccountCommand::CancelTransaction { transaction_id } => {
+                    cancel_transaction_command(&account, transaction_id).await
+                }
                 AccountCommand::Claim { output_id } => claim_command(&account, output_id).await,
                 AccountCommand::ClaimableOutputs => claimable_outputs_command(&account).await,
                 AccountCommand::Consolidate => consolidate_command(&account).await,
+                AccountCommand::Contacts => contacts_command(&account).await,
                 AccountCommand::CreateAliasOutput => create_alias_outputs_command(&account).await,
                 AccountCommand::DecreaseNativeTokenSupply { token_id, amount } => {
                     decrease_native_token_command(&account, token_id, amount).await
@@ -86,10 +107,16 @@ pub async fn account_prompt_internal(account: Account, history: &mut AccountHist
                 AccountCommand::Exit => {
                     return Ok(true);
                 }
+                AccountCommand::ExportHistory { file, from, to } => {
+                    export_history_command(&account, file, from, to).await
+                }
                 AccountCommand::Faucet { address, url } => faucet_command(&account, address, url).await,
                 AccountCommand::IncreaseNativeTokenSupply { token_id, amount } => {
                     increase_native_token_command(&account, token_id, amount).await
                 }
+                AccountCommand::LabelAddress { address, label } => {
+                    label_address_command(&account, address, label).await
+                }
                 AccountCommand::MintNativeToken {
                     circulating_supply,
                     maximum_supply,
@@ -128,12 +155,15 @@ pub async fn account_prompt_internal(account: Account, history: &mut AccountHist
                 AccountCommand::NewAddress => new_address_command(&account).await,
                 AccountCommand::Output { output_id } => output_command(&account, output_id).await,
                 AccountCommand::Outputs => outputs_command(&account).await,
+                AccountCommand::RemoveContact { address } => remove_contact_command(&account, address).await,
+                AccountCommand::SearchLabels { query } => search_labels_command(&account, query).await,
                 AccountCommand::Send {
                     address,
                     amount,
                     return_address,
                     expiration,
                     allow_micro_amount,
+                    dry_run,
                 } => {
                     let allow_micro_amount = if return_address.is_some() || expiration.is_some() {
                         true
@@ -147,6 +177,7 @@ pub async fn account_prompt_internal(account: Account, history: &mut AccountHist
                         return_address,
                         expiration.map(|e| e.as_secs() as u32),
                         allow_micro_amount,
+                        dry_run,
                     )
                     .await
                 }
@@ -157,10 +188,13 @@ pub async fn account_prompt_internal(account: Account, history: &mut AccountHist
                     gift_storage_deposit,
                 } => send_native_token_command(&account, address, token_id, amount, gift_storage_deposit).await,
                 AccountCommand::SendNft { address, nft_id } => send_nft_command(&account, address, nft_id).await,
+                AccountCommand::SetContact { address, name } => set_contact_command(&account, address, name).await,
                 AccountCommand::Sync => sync_command(&account).await,
+                AccountCommand::TagOutput { output_id, tag } => tag_output_command(&account, output_id, tag).await,
                 AccountCommand::Transaction { transaction_id } => transaction_command(&account, &transaction_id).await,
                 AccountCommand::Transactions { show_details } => transactions_command(&account, show_details).await,
                 AccountCommand::UnspentOutputs => unspent_outputs_command(&account).await,
+                AccountCommand::UntagOutput { output_id, tag } => untag_output_command(&account, output_id, tag).await,
                 AccountCommand::Vote { event_id, answers } => vote_command(&account, event_id, answers).await,
                 AccountCommand::StopParticipating { event_id } => stop_participating_command(&account, event_id).await,
                 AccountCommand::ParticipationOverview { event_ids } => {
@@ -4,39 +4,52 @@
 use dialoguer::Completion;
 
 pub(crate) struct AccountCompletion<'a> {
-    options: [&'a str; 36],
+    options: [&'a str; 47],
+    // Names and addresses of the contacts, an argument starting with `@` is completed to the address of a contact
+    contacts: Vec<(String, String)>,
 }
 
 pub(crate) const ACCOUNT_COMPLETION: AccountCompletion = AccountCompletion {
     options: [
         "addresses",
         "balance",
+        "batch-payout",
+        "batch-payout-status",
         "burn-native-token",
         "burn-nft",
+        "cancel-transaction",
         "claim",
         "claimable-outputs",
         "consolidate",
+        "contacts",
         "create-alias-output",
         "decrease-native-token-supply",
         "destroy-alias",
         "destroy-foundry",
         "exit",
+        "export-history",
         "faucet",
         "increase-native-token-supply",
+        "label-address",
         "mint-native-token",
         "mint-nft",
         "new-address",
         "output",
         "outputs",
+        "remove-contact",
+        "search-labels",
         "send",
         "send-native-token",
         "send-nft",
+        "set-contact",
         "sync",
+        "tag-output",
         "transaction",
         "transactions",
         "tx",
         "txs",
         "unspent-outputs",
+        "untag-output",
         "vote",
         "stop-participating",
         "participation-overview",
@@ -46,10 +59,32 @@ pub(crate) const ACCOUNT_COMPLETION: AccountCompletion = AccountCompletion {
         "voting-output",
         "help",
     ],
+    contacts: Vec::new(),
 };
 
+impl<'a> AccountCompletion<'a> {
+    pub(crate) fn with_contacts(self, contacts: Vec<(String, String)>) -> Self {
+        Self { contacts, ..self }
+    }
+}
+
 impl<'a> Completion for AccountCompletion<'a> {
     fn get(&self, input: &str) -> Option<String> {
+        if let Some((command, name)) = input.rsplit_once(" @") {
+            let name = name.to_lowercase();
+            let matches = self
+                .contacts
+                .iter()
+                .filter(|(contact_name, _)| contact_name.to_lowercase().starts_with(&name))
+                .collect::<Vec<_>>();
+
+            return if matches.len() == 1 {
+                Some(format!("{command} {}", matches[0].1))
+            } else {
+                None
+            };
+        }
+
         let matches = self
             .options
             .iter()
@@ -3,6 +3,7 @@
 
 use std::str::FromStr;
 
+use chrono::NaiveDate;
 use clap::{Parser, Subcommand};
 use iota_sdk::{
     client::request_funds_from_faucet,
@@ -19,7 +20,10 @@ use iota_sdk::{
         },
     },
     wallet::{
-        account::{types::AccountAddress, Account, OutputsToClaim, TransactionOptions},
+        account::{
+            types::AccountAddress, Account, BatchPayout, ExportOptions, ExportRow, LabelMatch, OutputsToClaim,
+            PayoutRecipient, PayoutStatus, TransactionDryRun, TransactionOptions,
+        },
         MintNativeTokenParams, MintNftParams, SendAmountParams, SendNativeTokensParams, SendNftParams,
     },
     U256,
@@ -44,6 +48,20 @@ pub enum AccountCommand {
         /// Addresses to compute the balance for.
         addresses: Option<Vec<Bech32Address>>,
     },
+    /// Pay a list of recipients from a CSV or JSON file, recipients already paid by a previous run of the same payout
+    /// are skipped.
+    BatchPayout {
+        /// Identifier of the payout, used to track the status of the recipients across runs, e.g. payroll-2023-06.
+        payout_id: String,
+        /// Path of the file with the recipients, a `.csv` file with the header
+        /// `id,address,amount,native_tokens,tag,metadata` or a JSON array, e.g. ./payroll.csv.
+        file: String,
+    },
+    /// Print the status of the recipients of a batch payout.
+    BatchPayoutStatus {
+        /// Identifier of the payout, e.g. payroll-2023-06.
+        payout_id: String,
+    },
     /// Burn an amount of native token.
     BurnNativeToken {
         /// Token ID to be burnt, e.g. 0x087d205988b733d97fb145ae340e27a8b19554d1ceee64574d7e5ff66c45f69e7a0100000000.
@@ -56,6 +74,11 @@ pub enum AccountCommand {
         /// NFT ID to be burnt, e.g. 0xecadf10e6545aa82da4df2dfd2a496b457c8850d2cab49b7464cb273d3dffb07.
         nft_id: String,
     },
+    /// Cancel a pending transaction by replacing it with a transaction that sends its inputs back to the account.
+    CancelTransaction {
+        /// Transaction ID to be cancelled, e.g. 0xbe5d2e46bdea8c2f44fd5e5a7b9b0a4c7f5d7b5f7f2dc9a4e2a7cf8f0f1b7a3e.
+        transaction_id: String,
+    },
     /// Claim outputs with storage deposit return, expiration or timelock unlock conditions.
     Claim {
         /// Output ID to be claimed.
@@ -65,6 +88,8 @@ pub enum AccountCommand {
     ClaimableOutputs,
     /// Consolidate all basic outputs into one address.
     Consolidate,
+    /// List the address book.
+    Contacts,
     /// Create a new alias output.
     CreateAliasOutput,
     /// Melt an amount of native token.
@@ -87,6 +112,17 @@ pub enum AccountCommand {
     },
     /// Exit the CLI wallet.
     Exit,
+    /// Export every balance movement of the account transactions to a file, for accounting.
+    ExportHistory {
+        /// Path of the file to write, a `.csv` file or a JSON array otherwise, e.g. ./history-2023.csv.
+        file: String,
+        /// Only export transactions from this UTC date on, e.g. --from 2023-01-01.
+        #[arg(long)]
+        from: Option<String>,
+        /// Only export transactions up to and including this UTC date, e.g. --to 2023-12-31.
+        #[arg(long)]
+        to: Option<String>,
+    },
     /// Request funds from the faucet.
     Faucet {
         /// Address the faucet sends the funds to, defaults to the latest address.
@@ -101,6 +137,13 @@ pub enum AccountCommand {
         /// Amount to be minted, e.g. 100.
         amount: String,
     },
+    /// Label an address of the account, e.g. invoice #123. Removes the label if none is provided.
+    LabelAddress {
+        /// Address of the account to label, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
+        address: Bech32Address,
+        /// The label, e.g. invoice #123.
+        label: Vec<String>,
+    },
     /// Mint a native token.
     MintNativeToken {
         /// Circulating supply of the native token to be minted, e.g. 100.
@@ -150,6 +193,16 @@ pub enum AccountCommand {
     },
     /// List all outputs.
     Outputs,
+    /// Remove a contact from the address book.
+    RemoveContact {
+        /// Address of the contact, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
+        address: Bech32Address,
+    },
+    /// Search the contacts, address labels and output tags.
+    SearchLabels {
+        /// Text to search for, ignoring the case, e.g. alice.
+        query: String,
+    },
     /// Send an amount.
     Send {
         /// Address to send funds to, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
@@ -170,6 +223,9 @@ pub enum AccountCommand {
         /// conditions if necessary. This flag is implied by the existence of a return address or expiration.
         #[arg(long, default_value_t = false)]
         allow_micro_amount: bool,
+        /// Only report what the transaction would do, without signing or sending it.
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
     },
     /// Send native tokens.
     /// This will create an output with an expiration and storage deposit return unlock condition.
@@ -190,8 +246,23 @@ pub enum AccountCommand {
         /// NFT ID to be sent, e.g. 0xecadf10e6545aa82da4df2dfd2a496b457c8850d2cab49b7464cb273d3dffb07.
         nft_id: String,
     },
+    /// Add a contact to the address book, or rename it. Contacts can be completed with `@name`.
+    SetContact {
+        /// Address of the contact, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
+        address: Bech32Address,
+        /// Name of the contact, e.g. Alice.
+        #[arg(required = true)]
+        name: Vec<String>,
+    },
     /// Synchronize the account.
     Sync,
+    /// Tag an output of the account.
+    TagOutput {
+        /// Output ID to be tagged, e.g. 0xbce525324af12eda02bf7927e92cea3a8e8322d0f41966271443e6c3b245a4400000.
+        output_id: String,
+        /// The tag, e.g. rent.
+        tag: String,
+    },
     /// Show the details of the transaction.
     #[clap(alias = "tx")]
     Transaction {
@@ -207,6 +278,13 @@ pub enum AccountCommand {
     },
     /// List the account unspent outputs.
     UnspentOutputs,
+    /// Remove a tag from an output of the account.
+    UntagOutput {
+        /// Output ID to be untagged, e.g. 0xbce525324af12eda02bf7927e92cea3a8e8322d0f41966271443e6c3b245a4400000.
+        output_id: String,
+        /// The tag, e.g. rent.
+        tag: String,
+    },
     /// Cast votes for an event.
     Vote {
         /// Event ID for which to cast votes, e.g. 0xdc049a721dc65ec342f836c876ec15631ed915cd55213cee39e8d1c821c751f2.
@@ -308,6 +386,74 @@ pub async fn balance_command(account: &Account, addresses: Option<Vec<Bech32Addr
     Ok(())
 }
 
+// `batch-payout` command
+pub async fn batch_payout_command(account: &Account, payout_id: String, file: String) -> Result<(), Error> {
+    let content = tokio::fs::read_to_string(&file).await?;
+    let recipients = if file.ends_with(".csv") {
+        PayoutRecipient::from_csv(&content)?
+    } else {
+        PayoutRecipient::from_json(&content)?
+    };
+
+    println_log_info!("Paying {} recipients of payout {payout_id}.", recipients.len());
+
+    let payout = account.batch_payout(payout_id, recipients, None).await?;
+
+    print_batch_payout(&payout);
+
+    Ok(())
+}
+
+// `batch-payout-status` command
+pub async fn batch_payout_status_command(account: &Account, payout_id: String) -> Result<(), Error> {
+    match account.get_batch_payout(&payout_id).await? {
+        Some(payout) => print_batch_payout(&payout),
+        None => println_log_info!("No batch payout found with id {payout_id}"),
+    }
+
+    Ok(())
+}
+
+fn print_batch_payout(payout: &BatchPayout) {
+    for state in &payout.recipients {
+        println_log_info!(
+            "{}: {} {} {:?}",
+            state.recipient.id,
+            state.recipient.address,
+            state.recipient.amount,
+            state.status
+        );
+    }
+
+    let paid = payout
+        .recipients
+        .iter()
+        .filter(|state| matches!(state.status, PayoutStatus::Confirmed(_)))
+        .count();
+    println_log_info!(
+        "Payout {}: {paid}/{} recipients paid.",
+        payout.id,
+        payout.recipients.len()
+    );
+}
+
+// `cancel-transaction` command
+pub async fn cancel_transaction_command(account: &Account, transaction_id: String) -> Result<(), Error> {
+    println_log_info!("Cancelling transaction {transaction_id}.");
+
+    let transaction = account
+        .cancel_transaction(&TransactionId::from_str(&transaction_id)?, None)
+        .await?;
+
+    println_log_info!(
+        "Replacement transaction sent:\n{:?}\n{:?}",
+        transaction.transaction_id,
+        transaction.block_id
+    );
+
+    Ok(())
+}
+
 // `claim` command
 pub async fn claim_command(account: &Account, output_id: Option<String>) -> Result<(), Error> {
     if let Some(output_id) = output_id {
@@ -407,6 +553,21 @@ pub async fn consolidate_command(account: &Account) -> Result<(), Error> {
     Ok(())
 }
 
+// `contacts` command
+pub async fn contacts_command(account: &Account) -> Result<(), Error> {
+    let contacts = account.labels().await.contacts;
+
+    if contacts.is_empty() {
+        println_log_info!("No contacts found");
+    } else {
+        for contact in contacts {
+            println_log_info!("{:<20} {}", contact.name, contact.address);
+        }
+    }
+
+    Ok(())
+}
+
 // `create-alias-output` command
 pub async fn create_alias_outputs_command(account: &Account) -> Result<(), Error> {
     println_log_info!("Creating alias output.");
@@ -471,6 +632,43 @@ pub async fn destroy_foundry_command(account: &Account, foundry_id: String) -> R
     Ok(())
 }
 
+// `export-history` command
+pub async fn export_history_command(
+    account: &Account,
+    file: String,
+    from: Option<String>,
+    to: Option<String>,
+) -> Result<(), Error> {
+    let options = ExportOptions {
+        lower_bound_timestamp: from.map(|date| date_to_millis(&date, (0, 0, 0, 0))).transpose()?,
+        upper_bound_timestamp: to.map(|date| date_to_millis(&date, (23, 59, 59, 999))).transpose()?,
+        filter: None,
+    };
+
+    let rows = account.export_history(options).await?;
+    let content = if file.ends_with(".csv") {
+        ExportRow::to_csv(&rows)?
+    } else {
+        ExportRow::to_json(&rows)?
+    };
+    tokio::fs::write(&file, content).await?;
+
+    println_log_info!("Exported {} rows to {file}.", rows.len());
+
+    Ok(())
+}
+
+// Milliseconds since the unix epoch of a time on a `YYYY-MM-DD` UTC date
+fn date_to_millis(date: &str, (hour, min, sec, milli): (u32, u32, u32, u32)) -> Result<u128, Error> {
+    let date_time = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|e| Error::Miscellaneous(format!("invalid date {date}: {e}")))?
+        .and_hms_milli_opt(hour, min, sec, milli)
+        .ok_or_else(|| Error::Miscellaneous(format!("invalid date {date}")))?;
+
+    u128::try_from(date_time.timestamp_millis())
+        .map_err(|_| Error::Miscellaneous(format!("date {date} is before 1970")))
+}
+
 // `faucet` command
 pub async fn faucet_command(
     account: &Account,
@@ -513,6 +711,19 @@ pub async fn increase_native_token_command(account: &Account, token_id: String,
     Ok(())
 }
 
+// `label-address` command
+pub async fn label_address_command(account: &Account, address: Bech32Address, label: Vec<String>) -> Result<(), Error> {
+    let label = (!label.is_empty()).then(|| label.join(" "));
+    account.set_address_label(address, label.clone()).await?;
+
+    match label {
+        Some(label) => println_log_info!("Labeled {address} as \"{label}\"."),
+        None => println_log_info!("Removed the label of {address}."),
+    }
+
+    Ok(())
+}
+
 // `mint-native-token` command
 pub async fn mint_native_token_command(
     account: &Account,
@@ -624,6 +835,35 @@ pub async fn outputs_command(account: &Account) -> Result<(), Error> {
     Ok(())
 }
 
+// `remove-contact` command
+pub async fn remove_contact_command(account: &Account, address: Bech32Address) -> Result<(), Error> {
+    account.remove_contact(address).await?;
+
+    println_log_info!("Removed contact {address}.");
+
+    Ok(())
+}
+
+// `search-labels` command
+pub async fn search_labels_command(account: &Account, query: String) -> Result<(), Error> {
+    let matches = account.search_labels(&query).await;
+
+    if matches.is_empty() {
+        println_log_info!("No labels found");
+    }
+    for label_match in matches {
+        match label_match {
+            LabelMatch::Contact(contact) => println_log_info!("Contact        {} {}", contact.name, contact.address),
+            LabelMatch::AddressLabel(address_label) => {
+                println_log_info!("Address label  {} {}", address_label.label, address_label.address)
+            }
+            LabelMatch::OutputTag { output_id, tag } => println_log_info!("Output tag     {tag} {output_id}"),
+        }
+    }
+
+    Ok(())
+}
+
 // `send` command
 pub async fn send_command(
     account: &Account,
@@ -632,19 +872,23 @@ pub async fn send_command(
     return_address: Option<impl ConvertTo<Bech32Address>>,
     expiration: Option<u32>,
     allow_micro_amount: bool,
+    dry_run: bool,
 ) -> Result<(), Error> {
     let outputs = [SendAmountParams::new(address, amount)?
         .with_return_address(return_address.map(ConvertTo::convert).transpose()?)
         .with_expiration(expiration)];
-    let transaction = account
-        .send_amount(
-            outputs,
-            TransactionOptions {
-                allow_micro_amount,
-                ..Default::default()
-            },
-        )
-        .await?;
+    let options = TransactionOptions {
+        allow_micro_amount,
+        ..Default::default()
+    };
+
+    if dry_run {
+        let dry_run = account.dry_run_send_amount(outputs, options).await?;
+        print_transaction_dry_run(&dry_run);
+        return Ok(());
+    }
+
+    let transaction = account.send_amount(outputs, options).await?;
 
     println_log_info!(
         "Transaction sent:\n{:?}\n{:?}",
@@ -655,6 +899,47 @@ pub async fn send_command(
     Ok(())
 }
 
+fn print_transaction_dry_run(dry_run: &TransactionDryRun) {
+    println_log_info!("Dry run, the transaction was not sent.");
+    println_log_info!("Conflict: {:?}", dry_run.conflict);
+    println_log_info!("Inputs: {}, outputs: {}", dry_run.inputs.len(), dry_run.outputs.len());
+
+    for delta in &dry_run.balance_delta {
+        let token = delta
+            .token_id
+            .map_or_else(|| "base coin".to_string(), |token_id| token_id.to_string());
+        let sign = if delta.is_decrease() { "-" } else { "+" };
+        let amount = if delta.is_decrease() {
+            delta.consumed - delta.created
+        } else {
+            delta.created - delta.consumed
+        };
+        println_log_info!("Balance change {token}: {sign}{amount}");
+    }
+
+    for chain_id in &dry_run.created_chains {
+        println_log_info!("Created chain: {chain_id}");
+    }
+    for chain_id in &dry_run.consumed_chains {
+        println_log_info!("Consumed chain: {chain_id}");
+    }
+
+    println_log_info!(
+        "Storage deposit locked: {}, returned: {}",
+        dry_run.storage_deposit_locked,
+        dry_run.storage_deposit_returned
+    );
+
+    for remainder in &dry_run.remainders {
+        println_log_info!(
+            "Remainder: {} to {} (output {})",
+            remainder.amount,
+            remainder.address,
+            remainder.output_index
+        );
+    }
+}
+
 // `send-native-token` command
 pub async fn send_native_token_command(
     account: &Account,
@@ -719,6 +1004,16 @@ pub async fn send_nft_command(
     Ok(())
 }
 
+// `set-contact` command
+pub async fn set_contact_command(account: &Account, address: Bech32Address, name: Vec<String>) -> Result<(), Error> {
+    let name = name.join(" ");
+    account.set_contact(address, name.clone()).await?;
+
+    println_log_info!("Saved contact {name} with address {address}.");
+
+    Ok(())
+}
+
 // `sync` command
 pub async fn sync_command(account: &Account) -> Result<(), Error> {
     let balance = account.sync(None).await?;
@@ -728,6 +1023,16 @@ pub async fn sync_command(account: &Account) -> Result<(), Error> {
     Ok(())
 }
 
+// `tag-output` command
+pub async fn tag_output_command(account: &Account, output_id: String, tag: String) -> Result<(), Error> {
+    let output_id = OutputId::from_str(&output_id)?;
+    account.add_output_tag(output_id, tag.clone()).await?;
+
+    println_log_info!("Tagged output {output_id} with {tag}.");
+
+    Ok(())
+}
+
 /// `transaction` command
 pub async fn transaction_command(account: &Account, transaction_id_str: &str) -> Result<(), Error> {
     let transaction_id = TransactionId::from_str(transaction_id_str)?;
@@ -783,6 +1088,16 @@ pub async fn unspent_outputs_command(account: &Account) -> Result<(), Error> {
     Ok(())
 }
 
+// `untag-output` command
+pub async fn untag_output_command(account: &Account, output_id: String, tag: String) -> Result<(), Error> {
+    let output_id = OutputId::from_str(&output_id)?;
+    account.remove_output_tag(output_id, &tag).await?;
+
+    println_log_info!("Removed tag {tag} from output {output_id}.");
+
+    Ok(())
+}
+
 pub async fn vote_command(account: &Account, event_id: ParticipationEventId, answers: Vec<u8>) -> Result<(), Error> {
     let transaction = account.vote(Some(event_id), Some(answers)).await?;
 
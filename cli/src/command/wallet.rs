@@ -7,11 +7,13 @@ use clap::{Args, Parser, Subcommand};
 use iota_sdk::{
     client::{
         constants::SHIMMER_COIN_TYPE,
+        node_manager::node::{ProxyOptions, TlsOptions},
         secret::{stronghold::StrongholdSecretManager, SecretManager},
         stronghold::StrongholdAdapter,
         utils::Password,
     },
     wallet::{ClientOptions, Wallet},
+    Url,
 };
 use log::LevelFilter;
 
@@ -73,10 +75,7 @@ pub enum WalletCommand {
         backup_path: String,
     },
     /// Set the node to use.
-    SetNode {
-        /// Node URL to use for all future operations.
-        url: String,
-    },
+    SetNode(SetNodeParameters),
     /// Synchronize all accounts.
     Sync,
 }
@@ -95,6 +94,24 @@ pub struct InitParameters {
     pub coin_type: u32,
 }
 
+#[derive(Debug, Clone, Args)]
+pub struct SetNodeParameters {
+    /// Node URL to use for all future operations.
+    pub url: String,
+    /// Set a proxy through which all requests are sent, e.g. "http://proxy:8080" or "socks5://proxy:1080".
+    #[arg(long, value_name = "URL")]
+    pub proxy: Option<String>,
+    /// Set the path to a PEM encoded root certificate to trust. Can be provided multiple times.
+    #[arg(long = "root-certificate", value_name = "PATH")]
+    pub root_certificates: Vec<String>,
+    /// Only trust the provided root certificates instead of the built-in ones.
+    #[arg(long)]
+    pub pin_certificates: bool,
+    /// Set the path to a PEM file containing the client certificate and private key for mutual TLS.
+    #[arg(long, value_name = "PATH")]
+    pub client_identity: Option<String>,
+}
+
 impl Default for InitParameters {
     fn default() -> Self {
         Self {
@@ -218,10 +235,39 @@ pub async fn restore_command(storage_path: &Path, snapshot_path: &Path, backup_p
     Ok(wallet)
 }
 
-pub async fn set_node_command(storage_path: &Path, snapshot_path: &Path, url: String) -> Result<Wallet, Error> {
+pub async fn set_node_command(
+    storage_path: &Path,
+    snapshot_path: &Path,
+    parameters: SetNodeParameters,
+) -> Result<Wallet, Error> {
+    let mut client_options = ClientOptions::new().with_node(&parameters.url)?;
+
+    if let Some(proxy) = parameters.proxy {
+        client_options = client_options.with_proxy(ProxyOptions {
+            url: Url::parse(&proxy).map_err(iota_sdk::client::Error::Url)?,
+            basic_auth_name_pwd: None,
+        });
+    }
+
+    if !parameters.root_certificates.is_empty() || parameters.pin_certificates || parameters.client_identity.is_some() {
+        let root_certificates = parameters
+            .root_certificates
+            .iter()
+            .map(std::fs::read_to_string)
+            .collect::<Result<Vec<_>, _>>()?;
+        // Only the absolute path of the identity is stored, the private key is read when connecting
+        let client_identity_path = parameters.client_identity.map(std::fs::canonicalize).transpose()?;
+
+        client_options = client_options.with_tls(TlsOptions {
+            root_certificates,
+            disable_built_in_root_certificates: parameters.pin_certificates,
+            client_identity_path,
+        });
+    }
+
     let password = get_password("Stronghold password", !snapshot_path.exists())?;
     let wallet = unlock_wallet(storage_path, snapshot_path, password).await?;
-    wallet.set_client_options(ClientOptions::new().with_node(&url)?).await?;
+    wallet.set_client_options(client_options).await?;
 
     Ok(wallet)
 }
@@ -46,8 +46,8 @@ pub async fn new_wallet(cli: WalletCli) -> Result<(Option<Wallet>, Option<String
                 let (wallet, account) = new_command(storage_path, snapshot_path, alias).await?;
                 (Some(wallet), Some(account))
             }
-            WalletCommand::SetNode { url } => {
-                let wallet = set_node_command(storage_path, snapshot_path, url).await?;
+            WalletCommand::SetNode(parameters) => {
+                let wallet = set_node_command(storage_path, snapshot_path, parameters).await?;
                 (Some(wallet), None)
             }
             WalletCommand::Sync => {
@@ -6,13 +6,7 @@
 //!
 //! `cargo run --example mint_issuer_nft --release`
 
-use iota_sdk::{
-    types::block::{
-        output::{NftId, Output, OutputId},
-        payload::transaction::TransactionEssence,
-    },
-    wallet::{MintNftParams, Result, Wallet},
-};
+use iota_sdk::wallet::{account::Irc27Metadata, Result, Wallet};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -32,32 +26,13 @@ async fn main() -> Result<()> {
         .set_stronghold_password(std::env::var("STRONGHOLD_PASSWORD").unwrap())
         .await?;
 
-    let nft_options = [MintNftParams::new()
-        .with_immutable_metadata(b"This NFT will be the issuer from the awesome NFT collection".to_vec())];
+    let metadata = Irc27Metadata::new("image/png", "https://shimmer.network/og.png", "Shimmer OG")
+        .with_issuer_name("IOTA Foundation".to_string())
+        .with_description("This NFT will be the issuer from the awesome NFT collection".to_string());
 
-    let transaction = account.mint_nfts(nft_options, None).await?;
-    println!("Transaction sent: {}", transaction.transaction_id);
-
-    let block_id = account
-        .retry_transaction_until_included(&transaction.transaction_id, None, None)
-        .await?;
-    println!(
-        "Block with NFTs mint included: {}/block/{}",
-        std::env::var("EXPLORER_URL").unwrap(),
-        block_id
-    );
-
-    let TransactionEssence::Regular(essence) = transaction.payload.essence();
-    for (output_index, output) in essence.outputs().iter().enumerate() {
-        if let Output::Nft(nft_output) = output {
-            // New minted nft id is empty in the output
-            if nft_output.nft_id().is_null() {
-                let output_id = OutputId::new(transaction.transaction_id, output_index as u16)?;
-                let nft_id = NftId::from(&output_id);
-                println!("New minted NFT id: {nft_id}");
-            }
-        }
-    }
+    // Waits until the issuer NFT is minted and syncs the account
+    let issuer_nft_id = account.mint_collection_issuer(metadata, None).await?;
+    println!("New minted issuer NFT id: {issuer_nft_id}");
 
     Ok(())
 }
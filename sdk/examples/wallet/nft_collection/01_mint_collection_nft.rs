@@ -9,11 +9,11 @@
 use std::str::FromStr;
 
 use iota_sdk::{
-    types::block::{
-        address::{Bech32Address, NftAddress},
-        output::NftId,
+    types::block::output::NftId,
+    wallet::{
+        account::{CollectionNftParams, Irc27Metadata},
+        Result, Wallet,
     },
-    wallet::{MintNftParams, Result, Wallet},
 };
 
 #[tokio::main]
@@ -38,31 +38,35 @@ async fn main() -> Result<()> {
         .set_stronghold_password(std::env::var("STRONGHOLD_PASSWORD").unwrap())
         .await?;
 
-    let bech32_hrp = account.client().get_bech32_hrp().await?;
-
-    let nft_options = (0..nft_collection_size).map(|index| MintNftParams::new()
-        .with_immutable_metadata(format!("{{\"standard\":\"IRC27\",\"version\":\"v1.0\",\"type\":\"video/mp4\",\"uri\":\"ipfs://wrongcVm9fx47YXNTkhpMEYSxCD3Bqh7PJYr7eo5Ywrong\",\"name\":\"Shimmer OG NFT #{index}\",\"description\":\"The Shimmer OG NFT was handed out 1337 times by the IOTA Foundation to celebrate the official launch of the Shimmer Network.\",\"issuerName\":\"IOTA Foundation\",\"collectionId\":\"{issuer_nft_id}\",\"collectionName\":\"Shimmer OG\" }}").as_bytes().to_vec())
-        // The NFT address from the NFT we minted in mint_issuer_nft example
-        .with_issuer(Bech32Address::new(bech32_hrp, NftAddress::new(issuer_nft_id)))
-    ).collect::<Vec<_>>();
-
-    // Mint nfts in chunks, since the transaction size is limited
-    for nfts in nft_options.chunks(50) {
-        let transaction = account.mint_nfts(nfts.to_vec(), None).await?;
+    let nfts = (0..nft_collection_size)
+        .map(|index| CollectionNftParams {
+            address: None,
+            metadata: Irc27Metadata::new(
+                "video/mp4",
+                "ipfs://wrongcVm9fx47YXNTkhpMEYSxCD3Bqh7PJYr7eo5Ywrong",
+                format!("Shimmer OG NFT #{index}"),
+            )
+            .with_description("The Shimmer OG NFT was handed out 1337 times by the IOTA Foundation to celebrate the official launch of the Shimmer Network.".to_string())
+            .with_issuer_name("IOTA Foundation".to_string())
+            .with_collection_name("Shimmer OG".to_string()),
+        })
+        .collect::<Vec<_>>();
 
+    // The NFTs are minted in as many transactions as needed, with the issuer NFT as issuer
+    let transactions = account.mint_collection_nfts(issuer_nft_id, nfts, None).await?;
+    for transaction in transactions {
         println!(
-            "Transaction with chunk of NFTs mint sent: {}/transaction/{}",
+            "Transaction with chunk of NFTs mint included: {}/transaction/{}",
             std::env::var("EXPLORER_URL").unwrap(),
             transaction.transaction_id
         );
-        // Try to get the transaction confirmed
-        account
-            .retry_transaction_until_included(&transaction.transaction_id, None, None)
-            .await?;
-        // Sync so the new outputs are available again for new transactions
-        account.sync(None).await?;
     }
 
+    println!(
+        "NFTs of the collection: {}",
+        account.collection_nfts(issuer_nft_id).await?.len()
+    );
+
     // After the NFTs are minted, the issuer nft can be sent to the so called "null address"
     // 0x0000000000000000000000000000000000000000000000000000000000000000 (for smr:
     // smr1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqy8f002) or burned, to
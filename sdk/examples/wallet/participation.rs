@@ -68,6 +68,8 @@ async fn main() -> Result<()> {
         url: Url::parse("http://localhost:14265").map_err(iota_sdk::client::Error::Url)?,
         auth: None,
         disabled: false,
+        proxy: None,
+        tls: None,
     };
     account
         .register_participation_events(&ParticipationEventRegistrationOptions {
@@ -0,0 +1,28 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+/// The strategy used to choose the basic outputs that fulfill the amount requirement of a transaction.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum CoinSelectionStrategy {
+    /// Select the outputs with the lowest amounts first, to consume small outputs, and the outputs with the highest
+    /// amounts first if that would exceed the maximum number of inputs.
+    Default,
+    /// Select the outputs with the highest amounts first, to use as few inputs as possible.
+    MinimizeInputCount,
+    /// Search for outputs whose amounts exactly match the required amount, so no remainder output is created.
+    /// Falls back to [`CoinSelectionStrategy::Default`] if there is no exact match.
+    ExactMatch,
+    /// Select the outputs that were booked first, to consolidate old dust outputs.
+    OldestFirst,
+    /// Select outputs of a single address, preferably one that is already used by the transaction, so that addresses
+    /// don't get linked together. Falls back to [`CoinSelectionStrategy::Default`] if no address holds enough funds.
+    AvoidAddressMixing,
+}
+
+impl Default for CoinSelectionStrategy {
+    fn default() -> Self {
+        Self::Default
+    }
+}
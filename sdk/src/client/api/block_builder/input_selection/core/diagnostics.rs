@@ -0,0 +1,190 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Diagnostics of input selection failures.
+
+use std::fmt::{Display, Formatter};
+
+use primitive_types::U256;
+use serde::Serialize;
+
+use super::{requirement::amount::sdruc_not_expired, Error, InputSelection, Requirement};
+use crate::types::block::output::{OutputId, TokenId};
+
+/// Trace of the requirement fulfilment of an [`InputSelection`], returned together with the error by
+/// [`InputSelection::select_with_diagnostics()`].
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputSelectionDiagnostics {
+    /// Requirements that were fulfilled, in the order they were resolved.
+    pub fulfilled_requirements: Vec<FulfilledRequirement>,
+    /// Requirement that was being resolved when input selection failed.
+    pub failed_requirement: Option<Requirement>,
+    /// Candidate inputs that were rejected and why.
+    pub rejected_inputs: Vec<RejectedInput>,
+    /// Shortfall that remained when input selection failed.
+    pub shortfall: Option<Shortfall>,
+}
+
+/// An input selection error together with the trace of the requirement fulfilment.
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+#[error("{error}, diagnostics: {diagnostics}")]
+pub struct DiagnosedError {
+    /// The error, as returned by [`InputSelection::select()`].
+    pub error: Error,
+    /// The trace of the requirement fulfilment.
+    pub diagnostics: Box<InputSelectionDiagnostics>,
+}
+
+impl From<DiagnosedError> for Error {
+    fn from(error: DiagnosedError) -> Self {
+        error.error
+    }
+}
+
+/// A requirement and the inputs that were selected to fulfill it.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfilledRequirement {
+    /// The fulfilled requirement.
+    pub requirement: Requirement,
+    /// The inputs selected for it.
+    pub selected_inputs: Vec<OutputId>,
+}
+
+/// A candidate input that was rejected.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RejectedInput {
+    /// The output ID of the input.
+    pub output_id: OutputId,
+    /// Why it was rejected.
+    pub reason: InputRejectionReason,
+}
+
+impl RejectedInput {
+    /// Creates a new [`RejectedInput`].
+    pub fn new(output_id: OutputId, reason: InputRejectionReason) -> Self {
+        Self { output_id, reason }
+    }
+}
+
+/// The reason why a candidate input was rejected.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize)]
+pub enum InputRejectionReason {
+    /// The input is locked by another pending transaction.
+    Locked,
+    /// The input is forbidden.
+    Forbidden,
+    /// The input has a timelock that is not expired yet.
+    Timelocked,
+    /// The input has an expiration, so it is or will be owned by the return address of someone else.
+    Expiration,
+    /// The input has a storage deposit return that needs to be returned in full, so it makes no amount available.
+    StorageDepositReturn,
+    /// The input can't be unlocked by any of the provided addresses.
+    NotOwned,
+    /// The output type can't be used as input.
+    UnsupportedOutputType,
+}
+
+/// The shortfall that remained when input selection failed.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Shortfall {
+    /// Missing base coin amount.
+    Amount {
+        /// The missing amount.
+        amount: u64,
+    },
+    /// Missing native token amount.
+    NativeToken {
+        /// The token ID.
+        token_id: TokenId,
+        /// The missing amount.
+        amount: U256,
+    },
+}
+
+impl Display for InputSelectionDiagnostics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} fulfilled requirements", self.fulfilled_requirements.len())?;
+
+        if let Some(requirement) = &self.failed_requirement {
+            write!(f, ", failed requirement {requirement:?}")?;
+        }
+
+        match &self.shortfall {
+            Some(Shortfall::Amount { amount }) => write!(f, ", missing amount {amount}")?,
+            Some(Shortfall::NativeToken { token_id, amount }) => {
+                write!(f, ", missing native token amount {amount} of {token_id}")?
+            }
+            None => {}
+        }
+
+        if !self.rejected_inputs.is_empty() {
+            write!(f, ", rejected inputs:")?;
+
+            for rejected_input in &self.rejected_inputs {
+                write!(f, " {} ({:?})", rejected_input.output_id, rejected_input.reason)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl InputSelection {
+    /// Records a rejected candidate input if diagnostics are enabled, the first reason of an input is kept.
+    pub(crate) fn reject_input(&mut self, output_id: OutputId, reason: InputRejectionReason) {
+        if !self.diagnostics_enabled {
+            return;
+        }
+
+        if self.rejected_input_ids.insert(output_id) {
+            self.diagnostics
+                .rejected_inputs
+                .push(RejectedInput::new(output_id, reason));
+        }
+    }
+
+    /// Completes the diagnostics with what can be derived from the error and returns them together.
+    pub(crate) fn diagnose(&mut self, error: Error) -> DiagnosedError {
+        self.diagnostics.shortfall = match &error {
+            Error::InsufficientAmount { found, required } => Some(Shortfall::Amount {
+                amount: required - found,
+            }),
+            Error::InsufficientNativeTokenAmount {
+                token_id,
+                found,
+                required,
+            } => Some(Shortfall::NativeToken {
+                token_id: *token_id,
+                amount: required - found,
+            }),
+            _ => None,
+        };
+
+        // Inputs with a storage deposit return of their full amount are skipped by the amount requirement.
+        let timestamp = self.timestamp;
+        let storage_deposit_return_inputs = self
+            .available_inputs
+            .iter()
+            .filter(|input| {
+                input.output.unlock_conditions().is_some()
+                    && sdruc_not_expired(&input.output, timestamp)
+                        .map_or(false, |sdruc| sdruc.amount() == input.output.amount())
+            })
+            .map(|input| *input.output_id())
+            .collect::<Vec<_>>();
+
+        for output_id in storage_deposit_return_inputs {
+            self.reject_input(output_id, InputRejectionReason::StorageDepositReturn);
+        }
+
+        DiagnosedError {
+            error,
+            diagnostics: Box::new(std::mem::take(&mut self.diagnostics)),
+        }
+    }
+}
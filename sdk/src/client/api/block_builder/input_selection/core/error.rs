@@ -48,6 +48,14 @@ pub enum Error {
     /// Invalid amount of outputs.
     #[error("invalid amount of outputs: {0}")]
     InvalidOutputCount(usize),
+    /// Remainder denomination doesn't cover the storage deposit of an output.
+    #[error("invalid remainder denomination {denomination}: minimum is {minimum}")]
+    InvalidRemainderDenomination {
+        /// The denomination.
+        denomination: u64,
+        /// The minimum storage deposit of a remainder output.
+        minimum: u64,
+    },
     /// No input with matching ed25519 address provided.
     #[error("no input with matching ed25519 address provided")]
     MissingInputWithEd25519Address,
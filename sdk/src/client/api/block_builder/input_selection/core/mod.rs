@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod burn;
+pub(crate) mod coin_selection;
+pub(crate) mod diagnostics;
 pub(crate) mod error;
 pub(crate) mod remainder;
 pub(crate) mod requirement;
@@ -15,7 +17,12 @@ pub(crate) use requirement::is_alias_transition;
 
 pub use self::{
     burn::{Burn, BurnDto},
+    coin_selection::CoinSelectionStrategy,
+    diagnostics::{
+        DiagnosedError, FulfilledRequirement, InputRejectionReason, InputSelectionDiagnostics, RejectedInput, Shortfall,
+    },
     error::Error,
+    remainder::RemainderSplitStrategy,
     requirement::Requirement,
 };
 use crate::{
@@ -42,10 +49,15 @@ pub struct InputSelection {
     addresses: HashSet<Address>,
     burn: Option<Burn>,
     remainder_address: Option<Address>,
+    remainder_split_strategy: RemainderSplitStrategy,
     protocol_parameters: ProtocolParameters,
     timestamp: u32,
     requirements: Vec<Requirement>,
     automatically_transitioned: HashMap<ChainId, Option<AliasTransition>>,
+    coin_selection_strategy: CoinSelectionStrategy,
+    diagnostics_enabled: bool,
+    diagnostics: InputSelectionDiagnostics,
+    rejected_input_ids: HashSet<OutputId>,
 }
 
 /// Result of the input selection algorithm.
@@ -55,8 +67,9 @@ pub struct Selected {
     pub inputs: Vec<InputSigningData>,
     /// Provided and created outputs.
     pub outputs: Vec<Output>,
-    /// Remainder, if there was one.
-    pub remainder: Option<RemainderData>,
+    /// Remainders, if there were some. The first one holds the native tokens, the others are split off by the
+    /// [`RemainderSplitStrategy`].
+    pub remainders: Vec<RemainderData>,
 }
 
 impl InputSelection {
@@ -119,6 +132,17 @@ impl InputSelection {
         self.requirements.push(Requirement::NativeTokens);
 
         // Removes forbidden inputs from available inputs.
+        if self.diagnostics_enabled {
+            let forbidden_inputs = self
+                .available_inputs
+                .iter()
+                .map(|input| *input.output_id())
+                .filter(|output_id| self.forbidden_inputs.contains(output_id))
+                .collect::<Vec<_>>();
+            for output_id in forbidden_inputs {
+                self.reject_input(output_id, InputRejectionReason::Forbidden);
+            }
+        }
         self.available_inputs
             .retain(|input| !self.forbidden_inputs.contains(input.output_id()));
 
@@ -187,10 +211,15 @@ impl InputSelection {
             addresses,
             burn: None,
             remainder_address: None,
+            remainder_split_strategy: RemainderSplitStrategy::default(),
             protocol_parameters,
             timestamp: unix_timestamp_now().as_secs() as u32,
             requirements: Vec::new(),
             automatically_transitioned: HashMap::new(),
+            coin_selection_strategy: CoinSelectionStrategy::default(),
+            diagnostics_enabled: false,
+            diagnostics: InputSelectionDiagnostics::default(),
+            rejected_input_ids: HashSet::new(),
         }
     }
 
@@ -218,13 +247,38 @@ impl InputSelection {
         self
     }
 
+    /// Sets the remainder split strategy of an [`InputSelection`].
+    pub fn remainder_split_strategy(mut self, remainder_split_strategy: RemainderSplitStrategy) -> Self {
+        self.remainder_split_strategy = remainder_split_strategy;
+        self
+    }
+
     /// Sets the timestamp of an [`InputSelection`].
     pub fn timestamp(mut self, timestamp: u32) -> Self {
         self.timestamp = timestamp;
         self
     }
 
+    /// Sets the coin selection strategy of an [`InputSelection`].
+    pub fn coin_selection_strategy(mut self, coin_selection_strategy: CoinSelectionStrategy) -> Self {
+        self.coin_selection_strategy = coin_selection_strategy;
+        self
+    }
+
+    /// Sets the inputs that were rejected before being provided to an [`InputSelection`], so they are part of the
+    /// diagnostics of [`InputSelection::select_with_diagnostics()`].
+    pub fn rejected_inputs(mut self, rejected_inputs: impl IntoIterator<Item = RejectedInput>) -> Self {
+        for rejected_input in rejected_inputs {
+            if self.rejected_input_ids.insert(rejected_input.output_id) {
+                self.diagnostics.rejected_inputs.push(rejected_input);
+            }
+        }
+        self
+    }
+
     fn filter_inputs(&mut self) {
+        let mut rejected_inputs = Vec::new();
+
         self.available_inputs.retain(|input| {
             // Keep alias outputs because at this point we do not know if a state or governor address will be required.
             if input.output.is_alias() {
@@ -232,6 +286,7 @@ impl InputSelection {
             }
             // Filter out non basic/foundry/nft outputs.
             else if !input.output.is_basic() && !input.output.is_foundry() && !input.output.is_nft() {
+                rejected_inputs.push((*input.output_id(), InputRejectionReason::UnsupportedOutputType));
                 return false;
             }
 
@@ -239,6 +294,7 @@ impl InputSelection {
             let unlock_conditions = input.output.unlock_conditions().unwrap();
 
             if unlock_conditions.is_time_locked(self.timestamp) {
+                rejected_inputs.push((*input.output_id(), InputRejectionReason::Timelocked));
                 return false;
             }
 
@@ -250,8 +306,22 @@ impl InputSelection {
                 .unwrap()
                 .0;
 
-            self.addresses.contains(&required_address)
-        })
+            if self.addresses.contains(&required_address) {
+                true
+            } else {
+                let reason = if unlock_conditions.is_expired(self.timestamp) {
+                    InputRejectionReason::Expiration
+                } else {
+                    InputRejectionReason::NotOwned
+                };
+                rejected_inputs.push((*input.output_id(), reason));
+                false
+            }
+        });
+
+        for (output_id, reason) in rejected_inputs {
+            self.reject_input(output_id, reason);
+        }
     }
 
     // Inputs need to be sorted before signing, because the reference unlock conditions can only reference a lower index
@@ -361,6 +431,17 @@ impl InputSelection {
     /// Selects inputs that meet the requirements of the outputs to satisfy the semantic validation of the overall
     /// transaction. Also creates a remainder output and chain transition outputs if required.
     pub fn select(mut self) -> Result<Selected, Error> {
+        self.select_inner()
+    }
+
+    /// Same as [`InputSelection::select()`], but also traces the requirement fulfilment and returns it together with
+    /// the error if input selection fails.
+    pub fn select_with_diagnostics(mut self) -> Result<Selected, DiagnosedError> {
+        self.diagnostics_enabled = true;
+        self.select_inner().map_err(|error| self.diagnose(error))
+    }
+
+    fn select_inner(&mut self) -> Result<Selected, Error> {
         if !OUTPUT_COUNT_RANGE.contains(&(self.outputs.len() as u16)) {
             // If burn is provided, outputs will be added later
             if !(self.outputs.is_empty() && self.burn.is_some()) {
@@ -380,7 +461,20 @@ impl InputSelection {
         // Process all the requirements until there are no more.
         while let Some(requirement) = self.requirements.pop() {
             // Fulfill the requirement.
-            let inputs = self.fulfill_requirement(requirement)?;
+            let inputs = match self.fulfill_requirement(requirement) {
+                Ok(inputs) => inputs,
+                Err(error) => {
+                    self.diagnostics.failed_requirement = Some(requirement);
+                    return Err(error);
+                }
+            };
+
+            if self.diagnostics_enabled {
+                self.diagnostics.fulfilled_requirements.push(FulfilledRequirement {
+                    requirement,
+                    selected_inputs: inputs.iter().map(|(input, _)| *input.output_id()).collect(),
+                });
+            }
 
             // Select suggested inputs.
             for (input, alias_transition) in inputs {
@@ -392,12 +486,10 @@ impl InputSelection {
             return Err(Error::InvalidInputCount(self.selected_inputs.len()));
         }
 
-        let (remainder, storage_deposit_returns) = self.remainder_and_storage_deposit_return_outputs()?;
-
-        if let Some(remainder) = &remainder {
-            self.outputs.push(remainder.output.clone());
-        }
+        let (remainders, storage_deposit_returns) = self.remainder_and_storage_deposit_return_outputs()?;
 
+        self.outputs
+            .extend(remainders.iter().map(|remainder| remainder.output.clone()));
         self.outputs.extend(storage_deposit_returns);
 
         // Check again, because more outputs may have been added.
@@ -408,9 +500,13 @@ impl InputSelection {
         self.validate_transitions()?;
 
         Ok(Selected {
-            inputs: Self::sort_input_signing_data(self.selected_inputs, &self.outputs, Some(self.timestamp))?,
-            outputs: self.outputs,
-            remainder,
+            inputs: Self::sort_input_signing_data(
+                std::mem::take(&mut self.selected_inputs),
+                &self.outputs,
+                Some(self.timestamp),
+            )?,
+            outputs: std::mem::take(&mut self.outputs),
+            remainders,
         })
     }
 
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crypto::keys::slip10::Chain;
+use serde::{Deserialize, Serialize};
 
 use super::{
     requirement::{
@@ -15,10 +16,34 @@ use crate::{
     client::api::RemainderData,
     types::block::{
         address::{Address, Ed25519Address},
-        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokensBuilder, Output},
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder, Output,
+            OUTPUT_COUNT_MAX,
+        },
     },
 };
 
+/// The strategy used to split the remainder value into multiple outputs, so that they can be spent independently by
+/// later transactions.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(tag = "strategy", content = "value")]
+pub enum RemainderSplitStrategy {
+    /// Create a single remainder output.
+    Single,
+    /// Split the remainder value into this many outputs of about the same amount. Fewer outputs are created if the
+    /// value doesn't cover the storage deposit of all of them.
+    Outputs(u16),
+    /// Split the remainder value into outputs of these amounts, largest first. The value that can't be split this
+    /// way stays in the remainder output that also holds the native tokens.
+    Denominations(Vec<u64>),
+}
+
+impl Default for RemainderSplitStrategy {
+    fn default() -> Self {
+        Self::Single
+    }
+}
+
 impl InputSelection {
     // Gets the remainder address from configuration of finds one from the inputs.
     fn get_remainder_address(&self) -> Option<(Address, Option<Chain>)> {
@@ -82,9 +107,82 @@ impl InputSelection {
         ))
     }
 
+    // Splits the remainder value according to the remainder split strategy. Returns the amount that stays in the main
+    // remainder output and the amounts of the additional remainder outputs.
+    fn split_remainder_amount(
+        &self,
+        amount: u64,
+        remainder_address: Address,
+        native_tokens: Option<&NativeTokens>,
+        max_split_outputs: usize,
+    ) -> Result<(u64, Vec<u64>), Error> {
+        let rent_structure = *self.protocol_parameters.rent_structure();
+        let token_supply = self.protocol_parameters.token_supply();
+
+        let mut remainder_builder = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+            .add_unlock_condition(AddressUnlockCondition::new(remainder_address));
+        let split_minimum = remainder_builder.clone().finish_output(token_supply)?.amount();
+        if let Some(native_tokens) = native_tokens {
+            remainder_builder = remainder_builder.with_native_tokens(native_tokens.clone());
+        }
+        let remainder_minimum = remainder_builder.finish_output(token_supply)?.amount();
+
+        match &self.remainder_split_strategy {
+            RemainderSplitStrategy::Single => Ok((amount, Vec::new())),
+            RemainderSplitStrategy::Outputs(count) => {
+                let mut count = (*count as usize).min(max_split_outputs + 1).max(1) as u64;
+
+                // Reduce the number of outputs until all of them cover their storage deposit, the main remainder gets
+                // what can't be divided evenly.
+                while count > 1 {
+                    let split_amount = amount / count;
+                    let main_amount = amount - split_amount * (count - 1);
+
+                    if split_amount >= split_minimum && main_amount >= remainder_minimum {
+                        return Ok((main_amount, vec![split_amount; count as usize - 1]));
+                    }
+                    count -= 1;
+                }
+
+                Ok((amount, Vec::new()))
+            }
+            RemainderSplitStrategy::Denominations(denominations) => {
+                let mut denominations = denominations.clone();
+                denominations.sort_unstable_by(|a, b| b.cmp(a));
+                denominations.dedup();
+
+                if let Some(denomination) = denominations
+                    .last()
+                    .filter(|denomination| **denomination < split_minimum)
+                {
+                    return Err(Error::InvalidRemainderDenomination {
+                        denomination: *denomination,
+                        minimum: split_minimum,
+                    });
+                }
+
+                let mut main_amount = amount;
+                let mut split_amounts = Vec::new();
+
+                for denomination in denominations {
+                    while split_amounts.len() < max_split_outputs
+                        && main_amount.saturating_sub(denomination) >= remainder_minimum
+                    {
+                        main_amount -= denomination;
+                        split_amounts.push(denomination);
+                    }
+                }
+
+                Ok((main_amount, split_amounts))
+            }
+        }
+    }
+
+    // Returns the remainders and the storage deposit return outputs. The main remainder, which also holds the native
+    // tokens, comes first and is followed by the additional remainders created by the remainder split strategy.
     pub(crate) fn remainder_and_storage_deposit_return_outputs(
         &self,
-    ) -> Result<(Option<RemainderData>, Vec<Output>), Error> {
+    ) -> Result<(Vec<RemainderData>, Vec<Output>), Error> {
         let (inputs_sum, outputs_sum, inputs_sdr, outputs_sdr) =
             amount_sums(&self.selected_inputs, &self.outputs, self.timestamp);
         let mut storage_deposit_returns = Vec::new();
@@ -122,7 +220,7 @@ impl InputSelection {
 
         if inputs_sum == outputs_sum && native_tokens_diff.is_none() {
             log::debug!("No remainder required");
-            return Ok((None, storage_deposit_returns));
+            return Ok((Vec::new(), storage_deposit_returns));
         }
 
         let Some((remainder_address, chain)) = self.get_remainder_address() else {
@@ -130,6 +228,11 @@ impl InputSelection {
         };
 
         let diff = inputs_sum - outputs_sum;
+        // The main remainder output, the storage deposit returns and the provided outputs take their slots first.
+        let max_split_outputs =
+            (OUTPUT_COUNT_MAX as usize).saturating_sub(self.outputs.len() + storage_deposit_returns.len() + 1);
+        let (diff, split_amounts) =
+            self.split_remainder_amount(diff, remainder_address, native_tokens_diff.as_ref(), max_split_outputs)?;
         let mut remainder_builder = BasicOutputBuilder::new_with_amount(diff);
 
         remainder_builder = remainder_builder.add_unlock_condition(AddressUnlockCondition::new(remainder_address));
@@ -148,13 +251,29 @@ impl InputSelection {
             self.protocol_parameters.token_supply(),
         )?;
 
-        Ok((
-            Some(RemainderData {
-                output: remainder,
-                chain,
-                address: remainder_address,
-            }),
-            storage_deposit_returns,
-        ))
+        if !split_amounts.is_empty() {
+            log::debug!(
+                "Split {} remainder outputs off for {remainder_address:?}",
+                split_amounts.len()
+            );
+        }
+
+        let remainders = [Ok(remainder)]
+            .into_iter()
+            .chain(split_amounts.into_iter().map(|amount| {
+                BasicOutputBuilder::new_with_amount(amount)
+                    .add_unlock_condition(AddressUnlockCondition::new(remainder_address))
+                    .finish_output(self.protocol_parameters.token_supply())
+            }))
+            .map(|output| {
+                Ok(RemainderData {
+                    output: output?,
+                    chain: chain.clone(),
+                    address: remainder_address,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok((remainders, storage_deposit_returns))
     }
 }
@@ -1,9 +1,9 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use super::{Error, InputSelection, Requirement};
+use super::{CoinSelectionStrategy, Error, InputSelection, Requirement};
 use crate::{
     client::secret::types::InputSigningData,
     types::block::{
@@ -294,15 +294,11 @@ impl InputSelection {
             );
         }
 
-        // TODO if consolidate strategy: sum all the lowest amount until diff is covered.
-        // TODO this would be lowest amount of input strategy.
+        // Try to select outputs first with the ordering of the coin selection strategy, if that fails, try from high
+        // to low amount.
 
-        // Try to select outputs first with ordering from low to high amount, if that fails, try reversed.
-
-        log::debug!("Ordering inputs from low to high amount");
-        // Sort inputs per amount, low to high.
-        self.available_inputs
-            .sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
+        log::debug!("Ordering inputs with the {:?} strategy", self.coin_selection_strategy);
+        self.order_inputs(&amount_selection);
 
         if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
             return Ok(r);
@@ -418,3 +414,174 @@ impl InputSelection {
         }
     }
 }
+
+/// Maximum number of branches explored when searching for an exact match.
+const EXACT_MATCH_MAX_TRIES: usize = 100_000;
+
+impl InputSelection {
+    /// Orders the available inputs according to the coin selection strategy.
+    fn order_inputs(&mut self, amount_selection: &AmountSelection) {
+        // Sort inputs per amount, low to high, which is also the tie breaker of the other strategies.
+        self.available_inputs
+            .sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
+
+        match self.coin_selection_strategy {
+            CoinSelectionStrategy::Default => {}
+            CoinSelectionStrategy::MinimizeInputCount => {
+                self.available_inputs
+                    .sort_by(|left, right| right.output.amount().cmp(&left.output.amount()));
+            }
+            CoinSelectionStrategy::ExactMatch => {
+                if let Some(exact_match) = self.exact_match(amount_selection) {
+                    log::debug!("Found exact match {exact_match:?}");
+                    // Matching inputs first, they are the first ones to be considered by `fulfil`.
+                    self.available_inputs
+                        .sort_by_key(|input| !exact_match.contains(input.output_id()));
+                }
+            }
+            CoinSelectionStrategy::OldestFirst => {
+                self.available_inputs
+                    .sort_by_key(|input| input.output_metadata.milestone_timestamp_booked());
+            }
+            CoinSelectionStrategy::AvoidAddressMixing => {
+                if let Some(address) = self.single_funding_address(amount_selection) {
+                    log::debug!("Selecting inputs of {address:?} only");
+                    let timestamp = self.timestamp;
+                    self.available_inputs
+                        .sort_by_key(|input| simple_basic_input_address(input, timestamp) != Some(address));
+                }
+            }
+        }
+    }
+
+    /// Searches, with a bounded branch and bound, for inputs whose amounts sum up to exactly the missing amount.
+    fn exact_match(&self, amount_selection: &AmountSelection) -> Option<HashSet<OutputId>> {
+        // Only an exact match of the outputs avoids the remainder, if there are native tokens a remainder is needed
+        // anyway.
+        if amount_selection.inputs_sum >= amount_selection.outputs_sum || amount_selection.native_tokens_remainder {
+            return None;
+        }
+
+        let target = amount_selection.outputs_sum - amount_selection.inputs_sum;
+        let max_inputs = usize::from(INPUT_COUNT_MAX).saturating_sub(self.selected_inputs.len());
+
+        let mut candidates = self
+            .available_inputs
+            .iter()
+            .filter(|input| simple_basic_input_address(input, self.timestamp).is_some())
+            .map(|input| (*input.output_id(), input.output.amount()))
+            .collect::<Vec<_>>();
+        // Highest amounts first to find a match with few inputs and prune early.
+        candidates.sort_by(|left, right| right.1.cmp(&left.1));
+
+        let amounts = candidates.iter().map(|(_, amount)| *amount).collect::<Vec<_>>();
+        let mut suffix_sums = vec![0; amounts.len() + 1];
+        for index in (0..amounts.len()).rev() {
+            suffix_sums[index] = suffix_sums[index + 1] + amounts[index];
+        }
+
+        let mut selection = Vec::new();
+        let mut tries = 0;
+
+        if exact_match_search(
+            &amounts,
+            &suffix_sums,
+            0,
+            target,
+            max_inputs,
+            &mut selection,
+            &mut tries,
+        ) {
+            Some(selection.into_iter().map(|index| candidates[index].0).collect())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the address whose inputs can fund the missing amount alone, preferring addresses that are already used
+    /// by the selected inputs and then the one with the lowest balance.
+    fn single_funding_address(&self, amount_selection: &AmountSelection) -> Option<Address> {
+        let missing_amount = amount_selection.missing_amount();
+        let mut balances = HashMap::<Address, u64>::new();
+
+        for input in &self.available_inputs {
+            if let Some(address) = simple_basic_input_address(input, self.timestamp) {
+                *balances.entry(address).or_default() += input.output.amount();
+            }
+        }
+
+        let used_addresses = self
+            .selected_inputs
+            .iter()
+            .filter_map(|input| simple_basic_input_address(input, self.timestamp))
+            .collect::<HashSet<_>>();
+
+        balances
+            .into_iter()
+            .filter(|(_, balance)| *balance >= missing_amount)
+            .min_by_key(|(address, balance)| (!used_addresses.contains(address), *balance, *address))
+            .map(|(address, _)| address)
+    }
+}
+
+/// Returns the address of a basic input unlocked by an Ed25519 address, without native tokens and without unexpired
+/// storage deposit return, which are the inputs that `fulfil` considers first.
+fn simple_basic_input_address(input: &InputSigningData, timestamp: u32) -> Option<Address> {
+    if let Output::Basic(output) = &input.output {
+        let address = output.unlock_conditions().locked_address(output.address(), timestamp);
+
+        if address.is_ed25519()
+            && output.native_tokens().is_empty()
+            && sdruc_not_expired(&input.output, timestamp).is_none()
+        {
+            return Some(*address);
+        }
+    }
+
+    None
+}
+
+fn exact_match_search(
+    amounts: &[u64],
+    suffix_sums: &[u64],
+    index: usize,
+    target: u64,
+    max_inputs: usize,
+    selection: &mut Vec<usize>,
+    tries: &mut usize,
+) -> bool {
+    if target == 0 {
+        return true;
+    }
+    if index == amounts.len()
+        || selection.len() == max_inputs
+        || suffix_sums[index] < target
+        || *tries >= EXACT_MATCH_MAX_TRIES
+    {
+        return false;
+    }
+
+    *tries += 1;
+
+    // Branch including the input.
+    if amounts[index] <= target {
+        selection.push(index);
+
+        if exact_match_search(
+            amounts,
+            suffix_sums,
+            index + 1,
+            target - amounts[index],
+            max_inputs,
+            selection,
+            tries,
+        ) {
+            return true;
+        }
+
+        selection.pop();
+    }
+
+    // Branch excluding the input.
+    exact_match_search(amounts, suffix_sums, index + 1, target, max_inputs, selection, tries)
+}
@@ -12,6 +12,10 @@ mod utxo_chains;
 
 pub(crate) use self::core::is_alias_transition;
 pub use self::{
-    core::{Burn, BurnDto, Error, InputSelection, Requirement, Selected},
+    core::{
+        Burn, BurnDto, CoinSelectionStrategy, DiagnosedError, Error, FulfilledRequirement, InputRejectionReason,
+        InputSelection, InputSelectionDiagnostics, RejectedInput, RemainderSplitStrategy, Requirement, Selected,
+        Shortfall,
+    },
     helpers::minimum_storage_deposit_basic_output,
 };
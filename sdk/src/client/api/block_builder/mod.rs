@@ -3,6 +3,7 @@
 
 pub mod input_selection;
 pub mod pow;
+pub mod submission;
 pub mod transaction;
 
 use std::ops::Range;
@@ -10,7 +11,10 @@ use std::ops::Range;
 use packable::bounded::TryIntoBoundedU16Error;
 use serde::{Deserialize, Serialize};
 
-pub use self::transaction::verify_semantic;
+pub use self::{
+    submission::{BlockSubmissionMetrics, BlockSubmissionOptions, ParentsStrategy, PowStrategy},
+    transaction::verify_semantic,
+};
 use crate::{
     client::{
         api::block_builder::input_selection::Burn, constants::SHIMMER_COIN_TYPE, secret::SecretManager, Client, Error,
@@ -356,11 +360,10 @@ impl<'a> ClientBlockBuilder<'a> {
     pub async fn finish_block(self, payload: Option<Payload>) -> Result<Block> {
         // Do not replace parents with the latest tips if they are set explicitly,
         // necessary for block promotion.
-        let final_block = self.client.finish_block_builder(self.parents, payload).await?;
-
-        let block_id = self.client.post_block_raw(&final_block).await?;
+        let (final_block, metrics) = self.client.submit_block(self.parents, payload).await?;
+        let block_id = metrics.block_id;
         // Get block if we use remote PoW, because the node will change parents and nonce
-        if self.client.get_local_pow().await {
+        if metrics.local_pow {
             Ok(final_block)
         } else {
             // Request block multiple times because the node maybe didn't process it completely in this time
@@ -16,7 +16,18 @@ impl ClientInner {
     /// Finishes the block with local PoW if needed.
     /// Without local PoW, it will finish the block with a 0 nonce.
     pub async fn finish_block_builder(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
-        if self.get_local_pow().await {
+        self.finish_block_with_pow(parents, payload, self.get_local_pow().await)
+            .await
+    }
+
+    /// Finishes the block with local PoW if `local_pow` is set, ignoring the configured local PoW.
+    pub(crate) async fn finish_block_with_pow(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+        local_pow: bool,
+    ) -> Result<Block> {
+        if local_pow {
             self.finish_pow(parents, payload).await
         } else {
             // Finish block without doing PoW.
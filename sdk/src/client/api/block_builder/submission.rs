@@ -0,0 +1,439 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Strategy for building and posting blocks.
+
+use std::{collections::VecDeque, future::Future, str::FromStr};
+
+use instant::Instant;
+use packable::PackableExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{node_api::error::Error as NodeApiError, ClientInner, Error, Result},
+    types::{
+        api::core::response::SubmitBlockResponse,
+        block::{parent::Parents, payload::Payload, Block, BlockId},
+    },
+    utils::unix_timestamp_now,
+};
+
+/// Number of metrics of submitted blocks that are kept.
+pub(crate) const BLOCK_SUBMISSION_METRICS_MAX: usize = 100;
+/// Own blocks older than this are not used as parents anymore, so the node doesn't reject them as too old.
+const OWN_BLOCKS_MAX_AGE_IN_MILLIS: u128 = 30_000;
+/// Maximum number of own blocks used as parents, the rest are tips from the node.
+const OWN_BLOCKS_PARENTS_MAX: usize = 4;
+
+/// How the proof of work of a block is done.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum PowStrategy {
+    /// Do the proof of work as configured with the local PoW option of the client.
+    Configured,
+    /// Do the proof of work locally.
+    Local,
+    /// Let the node do the proof of work.
+    Remote,
+    /// Use local or remote proof of work, whichever was faster for the recently submitted blocks. Each of them is
+    /// measured once before they're compared.
+    Fastest,
+}
+
+impl Default for PowStrategy {
+    fn default() -> Self {
+        Self::Configured
+    }
+}
+
+/// How the parents of a block are chosen, if they're not provided explicitly.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(tag = "strategy", content = "value")]
+pub enum ParentsStrategy {
+    /// Use tips of the node.
+    NodeTips,
+    /// Use the latest blocks submitted by this client, so own blocks build on each other, and fill up with tips of
+    /// the node.
+    OwnBlocks,
+    /// Use these blocks.
+    Custom(Vec<BlockId>),
+}
+
+impl Default for ParentsStrategy {
+    fn default() -> Self {
+        Self::NodeTips
+    }
+}
+
+/// Options for building and posting blocks.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockSubmissionOptions {
+    /// How the proof of work is done.
+    #[serde(default)]
+    pub pow_strategy: PowStrategy,
+    /// How the parents are chosen.
+    #[serde(default)]
+    pub parents_strategy: ParentsStrategy,
+    /// How often a block is built again with new tips, if the node rejects its parents as too old.
+    #[serde(default = "default_max_retips")]
+    pub max_retips: u8,
+}
+
+fn default_max_retips() -> u8 {
+    3
+}
+
+impl Default for BlockSubmissionOptions {
+    fn default() -> Self {
+        Self {
+            pow_strategy: PowStrategy::default(),
+            parents_strategy: ParentsStrategy::default(),
+            max_retips: default_max_retips(),
+        }
+    }
+}
+
+/// Metrics of a submitted block.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockSubmissionMetrics {
+    /// The id of the block, as returned by the node.
+    pub block_id: BlockId,
+    /// The parents the block was built with, the node replaces them when it does the proof of work.
+    pub parents: Vec<BlockId>,
+    /// Whether the proof of work was done locally.
+    pub local_pow: bool,
+    /// Milliseconds it took to build the block, including local proof of work.
+    pub build_duration: u64,
+    /// Milliseconds it took to post the block, including remote proof of work.
+    pub post_duration: u64,
+    /// How often the block was built again with new tips.
+    pub retips: u8,
+    /// Milliseconds since the unix epoch when the block was posted.
+    pub timestamp: u128,
+}
+
+/// Step of a block submission, reported before it starts.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum BlockSubmissionProgress {
+    /// The proof of work is done locally.
+    PerformingPow,
+    /// The block is posted to the node.
+    Broadcasting,
+}
+
+/// State of the block submission.
+#[derive(Debug, Default)]
+pub(crate) struct BlockSubmission {
+    pub(crate) options: BlockSubmissionOptions,
+    // Latest submitted blocks with their timestamp, latest first
+    own_blocks: VecDeque<(BlockId, u128)>,
+    // Moving averages of the milliseconds it took to build and post a block
+    local_pow_duration: Option<u64>,
+    remote_pow_duration: Option<u64>,
+    metrics: VecDeque<BlockSubmissionMetrics>,
+}
+
+impl BlockSubmission {
+    pub(crate) fn new(options: BlockSubmissionOptions) -> Self {
+        Self {
+            options,
+            ..Default::default()
+        }
+    }
+
+    fn record(&mut self, metrics: BlockSubmissionMetrics) {
+        let duration = metrics.build_duration + metrics.post_duration;
+        let average = if metrics.local_pow {
+            &mut self.local_pow_duration
+        } else {
+            &mut self.remote_pow_duration
+        };
+        *average = Some(average.map_or(duration, |average| {
+            average.saturating_mul(3).saturating_add(duration) / 4
+        }));
+
+        self.own_blocks.push_front((metrics.block_id, metrics.timestamp));
+        self.own_blocks.truncate(OWN_BLOCKS_PARENTS_MAX);
+
+        if self.metrics.len() == BLOCK_SUBMISSION_METRICS_MAX {
+            self.metrics.pop_front();
+        }
+        self.metrics.push_back(metrics);
+    }
+
+    // Whether local proof of work was faster for the recently submitted blocks, each of them is measured once first
+    fn fastest_local_pow(&self) -> bool {
+        match (self.local_pow_duration, self.remote_pow_duration) {
+            (Some(local), Some(remote)) => local <= remote,
+            (Some(_), None) => false,
+            (None, _) => true,
+        }
+    }
+}
+
+impl ClientInner {
+    /// Returns the options for building and posting blocks.
+    pub async fn get_block_submission_options(&self) -> BlockSubmissionOptions {
+        self.block_submission.read().await.options.clone()
+    }
+
+    /// Sets the options for building and posting blocks.
+    pub async fn set_block_submission_options(&self, options: BlockSubmissionOptions) {
+        self.block_submission.write().await.options = options;
+    }
+
+    /// Returns the metrics of the latest blocks submitted with [`ClientInner::submit_block()`], oldest first.
+    pub async fn get_block_submission_metrics(&self) -> Vec<BlockSubmissionMetrics> {
+        self.block_submission.read().await.metrics.iter().cloned().collect()
+    }
+
+    /// Returns whether the next block is submitted with local proof of work, according to the [`PowStrategy`].
+    pub async fn get_block_submission_local_pow(&self) -> bool {
+        let (pow_strategy, fastest_local_pow) = {
+            let block_submission = self.block_submission.read().await;
+            (
+                block_submission.options.pow_strategy,
+                block_submission.fastest_local_pow(),
+            )
+        };
+
+        match pow_strategy {
+            PowStrategy::Configured => self.get_local_pow().await,
+            PowStrategy::Local => true,
+            PowStrategy::Remote => false,
+            PowStrategy::Fastest => fastest_local_pow,
+        }
+    }
+
+    /// Builds a block with the payload and posts it, according to the [`BlockSubmissionOptions`]. The parents are only
+    /// chosen by the [`ParentsStrategy`] if none are provided. If the node rejects the parents as too old, the block is
+    /// built again with new tips, unless the parents were provided. Returns the built block, with remote proof of work
+    /// the node replaces its parents and nonce, and the metrics of the submission.
+    pub async fn submit_block(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+    ) -> Result<(Block, BlockSubmissionMetrics)> {
+        self.submit_block_with_progress(parents, payload, |_| async {}).await
+    }
+
+    /// Submits a block like [`ClientInner::submit_block()`] and awaits `on_progress` before each step, so the caller
+    /// learns whether the proof of work is done locally from the same decision that's used for the block.
+    pub(crate) async fn submit_block_with_progress<F, Fut>(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+        mut on_progress: F,
+    ) -> Result<(Block, BlockSubmissionMetrics)>
+    where
+        F: FnMut(BlockSubmissionProgress) -> Fut + Send,
+        Fut: Future<Output = ()> + Send,
+    {
+        let options = self.get_block_submission_options().await;
+        let mut local_pow = self.get_block_submission_local_pow().await;
+        let mut retips = 0;
+
+        loop {
+            let block_parents = match (&parents, retips) {
+                (Some(parents), _) => Some(parents.clone()),
+                // Retips always use new tips of the node
+                (None, 0) => self.block_submission_parents(&options.parents_strategy).await?,
+                (None, _) => None,
+            };
+
+            if local_pow {
+                on_progress(BlockSubmissionProgress::PerformingPow).await;
+            }
+            let build_start = Instant::now();
+            let block = self
+                .finish_block_with_pow(block_parents, payload.clone(), local_pow)
+                .await?;
+            let build_duration = build_start.elapsed().as_millis() as u64;
+
+            on_progress(BlockSubmissionProgress::Broadcasting).await;
+
+            let post_start = Instant::now();
+            match self.post_block_with_pow(&block, local_pow).await {
+                Ok(block_id) => {
+                    let metrics = BlockSubmissionMetrics {
+                        block_id,
+                        parents: block.parents().iter().copied().collect(),
+                        local_pow,
+                        build_duration,
+                        post_duration: post_start.elapsed().as_millis() as u64,
+                        retips,
+                        timestamp: unix_timestamp_now().as_millis(),
+                    };
+                    log::debug!("[submit_block] {metrics:?}");
+                    self.block_submission.write().await.record(metrics.clone());
+
+                    return Ok((block, metrics));
+                }
+                Err(Error::Node(NodeApiError::UnavailablePow))
+                    if !local_pow && self.get_fallback_to_local_pow().await =>
+                {
+                    log::debug!("[submit_block] no node available for remote PoW, falling back to local PoW");
+                    // Never the fastest again
+                    self.block_submission.write().await.remote_pow_duration = Some(u64::MAX);
+                    local_pow = true;
+                }
+                Err(err) if parents.is_none() && retips < options.max_retips && is_too_old_parents_error(&err) => {
+                    log::debug!("[submit_block] parents too old, retipping: {err}");
+                    retips += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    // Returns the parents according to the strategy, `None` if the tips should be used
+    async fn block_submission_parents(&self, parents_strategy: &ParentsStrategy) -> Result<Option<Parents>> {
+        match parents_strategy {
+            ParentsStrategy::NodeTips => Ok(None),
+            ParentsStrategy::Custom(parents) => Ok(Some(Parents::from_vec(parents.clone())?)),
+            ParentsStrategy::OwnBlocks => {
+                let now = unix_timestamp_now().as_millis();
+                let mut parents = self
+                    .block_submission
+                    .read()
+                    .await
+                    .own_blocks
+                    .iter()
+                    .filter(|(_, timestamp)| now.saturating_sub(*timestamp) < OWN_BLOCKS_MAX_AGE_IN_MILLIS)
+                    .map(|(block_id, _)| *block_id)
+                    .collect::<Vec<_>>();
+
+                if parents.is_empty() {
+                    return Ok(None);
+                }
+
+                let parents_max = *Parents::COUNT_RANGE.end() as usize;
+                for tip in self.get_tips().await? {
+                    if parents.len() == parents_max {
+                        break;
+                    }
+                    if !parents.contains(&tip) {
+                        parents.push(tip);
+                    }
+                }
+
+                Ok(Some(Parents::from_vec(parents)?))
+            }
+        }
+    }
+
+    // Posts a block, without falling back to local PoW
+    async fn post_block_with_pow(&self, block: &Block, local_pow: bool) -> Result<BlockId> {
+        let timeout = if local_pow {
+            self.get_timeout().await
+        } else {
+            self.get_remote_pow_timeout().await
+        };
+
+        let response = self
+            .node_manager
+            .read()
+            .await
+            .post_request_bytes::<SubmitBlockResponse>("api/core/v2/blocks", timeout, &block.pack_to_vec(), local_pow)
+            .await?;
+
+        Ok(BlockId::from_str(&response.block_id)?)
+    }
+}
+
+// Nodes reject blocks whose parents are below max depth
+fn is_too_old_parents_error(error: &Error) -> bool {
+    match error {
+        Error::Node(NodeApiError::ResponseError { code: 400, text, .. }) => {
+            let text = text.to_lowercase();
+            text.contains("too old") || text.contains("below max depth")
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(block_id: u8, local_pow: bool, build_duration: u64, post_duration: u64) -> BlockSubmissionMetrics {
+        BlockSubmissionMetrics {
+            block_id: BlockId::new([block_id; BlockId::LENGTH]),
+            parents: Vec::new(),
+            local_pow,
+            build_duration,
+            post_duration,
+            retips: 0,
+            timestamp: block_id as u128,
+        }
+    }
+
+    fn response_error(code: u16, text: &str) -> Error {
+        Error::Node(NodeApiError::ResponseError {
+            code,
+            text: text.to_string(),
+            url: "http://localhost:14265/api/core/v2/blocks".to_string(),
+        })
+    }
+
+    #[test]
+    fn too_old_parents_error() {
+        assert!(is_too_old_parents_error(&response_error(
+            400,
+            r#"{"error":{"code":"400","message":"invalid block, error: parents are too old"}}"#
+        )));
+        assert!(is_too_old_parents_error(&response_error(
+            400,
+            "a parent is BELOW MAX DEPTH"
+        )));
+
+        assert!(!is_too_old_parents_error(&response_error(400, "invalid payload")));
+        assert!(!is_too_old_parents_error(&response_error(500, "parents are too old")));
+        assert!(!is_too_old_parents_error(&Error::Node(NodeApiError::UnavailablePow)));
+    }
+
+    #[test]
+    fn fastest_pow_is_measured_and_averaged() {
+        let mut block_submission = BlockSubmission::new(BlockSubmissionOptions {
+            pow_strategy: PowStrategy::Fastest,
+            ..Default::default()
+        });
+
+        // Local and remote proof of work are measured once first
+        assert!(block_submission.fastest_local_pow());
+        block_submission.record(metrics(1, true, 300, 100));
+        assert_eq!(block_submission.local_pow_duration, Some(400));
+        assert!(!block_submission.fastest_local_pow());
+        block_submission.record(metrics(2, false, 10, 190));
+        assert_eq!(block_submission.remote_pow_duration, Some(200));
+        assert!(!block_submission.fastest_local_pow());
+
+        // A slow remote proof of work moves the average by a quarter of the difference
+        block_submission.record(metrics(3, false, 10, 1190));
+        assert_eq!(block_submission.remote_pow_duration, Some(450));
+        assert!(block_submission.fastest_local_pow());
+        block_submission.record(metrics(4, true, 100, 100));
+        assert_eq!(block_submission.local_pow_duration, Some(350));
+        assert!(block_submission.fastest_local_pow());
+
+        // Without a node for remote proof of work, it's never the fastest again
+        block_submission.remote_pow_duration = Some(u64::MAX);
+        block_submission.record(metrics(5, true, u64::MAX / 2, u64::MAX / 2));
+        assert!(block_submission.fastest_local_pow());
+
+        // Only the latest blocks are used as parents, latest first
+        assert_eq!(
+            block_submission
+                .own_blocks
+                .iter()
+                .map(|(block_id, _)| *block_id)
+                .collect::<Vec<_>>(),
+            (2..=5)
+                .rev()
+                .map(|block_id| BlockId::new([block_id; BlockId::LENGTH]))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(block_submission.metrics.len(), 5);
+    }
+}
@@ -83,7 +83,7 @@ impl<'a> ClientBlockBuilder<'a> {
         Ok(PreparedTransactionData {
             essence,
             inputs_data: selected_transaction_data.inputs,
-            remainder: selected_transaction_data.remainder,
+            remainders: selected_transaction_data.remainders,
         })
     }
 
@@ -29,8 +29,8 @@ pub struct PreparedTransactionData {
     pub essence: TransactionEssence,
     /// Required input information for signing. Inputs need to be ordered by address type
     pub inputs_data: Vec<InputSigningData>,
-    /// Optional remainder output information
-    pub remainder: Option<RemainderData>,
+    /// Remainder output information, the remainder holding the native tokens comes first
+    pub remainders: Vec<RemainderData>,
 }
 
 /// PreparedTransactionData Dto
@@ -41,8 +41,9 @@ pub struct PreparedTransactionDataDto {
     pub essence: TransactionEssenceDto,
     /// Required address information for signing
     pub inputs_data: Vec<InputSigningDataDto>,
-    /// Optional remainder output information
-    pub remainder: Option<RemainderDataDto>,
+    /// Remainder output information, the remainder holding the native tokens comes first
+    #[serde(default)]
+    pub remainders: Vec<RemainderDataDto>,
 }
 
 impl From<&PreparedTransactionData> for PreparedTransactionDataDto {
@@ -50,7 +51,7 @@ impl From<&PreparedTransactionData> for PreparedTransactionDataDto {
         Self {
             essence: TransactionEssenceDto::from(&value.essence),
             inputs_data: value.inputs_data.iter().map(InputSigningDataDto::from).collect(),
-            remainder: value.remainder.as_ref().map(RemainderDataDto::from),
+            remainders: value.remainders.iter().map(RemainderDataDto::from).collect(),
         }
     }
 }
@@ -70,13 +71,12 @@ impl PreparedTransactionData {
                 .map(|i| InputSigningData::try_from_dto(i, protocol_parameters.token_supply()))
                 .collect::<crate::client::Result<Vec<InputSigningData>>>()
                 .map_err(|_| Error::InvalidField("input_data"))?,
-            remainder: match &value.remainder {
-                Some(remainder) => Some(
-                    RemainderData::try_from_dto(remainder, protocol_parameters.token_supply())
-                        .map_err(|_| Error::InvalidField("remainder"))?,
-                ),
-                None => None,
-            },
+            remainders: value
+                .remainders
+                .iter()
+                .map(|remainder| RemainderData::try_from_dto(remainder, protocol_parameters.token_supply()))
+                .collect::<crate::client::Result<Vec<RemainderData>>>()
+                .map_err(|_| Error::InvalidField("remainders"))?,
         })
     }
 
@@ -91,12 +91,12 @@ impl PreparedTransactionData {
                 .map(InputSigningData::try_from_dto_unverified)
                 .collect::<crate::client::Result<Vec<InputSigningData>>>()
                 .map_err(|_| Error::InvalidField("inputs_data"))?,
-            remainder: match &value.remainder {
-                Some(remainder) => Some(
-                    RemainderData::try_from_dto_unverified(remainder).map_err(|_| Error::InvalidField("remainder"))?,
-                ),
-                None => None,
-            },
+            remainders: value
+                .remainders
+                .iter()
+                .map(RemainderData::try_from_dto_unverified)
+                .collect::<crate::client::Result<Vec<RemainderData>>>()
+                .map_err(|_| Error::InvalidField("remainders"))?,
         })
     }
 }
@@ -11,11 +11,12 @@ use super::{node_manager::builder::NodeManagerBuilder, ClientInner};
 use crate::client::node_api::mqtt::{BrokerOptions, MqttEvent};
 use crate::{
     client::{
+        api::block_builder::submission::{BlockSubmission, BlockSubmissionOptions},
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL},
         error::Result,
         node_manager::{
             builder::validate_url,
-            node::{Node, NodeAuth},
+            node::{Node, NodeAuth, ProxyOptions, TlsOptions},
         },
         Client,
     },
@@ -124,6 +125,9 @@ pub struct ClientBuilder {
     /// Timeout when sending a block that requires remote proof of work
     #[serde(default = "default_remote_pow_timeout")]
     pub remote_pow_timeout: Duration,
+    /// Options for building and posting blocks
+    #[serde(default)]
+    pub block_submission_options: BlockSubmissionOptions,
     /// The amount of threads to be used for proof of work
     #[serde(default)]
     #[cfg(not(target_family = "wasm"))]
@@ -160,6 +164,7 @@ impl Default for ClientBuilder {
             network_info: NetworkInfo::default(),
             api_timeout: DEFAULT_API_TIMEOUT,
             remote_pow_timeout: DEFAULT_REMOTE_POW_API_TIMEOUT,
+            block_submission_options: BlockSubmissionOptions::default(),
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: None,
         }
@@ -272,6 +277,19 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets a proxy through which all requests are sent, unless a node provides its own.
+    pub fn with_proxy(mut self, proxy: ProxyOptions) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_proxy(proxy);
+        self
+    }
+
+    /// Sets the TLS options, like additional root certificates or a client certificate, for all requests, unless a
+    /// node provides its own.
+    pub fn with_tls(mut self, tls: TlsOptions) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_tls(tls);
+        self
+    }
+
     /// Sets the MQTT broker options.
     #[cfg(feature = "mqtt")]
     #[cfg_attr(docsrs, doc(cfg(feature = "mqtt")))]
@@ -317,6 +335,12 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the options for building and posting blocks.
+    pub fn with_block_submission_options(mut self, options: BlockSubmissionOptions) -> Self {
+        self.block_submission_options = options;
+        self
+    }
+
     /// Set User-Agent header for requests
     /// Default is "iota-client/{version}"
     pub fn with_user_agent(mut self, user_agent: String) -> Self {
@@ -343,10 +367,11 @@ impl ClientBuilder {
         let (mqtt_event_tx, mqtt_event_rx) = tokio::sync::watch::channel(MqttEvent::Connected);
 
         let client_inner = Arc::new(ClientInner {
-            node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())),
+            node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())?),
             network_info: RwLock::new(self.network_info),
             api_timeout: RwLock::new(self.api_timeout),
             remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
+            block_submission: RwLock::new(BlockSubmission::new(self.block_submission_options)),
             pow_worker_count: RwLock::new(self.pow_worker_count),
             #[cfg(feature = "mqtt")]
             mqtt: super::MqttInner {
@@ -385,10 +410,11 @@ impl ClientBuilder {
 
         let client = Client {
             inner: Arc::new(ClientInner {
-                node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())),
+                node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())?),
                 network_info: RwLock::new(self.network_info),
                 api_timeout: RwLock::new(self.api_timeout),
                 remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
+                block_submission: RwLock::new(BlockSubmission::new(self.block_submission_options)),
                 #[cfg(feature = "mqtt")]
                 mqtt: super::MqttInner {
                     client: Default::default(),
@@ -411,6 +437,7 @@ impl ClientBuilder {
             network_info: client.network_info.read().await.clone(),
             api_timeout: client.get_timeout().await,
             remote_pow_timeout: client.get_remote_pow_timeout().await,
+            block_submission_options: client.get_block_submission_options().await,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: *client.pow_worker_count.read().await,
         }
@@ -17,6 +17,7 @@ use {
 use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
 use crate::{
     client::{
+        api::block_builder::submission::BlockSubmission,
         builder::{ClientBuilder, NetworkInfo},
         error::Result,
         node_manager::NodeManager,
@@ -49,6 +50,8 @@ pub struct ClientInner {
     pub(crate) api_timeout: RwLock<Duration>,
     /// HTTP request timeout for remote PoW API call.
     pub(crate) remote_pow_timeout: RwLock<Duration>,
+    /// Options and state for building and posting blocks.
+    pub(crate) block_submission: RwLock<BlockSubmission>,
     /// pow_worker_count for local PoW.
     #[cfg(not(target_family = "wasm"))]
     pub(crate) pow_worker_count: RwLock<Option<usize>>,
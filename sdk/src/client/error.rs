@@ -62,6 +62,14 @@ pub enum Error {
     /// Invalid mnemonic error
     #[error("invalid mnemonic {0}")]
     InvalidMnemonic(String),
+    /// The value of a plugin route path parameter can't be used as path segment
+    #[error("invalid value for path parameter {name}: {value}")]
+    InvalidPathParameter {
+        /// The name of the path parameter.
+        name: &'static str,
+        /// The invalid value.
+        value: String,
+    },
     /// The transaction essence is too large
     #[error("the transaction essence is too large. Its length is {length}, max length is {max_length}")]
     InvalidRegularTransactionEssenceLength {
@@ -84,6 +92,9 @@ pub enum Error {
     /// Missing required parameters
     #[error("must provide required parameter: {0}")]
     MissingParameter(&'static str),
+    /// A placeholder of a plugin route path template has no value
+    #[error("missing value for path parameter: {0}")]
+    MissingPathParameter(String),
     /// Error on API request
     #[error("node error: {0}")]
     Node(#[from] crate::client::node_api::error::Error),
@@ -96,6 +107,12 @@ pub enum Error {
     /// PlaceholderSecretManager can't be used for address generation or signing
     #[error("placeholderSecretManager can't be used for address generation or signing")]
     PlaceholderSecretManager,
+    /// The node doesn't expose the requested plugin
+    #[error("plugin {0} is not available on the node")]
+    PluginNotAvailable(String),
+    /// A paginated route returned a cursor it already returned before
+    #[error("the node returned the cursor {0} again")]
+    RepeatedCursor(String),
     /// Rw lock failed.
     #[error("rw lock failed")]
     PoisonError,
@@ -124,6 +141,9 @@ pub enum Error {
     /// No node available in the healthy node pool
     #[error("no healthy node available")]
     HealthyNodePoolEmpty,
+    /// Protected routes are only sent to the primary node, or the only node
+    #[error("a primary node is required for the protected routes of a node when multiple nodes are set")]
+    ProtectedNodeMissing,
     /// Error when building tagged_data blocks
     #[error("error when building tagged_data block: {0}")]
     TaggedData(String),
@@ -153,6 +173,9 @@ pub enum Error {
     /// An indexer API request contains a query parameter not supported by the endpoint.
     #[error("an indexer API request contains a query parameter not supported by the endpoint: {0}.")]
     UnsupportedQueryParameter(QueryParameter),
+    /// A path parameter doesn't match any placeholder of a plugin route path template
+    #[error("unexpected path parameter: {0}")]
+    UnexpectedPathParameter(&'static str),
     /// Unpack error
     #[error("{0}")]
     Unpack(#[from] packable::error::UnpackError<crate::types::block::Error, UnexpectedEOF>),
@@ -12,16 +12,21 @@ use url::Url;
 use crate::{
     client::{
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_USER_AGENT},
-        node_manager::node::{Node, NodeAuth},
+        node_manager::{
+            http_client::HttpClient,
+            node::{Node, NodeAuth},
+        },
         Client, ClientInner, Error, Result,
     },
     types::{
         api::core::{
             dto::{PeerDto, ReceiptDto},
+            request::{AddPeerRequest, CreateSnapshotsRequest, DashboardAuthRequest, PruneDatabaseRequest},
             response::{
-                BlockMetadataResponse, BlockResponse, InfoResponse, MilestoneResponse, OutputWithMetadataResponse,
-                PeersResponse, ReceiptsResponse, RoutesResponse, SubmitBlockResponse, TipsResponse, TreasuryResponse,
-                UtxoChangesResponse,
+                AddPeerResponse, BlockMetadataResponse, BlockResponse, CreateSnapshotsResponse, DashboardAuthResponse,
+                InfoResponse, MilestoneResponse, OutputWithMetadataResponse, PeerResponse, PeersResponse,
+                PruneDatabaseResponse, ReceiptsResponse, RoutesResponse, SubmitBlockResponse, TipsResponse,
+                TreasuryResponse, UtxoChangesResponse,
             },
         },
         block::{
@@ -58,12 +63,15 @@ impl ClientInner {
 
         let mut url = Url::parse(url)?;
         url.set_path(path);
-        let status = crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
+        let http_client = self.node_manager.read().await.http_client.clone();
+        let status = http_client
             .get(
                 Node {
                     url,
                     auth: None,
                     disabled: false,
+                    proxy: None,
+                    tls: None,
                 },
                 DEFAULT_API_TIMEOUT,
             )
@@ -493,6 +501,7 @@ impl ClientInner {
 
     // Peers routes.
 
+    /// Returns the peers of the node. Requires a JWT for the protected routes of the node.
     /// GET /api/core/v2/peers
     pub async fn get_peers(&self) -> Result<Vec<PeerDto>> {
         let path = "api/core/v2/peers";
@@ -501,38 +510,132 @@ impl ClientInner {
             .node_manager
             .read()
             .await
-            .get_request::<PeersResponse>(path, None, self.get_timeout().await, false, false)
+            .get_protected_request::<PeersResponse>(path, self.get_timeout().await)
+            .await?;
+
+        Ok(resp.0)
+    }
+
+    /// Returns information about a specific peer of the node. Requires a JWT for the protected routes of the node.
+    /// GET /api/core/v2/peers/{peerId}
+    pub async fn get_peer(&self, peer_id: &str) -> Result<PeerDto> {
+        let path = &format!("api/core/v2/peers/{peer_id}");
+
+        let resp = self
+            .node_manager
+            .read()
+            .await
+            .get_protected_request::<PeerResponse>(path, self.get_timeout().await)
             .await?;
 
         Ok(resp.0)
     }
 
-    // // RoutePeer is the route for getting peers by their peerID.
-    // // GET returns the peer
-    // // DELETE deletes the peer.
-    // RoutePeer = "/peers/:" + restapipkg.ParameterPeerID
+    /// Adds a new peer to the node. Requires a JWT for the protected routes of the node.
+    /// POST /api/core/v2/peers
+    pub async fn add_peer(&self, multi_address: impl Into<String> + Send, alias: Option<String>) -> Result<PeerDto> {
+        let path = "api/core/v2/peers";
+        let request = AddPeerRequest {
+            multi_address: multi_address.into(),
+            alias,
+        };
+
+        let resp = self
+            .node_manager
+            .read()
+            .await
+            .post_protected_request_json::<AddPeerResponse>(
+                path,
+                self.get_timeout().await,
+                serde_json::to_value(request)?,
+            )
+            .await?;
+
+        Ok(resp.0)
+    }
 
-    // // RoutePeers is the route for getting all peers of the node.
-    // // GET returns a list of all peers.
-    // // POST adds a new peer.
-    // RoutePeers = "/peers"
+    /// Removes a peer from the node. Requires a JWT for the protected routes of the node.
+    /// DELETE /api/core/v2/peers/{peerId}
+    pub async fn remove_peer(&self, peer_id: &str) -> Result<()> {
+        let path = &format!("api/core/v2/peers/{peer_id}");
+
+        self.node_manager
+            .read()
+            .await
+            .delete_protected_request(path, self.get_timeout().await)
+            .await
+    }
 
     // Control routes.
 
-    // // RouteControlDatabasePrune is the control route to manually prune the database.
-    // // POST prunes the database.
-    // RouteControlDatabasePrune = "/control/database/prune"
+    /// Manually prunes the database of the node. Requires a JWT for the protected routes of the node.
+    /// POST /api/core/v2/control/database/prune
+    pub async fn prune_database(&self, request: PruneDatabaseRequest) -> Result<PruneDatabaseResponse> {
+        let path = "api/core/v2/control/database/prune";
+
+        self.node_manager
+            .read()
+            .await
+            .post_protected_request_json(path, self.get_timeout().await, serde_json::to_value(request)?)
+            .await
+    }
+
+    /// Manually creates a snapshot file at the given milestone index. Requires a JWT for the protected routes of the
+    /// node.
+    /// POST /api/core/v2/control/snapshots/create
+    pub async fn create_snapshots(&self, index: u32) -> Result<CreateSnapshotsResponse> {
+        let path = "api/core/v2/control/snapshots/create";
+        let request = CreateSnapshotsRequest { index };
+
+        self.node_manager
+            .read()
+            .await
+            .post_protected_request_json(path, self.get_timeout().await, serde_json::to_value(request)?)
+            .await
+    }
 
-    // // RouteControlSnapshotsCreate is the control route to manually create a snapshot files.
-    // // POST creates a snapshot (full, delta or both).
-    // RouteControlSnapshotsCreate = "/control/snapshots/create"
+    // Dashboard routes.
+
+    /// Exchanges the dashboard credentials for a JWT, which can then be set as [`NodeAuth::jwt`] to access the
+    /// protected routes of the node.
+    /// POST /dashboard/auth
+    pub async fn dashboard_auth(
+        &self,
+        user: impl Into<String> + Send,
+        password: impl Into<String> + Send,
+    ) -> Result<DashboardAuthResponse> {
+        let path = "dashboard/auth";
+        let request = DashboardAuthRequest {
+            user: user.into(),
+            password: password.into(),
+        };
+
+        self.node_manager
+            .read()
+            .await
+            .post_protected_request_json(path, self.get_timeout().await, serde_json::to_value(request)?)
+            .await
+    }
 }
 
 impl Client {
     /// GET /api/core/v2/info endpoint
     pub async fn get_node_info(url: &str, auth: Option<NodeAuth>) -> Result<InfoResponse> {
-        let mut url = crate::client::node_manager::builder::validate_url(Url::parse(url)?)?;
-        if let Some(auth) = &auth {
+        let node = Node {
+            url: Url::parse(url)?,
+            auth,
+            disabled: false,
+            proxy: None,
+            tls: None,
+        };
+
+        Self::get_node_info_with_http_client(&HttpClient::new(DEFAULT_USER_AGENT.to_string()), node).await
+    }
+
+    /// GET /api/core/v2/info endpoint, requested with the given HTTP client so that its proxy and TLS options apply.
+    pub(crate) async fn get_node_info_with_http_client(http_client: &HttpClient, node: Node) -> Result<InfoResponse> {
+        let mut url = crate::client::node_manager::builder::validate_url(node.url)?;
+        if let Some(auth) = &node.auth {
             if let Some((name, password)) = &auth.basic_auth_name_pwd {
                 url.set_username(name)
                     .map_err(|_| crate::client::Error::UrlAuth("username"))?;
@@ -543,19 +646,11 @@ impl Client {
         let path = "api/core/v2/info";
         url.set_path(path);
 
-        let resp: InfoResponse =
-            crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
-                .get(
-                    Node {
-                        url,
-                        auth,
-                        disabled: false,
-                    },
-                    DEFAULT_API_TIMEOUT,
-                )
-                .await?
-                .into_json()
-                .await?;
+        let resp: InfoResponse = http_client
+            .get(Node { url, ..node }, DEFAULT_API_TIMEOUT)
+            .await?
+            .into_json()
+            .await?;
 
         Ok(resp)
     }
@@ -26,4 +26,10 @@ pub enum Error {
     /// None of our nodes have remote Pow enabled
     #[error("No node available for remote Pow")]
     UnavailablePow,
+    /// Proxy or TLS options were provided, but they are not supported by the current build or target
+    #[error("proxy and TLS options are not supported by this build or target")]
+    UnsupportedConnectionOptions,
+    /// The TLS client identity file couldn't be read
+    #[error("can't read the TLS client identity {0}")]
+    TlsClientIdentity(String),
 }
@@ -6,6 +6,8 @@
 pub mod query_parameters;
 pub mod routes;
 
+use futures::{stream, Future, Stream};
+
 pub(crate) use self::query_parameters::{QueryParameter, QueryParameters};
 use crate::{
     client::{ClientInner, Result},
@@ -60,4 +62,149 @@ impl ClientInner {
 
         Ok(merged_output_ids_response)
     }
+
+    /// Get a stream of the pages of output ids for a provided URL route and query parameters.
+    /// A page is only requested when the stream is polled, so a slow consumer applies backpressure to the node
+    /// requests. The `cursor` of a page is a resume token: providing it as `QueryParameter::Cursor(_)` continues the
+    /// stream with the following page.
+    pub fn get_output_ids_stream(
+        &self,
+        route: impl Into<String>,
+        query_parameters: QueryParameters,
+        need_quorum: bool,
+        prefer_permanode: bool,
+    ) -> impl Stream<Item = Result<OutputIdsResponse>> + Send + '_ {
+        let route = route.into();
+
+        output_ids_pages(query_parameters, move |query_parameters| {
+            let route = route.clone();
+
+            async move {
+                self.node_manager
+                    .read()
+                    .await
+                    .get_request::<OutputIdsResponse>(
+                        &route,
+                        query_parameters.to_query_string().as_deref(),
+                        self.get_timeout().await,
+                        need_quorum,
+                        prefer_permanode,
+                    )
+                    .await
+            }
+        })
+    }
+}
+
+// Lazily requests the pages of output ids, following the cursor of each page until the last one.
+fn output_ids_pages<'a, F, Fut>(
+    query_parameters: QueryParameters,
+    mut request_page: F,
+) -> impl Stream<Item = Result<OutputIdsResponse>> + Send + 'a
+where
+    F: FnMut(QueryParameters) -> Fut + Send + 'a,
+    Fut: Future<Output = Result<OutputIdsResponse>> + Send + 'a,
+{
+    stream::try_unfold(Some(query_parameters), move |query_parameters| {
+        let request =
+            query_parameters.map(|query_parameters| (request_page(query_parameters.clone()), query_parameters));
+
+        async move {
+            let (request, mut query_parameters) = match request {
+                Some(request) => request,
+                None => return Ok(None),
+            };
+
+            let output_ids_response = request.await?;
+
+            let next_query_parameters = output_ids_response.cursor.clone().map(|cursor| {
+                query_parameters.replace(QueryParameter::Cursor(cursor));
+                query_parameters
+            });
+
+            Ok(Some((output_ids_response, next_query_parameters)))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures::{StreamExt, TryStreamExt};
+
+    use super::*;
+    use crate::{client::Error, types::block::output::OutputId};
+
+    const OUTPUT_ID: &str = "0xbce525324af12eda02bf7927e92cea3a8e8322d0f41966271443e6c3b245a4400000";
+
+    fn page(cursor: Option<&str>, output_count: usize) -> OutputIdsResponse {
+        OutputIdsResponse {
+            ledger_index: 10,
+            cursor: cursor.map(ToString::to_string),
+            items: vec![OUTPUT_ID.parse::<OutputId>().unwrap(); output_count],
+        }
+    }
+
+    // Serves the pages in order and records the query of every request
+    fn mocked_pages(
+        pages: Vec<Result<OutputIdsResponse>>,
+    ) -> (
+        Arc<Mutex<Vec<Option<String>>>>,
+        impl FnMut(QueryParameters) -> futures::future::Ready<Result<OutputIdsResponse>> + Send,
+    ) {
+        let queries = Arc::new(Mutex::new(Vec::new()));
+        let mut pages = pages.into_iter();
+        let requested = queries.clone();
+
+        (queries, move |query_parameters: QueryParameters| {
+            requested.lock().unwrap().push(query_parameters.to_query_string());
+            futures::future::ready(pages.next().expect("no more pages"))
+        })
+    }
+
+    #[tokio::test]
+    async fn output_ids_pages_follow_cursor() {
+        let (queries, request_page) = mocked_pages(vec![
+            Ok(page(Some("a.2"), 2)),
+            Ok(page(Some("b.2"), 2)),
+            Ok(page(None, 1)),
+        ]);
+        let mut pages = Box::pin(output_ids_pages(
+            QueryParameters::new([QueryParameter::PageSize(2)]),
+            request_page,
+        ));
+
+        // Pages are only requested when the stream is polled
+        assert!(queries.lock().unwrap().is_empty());
+        assert_eq!(pages.try_next().await.unwrap().unwrap().items.len(), 2);
+        assert_eq!(queries.lock().unwrap().len(), 1);
+
+        let rest = pages.try_collect::<Vec<_>>().await.unwrap();
+        assert_eq!(rest.iter().map(|page| page.items.len()).collect::<Vec<_>>(), [2, 1]);
+        assert_eq!(
+            *queries.lock().unwrap(),
+            [
+                Some("pageSize=2".to_string()),
+                Some("cursor=a.2&pageSize=2".to_string()),
+                Some("cursor=b.2&pageSize=2".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn output_ids_pages_stop_at_error() {
+        let (queries, request_page) = mocked_pages(vec![
+            Ok(page(Some("a.2"), 2)),
+            Err(Error::HealthyNodePoolEmpty),
+            Ok(page(None, 1)),
+        ]);
+        let pages = output_ids_pages(QueryParameters::empty(), request_page)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(pages.len(), 2);
+        assert!(matches!(pages[1], Err(Error::HealthyNodePoolEmpty)));
+        assert_eq!(queries.lock().unwrap().len(), 2);
+    }
 }
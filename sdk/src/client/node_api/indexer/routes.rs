@@ -3,6 +3,8 @@
 
 //! IOTA node indexer routes
 
+use futures::Stream;
+
 use crate::{
     client::{
         node_api::indexer::{
@@ -124,4 +126,52 @@ impl ClientInner {
             .first()
             .ok_or_else(|| Error::NoOutput(format!("{nft_id:?}")))?))
     }
+
+    /// Get a stream of the pages of basic output ids filtered by the given parameters.
+    /// Supports the same query parameters as [`ClientInner::basic_output_ids()`]; a `QueryParameter::Cursor(_)`
+    /// resumes the stream at the given page.
+    pub fn basic_output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + Send + '_> {
+        let query_parameters = verify_query_parameters_basic_outputs(query_parameters.into())?;
+
+        Ok(self.get_output_ids_stream("api/indexer/v1/outputs/basic", query_parameters, true, false))
+    }
+
+    /// Get a stream of the pages of alias output ids filtered by the given parameters.
+    /// Supports the same query parameters as [`ClientInner::alias_output_ids()`]; a `QueryParameter::Cursor(_)`
+    /// resumes the stream at the given page.
+    pub fn alias_output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + Send + '_> {
+        let query_parameters = verify_query_parameters_alias_outputs(query_parameters.into())?;
+
+        Ok(self.get_output_ids_stream("api/indexer/v1/outputs/alias", query_parameters, true, false))
+    }
+
+    /// Get a stream of the pages of foundry output ids filtered by the given parameters.
+    /// Supports the same query parameters as [`ClientInner::foundry_output_ids()`]; a `QueryParameter::Cursor(_)`
+    /// resumes the stream at the given page.
+    pub fn foundry_output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + Send + '_> {
+        let query_parameters = verify_query_parameters_foundry_outputs(query_parameters.into())?;
+
+        Ok(self.get_output_ids_stream("api/indexer/v1/outputs/foundry", query_parameters, true, false))
+    }
+
+    /// Get a stream of the pages of NFT output ids filtered by the given parameters.
+    /// Supports the same query parameters as [`ClientInner::nft_output_ids()`]; a `QueryParameter::Cursor(_)`
+    /// resumes the stream at the given page.
+    pub fn nft_output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + Send + '_> {
+        let query_parameters = verify_query_parameters_nft_outputs(query_parameters.into())?;
+
+        Ok(self.get_output_ids_stream("api/indexer/v1/outputs/nft", query_parameters, true, false))
+    }
 }
@@ -0,0 +1,341 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Backfill of the MQTT events missed while the connection was lost.
+
+use std::{collections::HashSet, ops::RangeInclusive, str::FromStr};
+
+use super::{dispatch_event, MqttPayload, TopicEvent};
+use crate::{
+    client::{Client, Result},
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            address::{Hrp, ToBech32Ext},
+            output::{ChainId, Output, OutputId, UnlockCondition},
+        },
+    },
+};
+
+/// Topic which is subscribed internally to keep track of the confirmed milestones when backfill is enabled.
+pub(crate) const CONFIRMED_MILESTONE_TOPIC: &str = "milestone-info/confirmed";
+const MILESTONES_TOPIC: &str = "milestones";
+
+/// Keeps track of the milestones whose events were delivered, so the gap can be closed after a reconnection.
+#[derive(Debug, Default)]
+pub(crate) struct BackfillState {
+    /// Index of the latest confirmed milestone.
+    confirmed_milestone_index: Option<u32>,
+    /// Index of the latest milestone payload delivered on the `milestones` topic.
+    milestone_payload_index: Option<u32>,
+    /// Milestones of the last backfill whose `outputs/*` events were delivered.
+    backfilled_output_milestones: Option<RangeInclusive<u32>>,
+}
+
+impl BackfillState {
+    pub(crate) fn update_confirmed_milestone_index(&mut self, index: u32) {
+        self.confirmed_milestone_index = Some(self.confirmed_milestone_index.map_or(index, |i| i.max(index)));
+    }
+
+    /// Returns `false` if a milestone payload with the same or a higher index was already delivered.
+    pub(crate) fn update_milestone_payload_index(&mut self, index: u32) -> bool {
+        if self.milestone_payload_index.map_or(false, |i| index <= i) {
+            return false;
+        }
+        self.milestone_payload_index.replace(index);
+
+        true
+    }
+
+    /// Records that the `outputs/*` events of a milestone were delivered by the backfill.
+    pub(crate) fn add_backfilled_output_milestone(&mut self, index: u32) {
+        self.backfilled_output_milestones = Some(match self.backfilled_output_milestones.take() {
+            Some(range) if *range.end() + 1 == index => *range.start()..=index,
+            _ => index..=index,
+        });
+    }
+
+    /// Returns `false` if the `outputs/*` event was already delivered by the backfill, because it is received live
+    /// after a reconnection.
+    pub(crate) fn is_new_output_event(&self, output: &serde_json::Value) -> bool {
+        match (&self.backfilled_output_milestones, output_event_milestone_index(output)) {
+            (Some(range), Some(index)) => !range.contains(&index),
+            _ => true,
+        }
+    }
+}
+
+/// Returns the milestone of an `outputs/*` event, the one which spent the output for a spent output.
+fn output_event_milestone_index(output: &serde_json::Value) -> Option<u32> {
+    let metadata = output.get("metadata")?;
+    let key = if metadata.get("isSpent")?.as_bool()? {
+        "milestoneIndexSpent"
+    } else {
+        "milestoneIndexBooked"
+    };
+
+    metadata.get(key)?.as_u64().and_then(|index| u32::try_from(index).ok())
+}
+
+/// Delivers the `milestones` and `outputs/*` events of the milestones confirmed since the last known confirmed
+/// milestone, in order, using the REST API.
+pub(crate) async fn backfill(client: &Client, state: &mut BackfillState) -> Result<()> {
+    let last_confirmed_milestone_index = match state.confirmed_milestone_index {
+        Some(index) => index,
+        None => return Ok(()),
+    };
+
+    let subscribed_topics = client
+        .mqtt
+        .topic_handlers
+        .read()
+        .await
+        .keys()
+        .map(|topic| topic.as_str().to_owned())
+        .collect::<HashSet<String>>();
+    let backfill_milestones = subscribed_topics.contains(MILESTONES_TOPIC);
+    let backfill_outputs = subscribed_topics.iter().any(|topic| topic.starts_with("outputs/"));
+
+    if !backfill_milestones && !backfill_outputs {
+        return Ok(());
+    }
+
+    let confirmed_milestone_index = client.get_info().await?.node_info.status.confirmed_milestone.index;
+    let max_backfill_milestones = client.mqtt.broker_options.read().await.max_backfill_milestones;
+    let start_index = backfill_start_index(
+        last_confirmed_milestone_index,
+        confirmed_milestone_index,
+        max_backfill_milestones,
+    );
+    let hrp = client.get_bech32_hrp().await?;
+
+    for index in start_index..=confirmed_milestone_index {
+        if backfill_milestones && state.update_milestone_payload_index(index) {
+            let milestone_payload = client.get_milestone_by_index(index).await?;
+
+            dispatch_event(
+                client,
+                &TopicEvent {
+                    topic: MILESTONES_TOPIC.to_owned(),
+                    payload: MqttPayload::MilestonePayload(milestone_payload),
+                },
+            )
+            .await;
+        }
+
+        if backfill_outputs {
+            let utxo_changes = client.get_utxo_changes_by_index(index).await?;
+
+            for (output_ids, spent) in [
+                (utxo_changes.created_outputs, false),
+                (utxo_changes.consumed_outputs, true),
+            ] {
+                let output_ids = output_ids
+                    .iter()
+                    .map(|output_id| OutputId::from_str(output_id))
+                    .collect::<core::result::Result<Vec<_>, _>>()?;
+
+                // requested in parallel, but delivered in order
+                for output in client.get_outputs(&output_ids).await? {
+                    let output_id = output.metadata().output_id();
+                    let topics = output_topics(output.output(), output_id, hrp, spent)
+                        .into_iter()
+                        .filter(|topic| subscribed_topics.contains(topic))
+                        .collect::<Vec<_>>();
+
+                    if topics.is_empty() {
+                        continue;
+                    }
+
+                    let payload = MqttPayload::Json(serde_json::to_value(OutputWithMetadataResponse::from(&output))?);
+
+                    for topic in topics {
+                        dispatch_event(
+                            client,
+                            &TopicEvent {
+                                topic,
+                                payload: payload.clone(),
+                            },
+                        )
+                        .await;
+                    }
+                }
+            }
+            state.add_backfilled_output_milestone(index);
+        }
+
+        state.update_confirmed_milestone_index(index);
+    }
+
+    Ok(())
+}
+
+/// Returns the index of the first milestone to backfill, only the most recent ones if `max_backfill_milestones` is
+/// not 0.
+fn backfill_start_index(
+    last_confirmed_milestone_index: u32,
+    confirmed_milestone_index: u32,
+    max_backfill_milestones: u32,
+) -> u32 {
+    let start_index = last_confirmed_milestone_index + 1;
+
+    if max_backfill_milestones == 0 {
+        start_index
+    } else {
+        start_index.max(confirmed_milestone_index.saturating_sub(max_backfill_milestones - 1))
+    }
+}
+
+/// Returns the topics on which the node publishes the creation or the spending of an output.
+fn output_topics(output: &Output, output_id: &OutputId, hrp: Hrp, spent: bool) -> Vec<String> {
+    let mut topics = vec![format!("outputs/{output_id}")];
+
+    if !spent {
+        if let Some(chain_id) = output.chain_id() {
+            topics.push(match chain_id.or_from_output_id(output_id) {
+                ChainId::Alias(alias_id) => format!("outputs/alias/{alias_id}"),
+                ChainId::Foundry(foundry_id) => format!("outputs/foundry/{foundry_id}"),
+                ChainId::Nft(nft_id) => format!("outputs/nft/{nft_id}"),
+            });
+        }
+    }
+
+    if let Some(unlock_conditions) = output.unlock_conditions() {
+        let suffix = if spent { "/spent" } else { "" };
+
+        for unlock_condition in unlock_conditions.iter() {
+            let (condition, address) = match unlock_condition {
+                UnlockCondition::Address(uc) => ("address", uc.address()),
+                UnlockCondition::StorageDepositReturn(uc) => ("storage-return", uc.return_address()),
+                UnlockCondition::Expiration(uc) => ("expiration", uc.return_address()),
+                UnlockCondition::StateControllerAddress(uc) => ("state-controller", uc.address()),
+                UnlockCondition::GovernorAddress(uc) => ("governor", uc.address()),
+                UnlockCondition::ImmutableAliasAddress(uc) => ("immutable-alias", uc.address()),
+                UnlockCondition::Timelock(_) => continue,
+            };
+            let bech32_address = (*address).to_bech32(hrp);
+
+            topics.push(format!("outputs/unlock/{condition}/{bech32_address}{suffix}"));
+            topics.push(format!("outputs/unlock/+/{bech32_address}{suffix}"));
+        }
+    }
+
+    topics.sort_unstable();
+    topics.dedup();
+
+    topics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Address, Ed25519Address},
+        output::{
+            unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
+            BasicOutputBuilder, NftId, NftOutputBuilder,
+        },
+    };
+
+    const OUTPUT_ID: &str = "0xbce525324af12eda02bf7927e92cea3a8e8322d0f41966271443e6c3b245a4400000";
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    #[test]
+    fn backfill_state() {
+        let mut state = BackfillState::default();
+
+        state.update_confirmed_milestone_index(10);
+        state.update_confirmed_milestone_index(8);
+        assert_eq!(state.confirmed_milestone_index, Some(10));
+
+        // milestones are only delivered once and in order
+        assert!(state.update_milestone_payload_index(11));
+        assert!(!state.update_milestone_payload_index(11));
+        assert!(!state.update_milestone_payload_index(9));
+        assert!(state.update_milestone_payload_index(12));
+    }
+
+    #[test]
+    fn backfill_output_events() {
+        let mut state = BackfillState::default();
+        let output = |is_spent: bool, booked: u32, spent: Option<u32>| {
+            serde_json::json!({
+                "metadata": {
+                    "isSpent": is_spent,
+                    "milestoneIndexBooked": booked,
+                    "milestoneIndexSpent": spent,
+                },
+            })
+        };
+
+        // nothing was backfilled
+        assert!(state.is_new_output_event(&output(false, 11, None)));
+
+        state.add_backfilled_output_milestone(11);
+        state.add_backfilled_output_milestone(12);
+        assert!(!state.is_new_output_event(&output(false, 11, None)));
+        assert!(!state.is_new_output_event(&output(true, 5, Some(12))));
+        assert!(state.is_new_output_event(&output(false, 13, None)));
+        assert!(state.is_new_output_event(&output(true, 11, Some(13))));
+        // events without metadata are delivered
+        assert!(state.is_new_output_event(&serde_json::json!({})));
+
+        // a later backfill replaces the range
+        state.add_backfilled_output_milestone(20);
+        assert!(state.is_new_output_event(&output(false, 11, None)));
+        assert!(!state.is_new_output_event(&output(false, 20, None)));
+    }
+
+    #[test]
+    fn backfill_range() {
+        // all missed milestones
+        assert_eq!(backfill_start_index(10, 20, 0), 11);
+        assert_eq!(backfill_start_index(10, 20, 10), 11);
+        // only the most recent ones
+        assert_eq!(backfill_start_index(10, 20, 5), 16);
+        assert_eq!(backfill_start_index(10, 20, 1), 20);
+        // nothing missed
+        assert!(backfill_start_index(20, 20, 0) > 20);
+    }
+
+    #[test]
+    fn backfill_output_topics() {
+        let hrp = Hrp::from_str("rms").unwrap();
+        let output_id = OutputId::from_str(OUTPUT_ID).unwrap();
+        let address = Address::from(Ed25519Address::new([1; 32]));
+        let return_address = Address::from(Ed25519Address::new([2; 32]));
+        let bech32_address = address.to_bech32(hrp);
+        let bech32_return_address = return_address.to_bech32(hrp);
+
+        let basic_output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_unlock_condition(ExpirationUnlockCondition::new(return_address, 100).unwrap())
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap();
+
+        for (spent, suffix) in [(false, ""), (true, "/spent")] {
+            let mut expected = vec![
+                format!("outputs/{output_id}"),
+                format!("outputs/unlock/+/{bech32_address}{suffix}"),
+                format!("outputs/unlock/+/{bech32_return_address}{suffix}"),
+                format!("outputs/unlock/address/{bech32_address}{suffix}"),
+                format!("outputs/unlock/expiration/{bech32_return_address}{suffix}"),
+            ];
+            expected.sort_unstable();
+
+            assert_eq!(output_topics(&basic_output, &output_id, hrp, spent), expected);
+        }
+
+        // the id of a new NFT is derived from the output id
+        let nft_output = NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap();
+        let nft_id = NftId::from(&output_id);
+
+        assert!(output_topics(&nft_output, &output_id, hrp, false).contains(&format!("outputs/nft/{nft_id}")));
+        assert!(!output_topics(&nft_output, &output_id, hrp, true)
+            .iter()
+            .any(|topic| topic.starts_with("outputs/nft/")));
+    }
+}
@@ -3,17 +3,24 @@
 
 //! IOTA node MQTT API
 
+mod backfill;
 mod error;
 pub mod types;
 
-use std::{sync::Arc, time::Instant};
+use std::{sync::Arc, time::Duration};
 
 use crypto::utils;
 use log::warn;
 use packable::PackableExt;
-use rumqttc::{AsyncClient, Event, EventLoop, Incoming, MqttOptions, NetworkOptions, QoS, SubscribeFilter, Transport};
-use tokio::sync::watch::Receiver as WatchReceiver;
+use rumqttc::{
+    AsyncClient, Event, EventLoop, Incoming, MqttOptions, NetworkOptions, Publish, QoS, SubscribeFilter, Transport,
+};
+use tokio::sync::{
+    mpsc::{self, UnboundedReceiver},
+    watch::Receiver as WatchReceiver,
+};
 
+use self::backfill::{BackfillState, CONFIRMED_MILESTONE_TOPIC};
 pub use self::{error::Error, types::*};
 use crate::{
     client::{Client, ClientInner},
@@ -23,6 +30,48 @@ use crate::{
     },
 };
 
+const MAX_RECONNECTION_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Keeps track of the consecutive connection failures since the last successful connection.
+#[derive(Debug, Default)]
+pub(crate) struct Reconnection {
+    failure_count: usize,
+    backoff: Option<Duration>,
+}
+
+impl Reconnection {
+    /// Resets the failures after a successful connection.
+    pub(crate) fn connected(&mut self) {
+        self.failure_count = 0;
+        self.backoff = None;
+    }
+
+    /// Records a failed connection attempt. Returns the delay before the next attempt, which is doubled after each
+    /// failure, or `None` if the maximum number of attempts is reached. A maximum of 0 is unlimited.
+    pub(crate) fn failed(&mut self, max_attempts: usize, initial_backoff: Duration) -> Option<Duration> {
+        self.failure_count += 1;
+
+        if self.failure_count == max_attempts {
+            return None;
+        }
+
+        let backoff = self
+            .backoff
+            .map_or(initial_backoff, |backoff| (backoff * 2).min(MAX_RECONNECTION_BACKOFF));
+        self.backoff.replace(backoff);
+
+        Some(backoff)
+    }
+}
+
+/// The work of the task which delivers the events in order.
+enum Delivery {
+    /// An event received from the broker.
+    Publish(Publish),
+    /// The events missed while the connection was lost.
+    Backfill,
+}
+
 impl Client {
     /// Returns a handle to the MQTT topics manager.
     pub fn subscriber(&self) -> MqttManager<'_> {
@@ -145,125 +194,78 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
             // can perform the re-subscriptions and reset `is_subscribed` to true.
             // we need the flag since the first ConnAck must be ignored.
             let mut is_subscribed = true;
-            let mut error_instant = Instant::now();
-            let mut connection_failure_count = 0;
+            let mut reconnection = Reconnection::default();
+            // events and backfills are handled in order by a separate task, so slow handlers and backfills don't
+            // keep the event loop from being polled
+            let (delivery_sender, delivery_receiver) = mpsc::unbounded_channel();
+            crate::client::async_runtime::spawn(deliver_events(client.clone(), delivery_receiver));
 
             loop {
                 let event = event_loop.poll().await;
 
                 match event {
                     Ok(Event::Incoming(Incoming::ConnAck(_))) => {
+                        reconnection.connected();
                         let _ = client.mqtt.sender.read().await.send(MqttEvent::Connected);
-                        if !is_subscribed {
-                            is_subscribed = true;
-                            // resubscribe topics
-                            let topics = client
-                                .inner
+                        let backfill = client.mqtt.broker_options.read().await.backfill;
+
+                        let mut topics = if is_subscribed {
+                            Vec::new()
+                        } else {
+                            client
                                 .mqtt
                                 .topic_handlers
                                 .read()
                                 .await
                                 .keys()
                                 .map(|t| SubscribeFilter::new(t.as_str().to_owned(), QoS::AtLeastOnce))
-                                .collect::<Vec<SubscribeFilter>>();
-                            if !topics.is_empty() {
-                                let _ = client
-                                    .inner
-                                    .mqtt
-                                    .client
-                                    .write()
-                                    .await
-                                    .as_mut()
-                                    .unwrap()
-                                    .subscribe_many(topics)
-                                    .await;
+                                .collect::<Vec<SubscribeFilter>>()
+                        };
+                        if backfill {
+                            // keep track of the confirmed milestones to know where to start the backfill from
+                            topics.push(SubscribeFilter::new(
+                                CONFIRMED_MILESTONE_TOPIC.to_owned(),
+                                QoS::AtLeastOnce,
+                            ));
+                        }
+
+                        let resubscribe = !is_subscribed;
+                        is_subscribed = true;
+                        if !topics.is_empty() {
+                            if let Some(mqtt_client) = &*client.mqtt.client.read().await {
+                                if let Err(e) = mqtt_client.subscribe_many(topics).await {
+                                    warn!("Resubscribing to MQTT topics failed: {:?}", e);
+                                    // try again on the next ConnAck
+                                    is_subscribed = false;
+                                }
                             }
                         }
+
+                        if backfill && resubscribe {
+                            let _ = delivery_sender.send(Delivery::Backfill);
+                        }
                     }
                     Ok(Event::Incoming(Incoming::Publish(p))) => {
-                        let client = client.clone();
-
-                        crate::client::async_runtime::spawn(async move {
-                            let mqtt_topic_handlers = client.mqtt.topic_handlers.read().await;
-
-                            if let Some(handlers) = mqtt_topic_handlers.get(&Topic::new_unchecked(&p.topic)) {
-                                let event = {
-                                    if p.topic.contains("blocks") || p.topic.contains("included-block") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
-
-                                        match Block::unpack_verified(payload, protocol_parameters) {
-                                            Ok(block) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::Block(block),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Block unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else if p.topic.contains("milestones") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
-
-                                        match MilestonePayload::unpack_verified(payload, protocol_parameters) {
-                                            Ok(milestone_payload) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::MilestonePayload(milestone_payload),
-                                            }),
-                                            Err(e) => {
-                                                warn!("MilestonePayload unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else if p.topic.contains("receipts") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
-
-                                        match ReceiptMilestoneOption::unpack_verified(payload, protocol_parameters) {
-                                            Ok(receipt) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::Receipt(receipt),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Receipt unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else {
-                                        match serde_json::from_slice(&p.payload) {
-                                            Ok(value) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::Json(value),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Cannot parse JSON: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    }
-                                };
-                                if let Ok(event) = event {
-                                    for handler in handlers {
-                                        handler(&event);
-                                    }
-                                };
-                            }
-                        });
+                        let _ = delivery_sender.send(Delivery::Publish(p));
                     }
                     Err(_) => {
-                        if error_instant.elapsed().as_secs() < 5 {
-                            connection_failure_count += 1;
-                        } else {
-                            connection_failure_count = 1;
-                        }
-                        if connection_failure_count == client.mqtt.broker_options.read().await.max_reconnection_attempts
-                        {
-                            let _ = client.mqtt.sender.read().await.send(MqttEvent::Disconnected);
-                            break;
-                        }
-                        error_instant = Instant::now();
                         is_subscribed = false;
+
+                        // wait before the event loop tries to reconnect
+                        let (max_reconnection_attempts, reconnection_backoff) = {
+                            let broker_options = client.mqtt.broker_options.read().await;
+                            (
+                                broker_options.max_reconnection_attempts,
+                                broker_options.reconnection_backoff,
+                            )
+                        };
+                        match reconnection.failed(max_reconnection_attempts, reconnection_backoff) {
+                            Some(backoff) => tokio::time::sleep(backoff).await,
+                            None => {
+                                let _ = client.mqtt.sender.read().await.send(MqttEvent::Disconnected);
+                                break;
+                            }
+                        }
                     }
                     _ => {}
                 }
@@ -272,6 +274,126 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
     });
 }
 
+/// Delivers the received events and the backfilled ones in the order in which they were received.
+async fn deliver_events(client: Client, mut receiver: UnboundedReceiver<Delivery>) {
+    // the milestones and outputs fetched by the backfill after a reconnection are not delivered twice
+    let mut backfill_state = BackfillState::default();
+
+    while let Some(delivery) = receiver.recv().await {
+        match delivery {
+            Delivery::Publish(p) => {
+                if p.topic == CONFIRMED_MILESTONE_TOPIC {
+                    if let Some(index) = serde_json::from_slice::<serde_json::Value>(&p.payload)
+                        .ok()
+                        .and_then(|value| value.get("index").and_then(serde_json::Value::as_u64))
+                    {
+                        backfill_state.update_confirmed_milestone_index(index as u32);
+                    }
+                }
+
+                let has_handlers = client
+                    .mqtt
+                    .topic_handlers
+                    .read()
+                    .await
+                    .contains_key(&Topic::new_unchecked(&p.topic));
+
+                if has_handlers {
+                    if let Some(event) = topic_event(&client, &p).await {
+                        let is_new = match &event.payload {
+                            MqttPayload::MilestonePayload(milestone_payload) => {
+                                backfill_state.update_milestone_payload_index(*milestone_payload.essence().index())
+                            }
+                            MqttPayload::Json(output) if event.topic.starts_with("outputs/") => {
+                                backfill_state.is_new_output_event(output)
+                            }
+                            _ => true,
+                        };
+                        if !is_new {
+                            continue;
+                        }
+                        dispatch_event(&client, &event).await;
+                    }
+                }
+            }
+            Delivery::Backfill => {
+                if let Err(e) = backfill::backfill(&client, &mut backfill_state).await {
+                    warn!("MQTT backfill failed: {:?}", e);
+                }
+            }
+        }
+    }
+}
+
+/// Converts a MQTT publish into a topic event.
+async fn topic_event(client: &Client, p: &Publish) -> Option<TopicEvent> {
+    if p.topic.contains("blocks") || p.topic.contains("included-block") {
+        let payload = &*p.payload;
+        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
+
+        match Block::unpack_verified(payload, protocol_parameters) {
+            Ok(block) => Some(TopicEvent {
+                topic: p.topic.clone(),
+                payload: MqttPayload::Block(block),
+            }),
+            Err(e) => {
+                warn!("Block unpacking failed: {:?}", e);
+                None
+            }
+        }
+    } else if p.topic.contains("milestones") {
+        let payload = &*p.payload;
+        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
+
+        match MilestonePayload::unpack_verified(payload, protocol_parameters) {
+            Ok(milestone_payload) => Some(TopicEvent {
+                topic: p.topic.clone(),
+                payload: MqttPayload::MilestonePayload(milestone_payload),
+            }),
+            Err(e) => {
+                warn!("MilestonePayload unpacking failed: {:?}", e);
+                None
+            }
+        }
+    } else if p.topic.contains("receipts") {
+        let payload = &*p.payload;
+        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
+
+        match ReceiptMilestoneOption::unpack_verified(payload, protocol_parameters) {
+            Ok(receipt) => Some(TopicEvent {
+                topic: p.topic.clone(),
+                payload: MqttPayload::Receipt(receipt),
+            }),
+            Err(e) => {
+                warn!("Receipt unpacking failed: {:?}", e);
+                None
+            }
+        }
+    } else {
+        match serde_json::from_slice(&p.payload) {
+            Ok(value) => Some(TopicEvent {
+                topic: p.topic.clone(),
+                payload: MqttPayload::Json(value),
+            }),
+            Err(e) => {
+                warn!("Cannot parse JSON: {:?}", e);
+                None
+            }
+        }
+    }
+}
+
+/// Calls the handlers of the topic of the event.
+pub(crate) async fn dispatch_event(client: &Client, event: &TopicEvent) {
+    let mqtt_topic_handlers = client.mqtt.topic_handlers.read().await;
+
+    if let Some(handlers) = mqtt_topic_handlers.get(&Topic::new_unchecked(&event.topic)) {
+        for handler in handlers {
+            handler(event);
+        }
+    }
+}
+
 /// MQTT subscriber.
 pub struct MqttManager<'a> {
     client: &'a Client,
@@ -407,3 +529,44 @@ impl<'a> MqttTopicManager<'a> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconnection_backoff() {
+        let mut reconnection = Reconnection::default();
+        let backoffs = (0..8)
+            .map(|_| reconnection.failed(0, Duration::from_secs(5)).unwrap().as_secs())
+            .collect::<Vec<_>>();
+
+        assert_eq!(backoffs, [5, 10, 20, 40, 60, 60, 60, 60]);
+
+        // a successful connection starts over
+        reconnection.connected();
+        assert_eq!(
+            reconnection.failed(0, Duration::from_secs(5)),
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn reconnection_max_attempts() {
+        let mut reconnection = Reconnection::default();
+
+        // the attempts are consecutive failures, no matter how long the backoff between them is
+        assert!(reconnection.failed(3, MAX_RECONNECTION_BACKOFF).is_some());
+        assert!(reconnection.failed(3, MAX_RECONNECTION_BACKOFF).is_some());
+        assert!(reconnection.failed(3, MAX_RECONNECTION_BACKOFF).is_none());
+
+        let mut reconnection = Reconnection::default();
+
+        assert!(reconnection.failed(3, MAX_RECONNECTION_BACKOFF).is_some());
+        assert!(reconnection.failed(3, MAX_RECONNECTION_BACKOFF).is_some());
+        reconnection.connected();
+        assert!(reconnection.failed(3, MAX_RECONNECTION_BACKOFF).is_some());
+        assert!(reconnection.failed(3, MAX_RECONNECTION_BACKOFF).is_some());
+        assert!(reconnection.failed(3, MAX_RECONNECTION_BACKOFF).is_none());
+    }
+}
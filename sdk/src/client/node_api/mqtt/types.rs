@@ -69,6 +69,12 @@ pub struct BrokerOptions {
     pub(crate) port: u16,
     #[serde(default = "default_max_reconnection_attempts")]
     pub(crate) max_reconnection_attempts: usize,
+    #[serde(default = "default_reconnection_backoff")]
+    pub(crate) reconnection_backoff: Duration,
+    #[serde(default)]
+    pub(crate) backfill: bool,
+    #[serde(default = "default_max_backfill_milestones")]
+    pub(crate) max_backfill_milestones: u32,
 }
 
 fn default_broker_automatic_disconnect() -> bool {
@@ -91,6 +97,14 @@ fn default_max_reconnection_attempts() -> usize {
     0
 }
 
+fn default_reconnection_backoff() -> Duration {
+    Duration::from_secs(1)
+}
+
+fn default_max_backfill_milestones() -> u32 {
+    100
+}
+
 impl Default for BrokerOptions {
     fn default() -> Self {
         Self {
@@ -99,6 +113,9 @@ impl Default for BrokerOptions {
             use_ws: default_broker_use_ws(),
             port: default_broker_port(),
             max_reconnection_attempts: default_max_reconnection_attempts(),
+            reconnection_backoff: default_reconnection_backoff(),
+            backfill: false,
+            max_backfill_milestones: default_max_backfill_milestones(),
         }
     }
 }
@@ -133,11 +150,33 @@ impl BrokerOptions {
         self
     }
 
-    /// Sets the maximum number of reconnection attempts. 0 is unlimited.
+    /// Sets the maximum number of consecutive failed reconnection attempts since the last successful connection. 0 is
+    /// unlimited.
     pub fn max_reconnection_attempts(mut self, max_reconnection_attempts: usize) -> Self {
         self.max_reconnection_attempts = max_reconnection_attempts;
         self
     }
+
+    /// Sets the delay before the first reconnection attempt. The delay is doubled after each failed attempt, up to
+    /// one minute.
+    pub fn reconnection_backoff(mut self, reconnection_backoff: Duration) -> Self {
+        self.reconnection_backoff = reconnection_backoff;
+        self
+    }
+
+    /// Whether the `milestones` and `outputs/*` events missed while the connection was lost should be fetched from
+    /// the REST API and delivered after a reconnection or not.
+    pub fn backfill(mut self, backfill: bool) -> Self {
+        self.backfill = backfill;
+        self
+    }
+
+    /// Sets the maximum number of milestones that are backfilled after a reconnection. Only the most recent ones are
+    /// backfilled if more were missed. 0 is unlimited.
+    pub fn max_backfill_milestones(mut self, max_backfill_milestones: u32) -> Self {
+        self.max_backfill_milestones = max_backfill_milestones;
+        self
+    }
 }
 
 /// A MQTT topic.
@@ -0,0 +1,211 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Generic routes for node plugins.
+//!
+//! Downstream crates can describe the routes of a node plugin by implementing [`NodePlugin`] and [`PluginRoute`],
+//! and call them with [`ClientInner::call_plugin_route`]. The requests go through the same node selection, auth and
+//! quorum handling as the built-in routes.
+
+use std::{collections::HashSet, fmt::Debug};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::client::{node_api::error::Error as NodeApiError, ClientInner, Error, Result};
+
+/// A node plugin, identified by the name under which the node lists it in `GET /api/routes`.
+pub trait NodePlugin {
+    /// The name of the plugin as returned by `GET /api/routes`, e.g. `participation/v1`.
+    const NAME: &'static str;
+
+    /// The base path of the plugin routes.
+    fn base_path() -> String {
+        format!("api/{}", Self::NAME)
+    }
+}
+
+/// The HTTP method of a plugin route.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PluginMethod {
+    /// GET request.
+    Get,
+    /// POST request with a JSON body.
+    Post,
+}
+
+/// A typed route of a node plugin.
+///
+/// The path is built from [`PluginRoute::PATH`], a template relative to the base path of the plugin in which
+/// `{name}` placeholders are replaced by the percent-encoded values returned by [`PluginRoute::path_parameters`].
+pub trait PluginRoute: Send + Sync {
+    /// The plugin this route belongs to.
+    type Plugin: NodePlugin;
+    /// The type the response body is deserialized into.
+    type Response: DeserializeOwned + Debug + Serialize;
+
+    /// The path template of the route, relative to the base path of the plugin, e.g. `events/{eventId}/status`.
+    const PATH: &'static str;
+    /// The HTTP method of the route.
+    const METHOD: PluginMethod = PluginMethod::Get;
+    /// Whether the response of a GET route has to be confirmed by a quorum of nodes, if quorum is enabled.
+    const NEED_QUORUM: bool = false;
+    /// Whether permanodes should be preferred for a GET route.
+    const PREFER_PERMANODE: bool = false;
+
+    /// The values of the placeholders of the path template.
+    fn path_parameters(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    /// The query parameters of a GET route.
+    fn query_parameters(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    /// The JSON body of a POST route.
+    fn body(&self) -> Result<Value> {
+        Ok(Value::Null)
+    }
+
+    /// Builds the full path of the route by filling the path template.
+    fn path(&self) -> Result<String> {
+        let mut path = format!("{}/{}", Self::Plugin::base_path(), Self::PATH.trim_start_matches('/'));
+
+        for (name, value) in self.path_parameters() {
+            let placeholder = format!("{{{name}}}");
+
+            if !path.contains(&placeholder) {
+                return Err(Error::UnexpectedPathParameter(name));
+            }
+
+            // Empty and dot segments would change the route
+            if value.is_empty() || value == "." || value == ".." {
+                return Err(Error::InvalidPathParameter { name, value });
+            }
+
+            path = path.replace(&placeholder, &encode_path_segment(&value));
+        }
+
+        if let Some(start) = path.find('{') {
+            let name = path[start + 1..].split('}').next().unwrap_or_default();
+            return Err(Error::MissingPathParameter(name.to_string()));
+        }
+
+        Ok(path)
+    }
+
+    /// Builds the query string of the route.
+    fn query(&self) -> Option<String> {
+        let query_parameters = self.query_parameters();
+
+        if query_parameters.is_empty() {
+            None
+        } else {
+            Some(
+                url::form_urlencoded::Serializer::new(String::new())
+                    .extend_pairs(query_parameters)
+                    .finish(),
+            )
+        }
+    }
+}
+
+// Percent-encodes everything but the unreserved characters of RFC 3986, so a value stays a single path segment.
+fn encode_path_segment(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+
+    encoded
+}
+
+/// A plugin route which returns its results in pages linked by a cursor.
+pub trait PaginatedPluginRoute: PluginRoute {
+    /// The type of the items of a page.
+    type Item;
+
+    /// Sets the cursor of the page to request.
+    fn set_cursor(&mut self, cursor: String);
+
+    /// Splits a page into the cursor of the next page, if there is one, and its items.
+    fn into_page(response: Self::Response) -> (Option<String>, Vec<Self::Item>);
+}
+
+impl ClientInner {
+    /// Checks with `GET /api/routes` if the node exposes the given plugin.
+    pub async fn is_plugin_available<P: NodePlugin>(&self) -> Result<bool> {
+        Ok(self.get_routes().await?.routes.iter().any(|route| route == P::NAME))
+    }
+
+    /// Calls a plugin route.
+    /// If the node answers with a 404 and doesn't expose the plugin, [`Error::PluginNotAvailable`] is returned.
+    pub async fn call_plugin_route<R: PluginRoute>(&self, route: &R) -> Result<R::Response> {
+        let path = route.path()?;
+        let timeout = self.get_timeout().await;
+
+        let result = match R::METHOD {
+            PluginMethod::Get => {
+                self.node_manager
+                    .read()
+                    .await
+                    .get_request(
+                        &path,
+                        route.query().as_deref(),
+                        timeout,
+                        R::NEED_QUORUM,
+                        R::PREFER_PERMANODE,
+                    )
+                    .await
+            }
+            PluginMethod::Post => {
+                self.node_manager
+                    .read()
+                    .await
+                    .post_request_json(&path, timeout, route.body()?, true)
+                    .await
+            }
+        };
+
+        match result {
+            Err(Error::Node(NodeApiError::NotFound(url))) => {
+                if self.is_plugin_available::<R::Plugin>().await? {
+                    Err(Error::Node(NodeApiError::NotFound(url)))
+                } else {
+                    Err(Error::PluginNotAvailable(R::Plugin::NAME.to_string()))
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Calls a paginated plugin route and collects the items of all pages.
+    /// If the node returns a cursor again, [`Error::RepeatedCursor`] is returned instead of requesting pages forever.
+    pub async fn call_paginated_plugin_route<R: PaginatedPluginRoute>(&self, mut route: R) -> Result<Vec<R::Item>> {
+        let mut items = Vec::new();
+        let mut cursors = HashSet::new();
+
+        loop {
+            let (cursor, page) = R::into_page(self.call_plugin_route(&route).await?);
+            items.extend(page);
+
+            match cursor {
+                Some(cursor) => {
+                    if !cursors.insert(cursor.clone()) {
+                        return Err(Error::RepeatedCursor(cursor));
+                    }
+                    route.set_cursor(cursor);
+                }
+                None => break,
+            }
+        }
+
+        Ok(items)
+    }
+}
@@ -18,7 +18,7 @@ use crate::{
         error::{Error, Result},
         node_manager::{
             http_client::HttpClient,
-            node::{Node, NodeAuth, NodeDto},
+            node::{Node, NodeAuth, NodeDto, ProxyOptions, TlsOptions},
             NodeManager,
         },
     },
@@ -58,6 +58,12 @@ pub struct NodeManagerBuilder {
     /// The User-Agent header for requests
     #[serde(default = "default_user_agent")]
     pub user_agent: String,
+    /// Proxy through which all requests are sent, unless a node provides its own
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyOptions>,
+    /// TLS options for all requests, unless a node provides its own
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsOptions>,
 }
 
 fn default_user_agent() -> String {
@@ -87,6 +93,8 @@ impl NodeManagerBuilder {
             url,
             auth: None,
             disabled: false,
+            proxy: None,
+            tls: None,
         }));
         Ok(self)
     }
@@ -105,6 +113,8 @@ impl NodeManagerBuilder {
             url,
             auth,
             disabled: false,
+            proxy: None,
+            tls: None,
         }));
         Ok(self)
     }
@@ -123,6 +133,8 @@ impl NodeManagerBuilder {
             url,
             auth,
             disabled: false,
+            proxy: None,
+            tls: None,
         }));
         Ok(self)
     }
@@ -143,6 +155,8 @@ impl NodeManagerBuilder {
                     url,
                     auth,
                     disabled: false,
+                    proxy: None,
+                    tls: None,
                 }));
             }
             None => {
@@ -151,6 +165,8 @@ impl NodeManagerBuilder {
                     url,
                     auth,
                     disabled: false,
+                    proxy: None,
+                    tls: None,
                 }));
                 self.permanodes.replace(permanodes);
             }
@@ -177,6 +193,8 @@ impl NodeManagerBuilder {
             url,
             auth,
             disabled: false,
+            proxy: None,
+            tls: None,
         }));
         Ok(self)
     }
@@ -188,6 +206,8 @@ impl NodeManagerBuilder {
                 url,
                 auth: None,
                 disabled: false,
+                proxy: None,
+                tls: None,
             }));
         }
         Ok(self)
@@ -218,22 +238,47 @@ impl NodeManagerBuilder {
         self
     }
 
-    pub(crate) fn build(self, healthy_nodes: HashMap<Node, InfoResponse>) -> NodeManager {
-        NodeManager {
-            primary_node: self.primary_node.map(|node| node.into()),
-            primary_pow_node: self.primary_pow_node.map(|node| node.into()),
-            nodes: self.nodes.into_iter().map(|node| node.into()).collect(),
-            permanodes: self
-                .permanodes
-                .map(|nodes| nodes.into_iter().map(|node| node.into()).collect()),
+    pub(crate) fn with_proxy(mut self, proxy: ProxyOptions) -> Self {
+        self.proxy.replace(proxy);
+        self
+    }
+
+    pub(crate) fn with_tls(mut self, tls: TlsOptions) -> Self {
+        self.tls.replace(tls);
+        self
+    }
+
+    pub(crate) fn build(self, healthy_nodes: HashMap<Node, InfoResponse>) -> Result<NodeManager> {
+        let primary_node: Option<Node> = self.primary_node.map(|node| node.into());
+        let primary_pow_node: Option<Node> = self.primary_pow_node.map(|node| node.into());
+        let nodes: HashSet<Node> = self.nodes.into_iter().map(|node| node.into()).collect();
+        let permanodes: Option<HashSet<Node>> = self
+            .permanodes
+            .map(|nodes| nodes.into_iter().map(|node| node.into()).collect());
+        let http_client = HttpClient::with_options(
+            self.user_agent,
+            self.proxy,
+            self.tls,
+            primary_node
+                .iter()
+                .chain(primary_pow_node.iter())
+                .chain(nodes.iter())
+                .chain(permanodes.iter().flatten()),
+        )?;
+
+        Ok(NodeManager {
+            primary_node,
+            primary_pow_node,
+            nodes,
+            permanodes,
             ignore_node_health: self.ignore_node_health,
             node_sync_interval: self.node_sync_interval,
             healthy_nodes: RwLock::new(healthy_nodes),
             quorum: self.quorum,
             min_quorum_size: self.min_quorum_size,
             quorum_threshold: self.quorum_threshold,
-            http_client: HttpClient::new(self.user_agent),
-        }
+            http_client,
+        })
     }
 }
 
@@ -250,6 +295,8 @@ impl Default for NodeManagerBuilder {
             min_quorum_size: DEFAULT_MIN_QUORUM_SIZE,
             quorum_threshold: DEFAULT_QUORUM_THRESHOLD,
             user_agent: DEFAULT_USER_AGENT.to_string(),
+            proxy: None,
+            tls: None,
         }
     }
 }
@@ -278,6 +325,8 @@ impl From<&NodeManager> for NodeManagerBuilder {
             min_quorum_size: value.min_quorum_size,
             quorum_threshold: value.quorum_threshold,
             user_agent: value.http_client.user_agent.clone(),
+            proxy: value.http_client.proxy.clone(),
+            tls: value.http_client.tls.clone(),
         }
     }
 }
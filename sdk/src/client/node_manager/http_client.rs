@@ -3,7 +3,7 @@
 
 //! The node manager that takes care of sending requests with healthy nodes and quorum if enabled
 
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 
 use reqwest::RequestBuilder;
 use serde::de::DeserializeOwned;
@@ -11,8 +11,9 @@ use serde_json::Value;
 
 use crate::client::{
     node_api::error::{Error, Result},
-    node_manager::node::Node,
+    node_manager::node::{Node, ProxyOptions, TlsOptions},
 };
+
 pub(crate) struct Response(reqwest::Response);
 
 impl Response {
@@ -37,14 +38,69 @@ impl Response {
 #[derive(Clone)]
 pub(crate) struct HttpClient {
     client: reqwest::Client,
+    /// Clients for nodes which override the proxy or TLS options of the client.
+    node_clients: HashMap<(Option<ProxyOptions>, Option<TlsOptions>), reqwest::Client>,
     pub(crate) user_agent: String,
+    pub(crate) proxy: Option<ProxyOptions>,
+    pub(crate) tls: Option<TlsOptions>,
 }
 
 impl HttpClient {
     pub(crate) fn new(user_agent: String) -> Self {
         Self {
             client: reqwest::Client::new(),
+            node_clients: HashMap::new(),
+            user_agent,
+            proxy: None,
+            tls: None,
+        }
+    }
+
+    /// Creates a client with the given proxy and TLS options. Dedicated clients are created upfront for the nodes that
+    /// override these options.
+    pub(crate) fn with_options<'a>(
+        user_agent: String,
+        proxy: Option<ProxyOptions>,
+        tls: Option<TlsOptions>,
+        nodes: impl IntoIterator<Item = &'a Node>,
+    ) -> Result<Self> {
+        let mut node_clients = HashMap::new();
+
+        for node in nodes {
+            if node.proxy.is_some() || node.tls.is_some() {
+                let key = (node.proxy.clone(), node.tls.clone());
+                if !node_clients.contains_key(&key) {
+                    let client = build_client(
+                        node.proxy.as_ref().or(proxy.as_ref()),
+                        node.tls.as_ref().or(tls.as_ref()),
+                    )?;
+                    node_clients.insert(key, client);
+                }
+            }
+        }
+
+        Ok(Self {
+            client: build_client(proxy.as_ref(), tls.as_ref())?,
+            node_clients,
             user_agent,
+            proxy,
+            tls,
+        })
+    }
+
+    /// Returns the client that has to be used for requests to the node.
+    fn client_for(&self, node: &Node) -> Result<reqwest::Client> {
+        if node.proxy.is_none() && node.tls.is_none() {
+            return Ok(self.client.clone());
+        }
+
+        match self.node_clients.get(&(node.proxy.clone(), node.tls.clone())) {
+            Some(client) => Ok(client.clone()),
+            // The node wasn't known when this client was created
+            None => build_client(
+                node.proxy.as_ref().or(self.proxy.as_ref()),
+                node.tls.as_ref().or(self.tls.as_ref()),
+            ),
         }
     }
 
@@ -89,7 +145,7 @@ impl HttpClient {
     }
 
     pub(crate) async fn get(&self, node: Node, timeout: Duration) -> Result<Response> {
-        let mut request_builder = self.client.get(node.url.clone());
+        let mut request_builder = self.client_for(&node)?.get(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
         let start_time = instant::Instant::now();
         let resp = request_builder.send().await?;
@@ -104,7 +160,7 @@ impl HttpClient {
 
     // Get with header: "accept", "application/vnd.iota.serializer-v1"
     pub(crate) async fn get_bytes(&self, node: Node, timeout: Duration) -> Result<Response> {
-        let mut request_builder = self.client.get(node.url.clone());
+        let mut request_builder = self.client_for(&node)?.get(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
         request_builder = request_builder.header("accept", "application/vnd.iota.serializer-v1");
         let resp = request_builder.send().await?;
@@ -112,15 +168,67 @@ impl HttpClient {
     }
 
     pub(crate) async fn post_json(&self, node: Node, timeout: Duration, json: Value) -> Result<Response> {
-        let mut request_builder = self.client.post(node.url.clone());
+        let mut request_builder = self.client_for(&node)?.post(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
         Self::parse_response(request_builder.json(&json).send().await?, &node.url).await
     }
 
+    pub(crate) async fn delete(&self, node: Node, timeout: Duration) -> Result<Response> {
+        let mut request_builder = self.client_for(&node)?.delete(node.url.clone());
+        request_builder = self.build_request(request_builder, &node, timeout);
+        Self::parse_response(request_builder.send().await?, &node.url).await
+    }
+
     pub(crate) async fn post_bytes(&self, node: Node, timeout: Duration, body: &[u8]) -> Result<Response> {
-        let mut request_builder = self.client.post(node.url.clone());
+        let mut request_builder = self.client_for(&node)?.post(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
         request_builder = request_builder.header("Content-Type", "application/vnd.iota.serializer-v1");
         Self::parse_response(request_builder.body(body.to_vec()).send().await?, &node.url).await
     }
 }
+
+fn build_client(proxy: Option<&ProxyOptions>, tls: Option<&TlsOptions>) -> Result<reqwest::Client> {
+    #[cfg(target_family = "wasm")]
+    {
+        if proxy.is_some() || tls.is_some() {
+            return Err(Error::UnsupportedConnectionOptions);
+        }
+        Ok(reqwest::Client::new())
+    }
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let mut builder = reqwest::Client::builder();
+
+        if let Some(proxy) = proxy {
+            let mut reqwest_proxy = reqwest::Proxy::all(proxy.url.clone())?;
+            if let Some((name, password)) = &proxy.basic_auth_name_pwd {
+                reqwest_proxy = reqwest_proxy.basic_auth(name, password);
+            }
+            builder = builder.proxy(reqwest_proxy);
+        }
+
+        if let Some(tls) = tls {
+            #[cfg(feature = "tls")]
+            {
+                for certificate in &tls.root_certificates {
+                    builder = builder.add_root_certificate(reqwest::Certificate::from_pem(certificate.as_bytes())?);
+                }
+                if tls.disable_built_in_root_certificates {
+                    builder = builder.tls_built_in_root_certs(false);
+                }
+                if let Some(path) = &tls.client_identity_path {
+                    let identity = std::fs::read(path)
+                        .map_err(|e| Error::TlsClientIdentity(format!("{}: {e}", path.display())))?;
+                    builder = builder.identity(reqwest::Identity::from_pem(&identity)?);
+                }
+            }
+            #[cfg(not(feature = "tls"))]
+            {
+                let _ = tls;
+                return Err(Error::UnsupportedConnectionOptions);
+            }
+        }
+
+        Ok(builder.build()?)
+    }
+}
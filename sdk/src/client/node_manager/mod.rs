@@ -149,18 +149,7 @@ impl NodeManager {
 
         // Set path and query parameters
         for node in &mut nodes_with_modified_url {
-            node.url.set_path(path);
-            node.url.set_query(query);
-            if let Some(auth) = &node.auth {
-                if let Some((name, password)) = &auth.basic_auth_name_pwd {
-                    node.url
-                        .set_username(name)
-                        .map_err(|_| crate::client::Error::UrlAuth("username"))?;
-                    node.url
-                        .set_password(Some(password))
-                        .map_err(|_| crate::client::Error::UrlAuth("password"))?;
-                }
-            }
+            set_node_url(node, path, query)?;
         }
 
         Ok(nodes_with_modified_url)
@@ -371,4 +360,72 @@ impl NodeManager {
         // Each node will throw an error or return Ok()
         Err(error.unwrap())
     }
+
+    // Protected routes are specific to a node and can change its state, so unlike the other requests they are only sent
+    // to an explicitly configured node and never retried with another one.
+    pub(crate) async fn get_protected_request<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        timeout: Duration,
+    ) -> Result<T> {
+        let node = self.protected_node(path)?;
+
+        Ok(self.http_client.get(node, timeout).await?.into_json().await?)
+    }
+
+    pub(crate) async fn post_protected_request_json<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        timeout: Duration,
+        json: Value,
+    ) -> Result<T> {
+        let node = self.protected_node(path)?;
+
+        Ok(self
+            .http_client
+            .post_json(node, timeout, json)
+            .await?
+            .into_json()
+            .await?)
+    }
+
+    pub(crate) async fn delete_protected_request(&self, path: &str, timeout: Duration) -> Result<()> {
+        let node = self.protected_node(path)?;
+
+        self.http_client.delete(node, timeout).await?;
+
+        Ok(())
+    }
+
+    // The primary node, or the only node if no primary node is set.
+    fn protected_node(&self, path: &str) -> Result<Node> {
+        let mut node = match &self.primary_node {
+            Some(primary_node) => primary_node.clone(),
+            None if self.nodes.len() == 1 => self.nodes.iter().next().cloned().ok_or(Error::HealthyNodePoolEmpty)?,
+            None => return Err(Error::ProtectedNodeMissing),
+        };
+        if node.disabled {
+            return Err(Error::ProtectedNodeMissing);
+        }
+        set_node_url(&mut node, path, None)?;
+
+        Ok(node)
+    }
+}
+
+fn set_node_url(node: &mut Node, path: &str, query: Option<&str>) -> Result<()> {
+    node.url.set_path(path);
+    node.url.set_query(query);
+    if let Some(auth) = &node.auth {
+        if let Some((name, password)) = &auth.basic_auth_name_pwd {
+            node.url
+                .set_username(name)
+                .map_err(|_| crate::client::Error::UrlAuth("username"))?;
+            node.url
+                .set_password(Some(password))
+                .map_err(|_| crate::client::Error::UrlAuth("password"))?;
+        }
+    }
+
+    Ok(())
 }
@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::hash::Hash;
+use std::{hash::Hash, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +17,31 @@ pub struct NodeAuth {
     pub basic_auth_name_pwd: Option<(String, String)>,
 }
 
+/// Proxy through which the requests to a node are sent.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyOptions {
+    /// Proxy url, `http://`, `https://` or, with the `socks` feature, `socks5://`.
+    pub url: Url,
+    /// Username and password for the proxy.
+    pub basic_auth_name_pwd: Option<(String, String)>,
+}
+
+/// TLS options for the connection to a node.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct TlsOptions {
+    /// PEM encoded root certificates that are trusted in addition to the built-in ones.
+    #[serde(default)]
+    pub root_certificates: Vec<String>,
+    /// If only the provided root certificates should be trusted, which pins the connection to them.
+    #[serde(default)]
+    pub disable_built_in_root_certificates: bool,
+    /// Path to a PEM file with the client certificate and private key, used for mutual TLS. The file is read when
+    /// the connection is built, so the private key isn't part of the options.
+    pub client_identity_path: Option<PathBuf>,
+}
+
 /// Node definition.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Node {
@@ -27,6 +52,12 @@ pub struct Node {
     /// Whether the node is disabled or not.
     #[serde(default)]
     pub disabled: bool,
+    /// Proxy for this node, overrides the one of the client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyOptions>,
+    /// TLS options for this node, override the ones of the client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsOptions>,
 }
 
 impl From<Url> for Node {
@@ -35,6 +66,8 @@ impl From<Url> for Node {
             url,
             auth: None,
             disabled: false,
+            proxy: None,
+            tls: None,
         }
     }
 }
@@ -67,10 +67,11 @@ impl ClientInner {
         log::debug!("sync_nodes");
         let mut healthy_nodes = HashMap::new();
         let mut network_nodes: HashMap<String, Vec<(InfoResponse, Node)>> = HashMap::new();
+        let http_client = self.node_manager.read().await.http_client.clone();
 
         for node in nodes {
             // Put the healthy node url into the network_nodes
-            match crate::client::Client::get_node_info(node.url.as_ref(), node.auth.clone()).await {
+            match crate::client::Client::get_node_info_with_http_client(&http_client, node.clone()).await {
                 Ok(info) => {
                     if info.status.is_healthy || ignore_node_health {
                         match network_nodes.get_mut(&info.protocol.network_name) {
@@ -283,9 +283,10 @@ impl SignTransactionEssence for LedgerSecretManager {
             log::debug!("[LEDGER] {:?} {:?}", input_bip32_indices, essence_hash);
             ledger.prepare_blind_signing(input_bip32_indices, essence_hash)?;
         } else {
-            // figure out the remainder address and bip32 index (if there is one)
+            // figure out the remainder address and bip32 index (if there is one), the ledger only knows a single
+            // remainder, so split remainders are shown like other outputs
             let (remainder_address, remainder_bip32): (Option<&Address>, LedgerBIP32Index) =
-                match &prepared_transaction.remainder {
+                match prepared_transaction.remainders.first() {
                     Some(a) => {
                         let remainder_bip32_indices: Vec<u32> = match &a.chain {
                             Some(chain) => {
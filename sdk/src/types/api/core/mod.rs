@@ -5,4 +5,5 @@
 
 pub mod dto;
 pub mod error;
+pub mod request;
 pub mod response;
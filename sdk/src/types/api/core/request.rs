@@ -0,0 +1,60 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::string::String;
+
+/// Request of POST /api/core/v2/peers.
+/// Adds a new peer to the node.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct AddPeerRequest {
+    pub multi_address: String,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub alias: Option<String>,
+}
+
+/// Request of POST /api/core/v2/control/database/prune.
+/// Exactly one pruning criterion has to be provided.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum PruneDatabaseRequest {
+    /// Prunes the database until the given milestone index.
+    Index(u32),
+    /// Prunes the database, keeping the given amount of milestones.
+    Depth(u32),
+    /// Prunes the database until it fits the given size, e.g. "4GB".
+    TargetDatabaseSize(String),
+}
+
+/// Request of POST /api/core/v2/control/snapshots/create.
+/// Creates a snapshot at the given milestone index.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct CreateSnapshotsRequest {
+    pub index: u32,
+}
+
+/// Request of POST /dashboard/auth.
+/// Exchanges the dashboard credentials for a JWT which can be used for the protected routes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct DashboardAuthRequest {
+    pub user: String,
+    pub password: String,
+}
@@ -323,6 +323,43 @@ pub struct AddPeerResponse(pub PeerDto);
 )]
 pub struct PeerResponse(pub PeerDto);
 
+/// Response of POST /api/core/v2/control/database/prune.
+/// Returns the milestone index until which the database was pruned.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct PruneDatabaseResponse {
+    pub index: u32,
+}
+
+/// Response of POST /api/core/v2/control/snapshots/create.
+/// Returns the milestone index and the path of the created snapshot file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct CreateSnapshotsResponse {
+    pub index: u32,
+    pub file_path: String,
+}
+
+/// Response of POST /dashboard/auth.
+/// Returns a JWT for the protected routes of the node.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct DashboardAuthResponse {
+    pub jwt: String,
+}
+
 /// Response of GET /api/plugins/debug/whiteflag.
 /// Returns the computed merkle tree hash for the given white flag traversal.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -136,6 +136,8 @@ pub struct ValidationContext<'a> {
     pub storage_deposit_returns: HashMap<Address, u64>,
     ///
     pub simple_deposits: HashMap<Address, u64>,
+    /// Whether signature unlocks are checked against the essence hash, only disabled by the dry-run of a transaction.
+    pub(crate) verify_signatures: bool,
 }
 
 impl<'a> ValidationContext<'a> {
@@ -180,6 +182,7 @@ impl<'a> ValidationContext<'a> {
             unlocked_addresses: HashSet::new(),
             storage_deposit_returns: HashMap::new(),
             simple_deposits: HashMap::new(),
+            verify_signatures: true,
         }
     }
 }
@@ -9,7 +9,7 @@ use crate::{
     client::{api::GetAddressesOptions, secret::SecretManager},
     types::block::address::{Bech32Address, Hrp},
     wallet::{
-        account::{types::AccountAddress, Account, AccountDetails},
+        account::{types::AccountAddress, Account, AccountDetails, Labels},
         Error, Wallet,
     },
 };
@@ -19,6 +19,7 @@ pub struct AccountBuilder {
     addresses: Option<Vec<AccountAddress>>,
     alias: Option<String>,
     bech32_hrp: Option<Hrp>,
+    address_gap_limit: Option<u32>,
     wallet: Wallet,
 }
 
@@ -29,6 +30,7 @@ impl AccountBuilder {
             addresses: None,
             alias: None,
             bech32_hrp: None,
+            address_gap_limit: None,
             wallet,
         }
     }
@@ -52,6 +54,13 @@ impl AccountBuilder {
         self
     }
 
+    /// Set the amount of unused addresses which are kept after the last used address of each chain, see
+    /// [`Account::set_address_gap_limit()`]
+    pub fn with_address_gap_limit(mut self, address_gap_limit: impl Into<Option<u32>>) -> Self {
+        self.address_gap_limit = address_gap_limit.into();
+        self
+    }
+
     /// Build the Account and add it to the accounts from Wallet
     /// Also generates the first address of the account and if it's not the first account, the address for the first
     /// account will also be generated and compared, so no accounts get generated with different seeds
@@ -148,6 +157,18 @@ impl AccountBuilder {
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            transaction_plan: None,
+            replaced_transactions: HashMap::new(),
+            scheduled_intents: Vec::new(),
+            next_scheduled_intent_id: 0,
+            transaction_dependencies: HashMap::new(),
+            transactions_to_replan: Vec::new(),
+            synced_milestone: None,
+            address_gap_limit: self.address_gap_limit,
+            first_synced_milestone: None,
+            labels: Labels::default(),
+            invoices: Vec::new(),
+            next_invoice_id: 0,
         };
 
         let account = Account::new(account, self.wallet.inner.clone()).await?;
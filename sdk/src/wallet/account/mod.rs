@@ -25,16 +25,26 @@ use tokio::sync::{Mutex, RwLock};
 
 #[cfg(feature = "participation")]
 pub use self::operations::participation::{AccountParticipationOverview, ParticipationEventWithNodes};
+#[cfg(feature = "storage")]
+pub use self::operations::transaction::high_level::batch_payout::{
+    BatchPayout, PayoutRecipient, PayoutRecipientState, PayoutStatus,
+};
 use self::types::{
     address::{AccountAddress, AddressWithUnspentOutputs},
-    Balance, OutputData, Transaction,
+    Balance, MilestonePoint, OutputData, Transaction,
 };
 pub use self::{
     operations::{
+        export::{ExportDirection, ExportOptions, ExportRow},
+        history::{HistoryEntry, HistoryEntryKind, HistoryOptions, HistoryPage},
+        invoices::{Invoice, InvoiceParams, InvoiceStatus},
+        labels::{AddressLabel, Contact, LabelMatch, Labels},
         output_claiming::OutputsToClaim,
+        payment_uri::PaymentUri,
+        scheduled_intents::{ScheduledIntent, ScheduledIntentAction, ScheduledIntentKind},
         syncing::{
             options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
-            SyncOptions,
+            CancellationToken, SyncOptions, SyncedMilestone, SyncedOutputKinds,
         },
         transaction::{
             high_level::{
@@ -45,12 +55,15 @@ pub use self::{
                         PreparedMintTokenTransactionDto,
                     },
                     mint_nfts::{MintNftParams, MintNftParamsDto},
+                    nft_collection::{CollectionNft, CollectionNftParams, Irc27Attribute, Irc27Metadata},
                 },
+                transaction_plan::{TransactionPlan, TransactionPlanStep, TransactionPlanStepKind},
             },
             prepare_output::{
                 Assets, Features, OutputParams, OutputParamsDto, ReturnStrategy, StorageDeposit, Unlocks,
             },
-            RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
+            RemainderPlacement, RemainderValueStrategy, TokenBalanceDelta, TransactionDependency, TransactionDryRun,
+            TransactionDryRunDto, TransactionOptions, TransactionOptionsDto, TransactionReplan,
         },
     },
     types::OutputDataDto,
@@ -137,6 +150,43 @@ pub struct AccountDetails {
     /// Foundries for native tokens in outputs
     #[serde(default)]
     native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    /// Transaction plan which is currently executed, kept until all of its steps are confirmed so it can be resumed
+    #[serde(default)]
+    pub(crate) transaction_plan: Option<TransactionPlan>,
+    /// Pending transactions that got replaced by a conflicting transaction, mapped to the id of the replacement
+    #[serde(default)]
+    replaced_transactions: HashMap<TransactionId, TransactionId>,
+    /// Intents which are executed once their conditions are met
+    #[serde(default)]
+    scheduled_intents: Vec<ScheduledIntent>,
+    /// Identifier of the next scheduled intent, so identifiers of removed intents aren't reused
+    #[serde(default)]
+    pub(crate) next_scheduled_intent_id: u32,
+    /// Pending transactions that spend outputs of other pending transactions, mapped to their dependency
+    #[serde(default)]
+    transaction_dependencies: HashMap<TransactionId, TransactionDependency>,
+    /// Transactions that conflicted because a transaction they depended on conflicted, to be sent again
+    #[serde(default)]
+    transactions_to_replan: Vec<TransactionReplan>,
+    /// The milestone up to which all addresses were synced, to continue syncing incrementally
+    #[serde(default)]
+    synced_milestone: Option<SyncedMilestone>,
+    /// Amount of unused addresses kept after the last used address of each chain, addresses are generated during
+    /// syncing to keep it
+    #[serde(default)]
+    address_gap_limit: Option<u32>,
+    /// The confirmed milestone of the first sync, outputs spent before it can be unknown to the account
+    #[serde(default)]
+    first_synced_milestone: Option<MilestonePoint>,
+    /// The address book and the labels of addresses and outputs
+    #[serde(default)]
+    labels: Labels,
+    /// Invoices, outputs paying them are matched while syncing
+    #[serde(default)]
+    invoices: Vec<Invoice>,
+    /// Identifier of the next invoice, so identifiers of removed invoices aren't reused
+    #[serde(default)]
+    pub(crate) next_invoice_id: u32,
 }
 
 /// A thread guard over an account, so we can lock the account during operations.
@@ -154,6 +204,9 @@ pub struct AccountInner {
     // again, because sending transactions can change that
     pub(crate) last_synced: Mutex<u128>,
     pub(crate) default_sync_options: Mutex<SyncOptions>,
+    // outputs and options of prepared transactions which can spend outputs of pending transactions, by the hash of
+    // their essence, so their dependency can be recorded once they're submitted
+    pub(crate) unconfirmed_input_requests: Mutex<HashMap<[u8; 32], (Vec<Output>, TransactionOptions)>>,
 }
 
 // impl Deref so we can use `account.details()` instead of `account.details.read()`
@@ -185,6 +238,7 @@ impl Account {
                 details: RwLock::new(details),
                 last_synced: Default::default(),
                 default_sync_options: Mutex::new(default_sync_options),
+                unconfirmed_input_requests: Default::default(),
             }),
         })
     }
@@ -544,6 +598,18 @@ fn serialize() {
         incoming_transactions,
         inaccessible_incoming_transactions: HashSet::new(),
         native_token_foundries: HashMap::new(),
+        transaction_plan: None,
+        replaced_transactions: HashMap::new(),
+        scheduled_intents: Vec::new(),
+        next_scheduled_intent_id: 0,
+        transaction_dependencies: HashMap::new(),
+        transactions_to_replan: Vec::new(),
+        synced_milestone: None,
+        address_gap_limit: None,
+        first_synced_milestone: None,
+        labels: Labels::default(),
+        invoices: Vec::new(),
+        next_invoice_id: 0,
     };
 
     serde_json::from_str::<AccountDetails>(&serde_json::to_string(&account).unwrap()).unwrap();
@@ -579,6 +645,18 @@ impl AccountDetails {
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            transaction_plan: None,
+            replaced_transactions: HashMap::new(),
+            scheduled_intents: Vec::new(),
+            next_scheduled_intent_id: 0,
+            transaction_dependencies: HashMap::new(),
+            transactions_to_replan: Vec::new(),
+            synced_milestone: None,
+            address_gap_limit: None,
+            first_synced_milestone: None,
+            labels: Labels::default(),
+            invoices: Vec::new(),
+            next_invoice_id: 0,
         }
     }
 }
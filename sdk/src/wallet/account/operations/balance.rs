@@ -1,18 +1,22 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::{HashMap, HashSet};
+
 use primitive_types::U256;
 
 use crate::{
     types::block::{
         address::Bech32Address,
-        output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent},
+        output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, OutputId, Rent},
         ConvertTo,
     },
     wallet::{
         account::{
-            operations::helpers::time::can_output_be_unlocked_forever_from_now_on,
-            types::{AddressWithUnspentOutputs, Balance, NativeTokensBalance},
+            operations::{helpers::time::can_output_be_unlocked_forever_from_now_on, history::account_owned_addresses},
+            types::{
+                AddressWithUnspentOutputs, Balance, BalancePoint, HistoricalBalance, NativeTokensBalance, OutputData,
+            },
             Account, AccountDetails, OutputsToClaim,
         },
         Error, Result,
@@ -269,27 +273,180 @@ impl Account {
         self.finish(
             balance,
             account_details,
+            &account_details.locked_outputs,
             network_id,
             total_rent_amount,
             total_native_tokens,
         )
     }
 
+    /// Get the balance of the account at a past milestone or time, reconstructed from the booked and spent milestones
+    /// of the stored outputs. Time related unlock conditions are evaluated at the time of the point. Outputs whose
+    /// spending milestone is unknown and points before the first sync are reported, because the account can't know
+    /// all of its outputs then.
+    pub async fn historical_balance(&self, point: BalancePoint) -> Result<HistoricalBalance> {
+        log::debug!("[BALANCE] historical_balance {point:?}");
+
+        let network_id = self.client().get_network_id().await?;
+        let rent_structure = self.client().get_rent_structure().await?;
+        let point_timestamp = match point {
+            BalancePoint::Milestone { index } => self.milestone_timestamp(index).await?,
+            BalancePoint::Timestamp { timestamp_seconds } => timestamp_seconds,
+        };
+
+        let account_details = self.details().await;
+        let owned_addresses = account_owned_addresses(&account_details);
+        let mut balance = Balance::default();
+        let mut total_rent_amount = 0;
+        let mut total_native_tokens = NativeTokensBuilder::default();
+        let (unspent_outputs, unknown_spent_outputs) = outputs_at_point(&account_details.outputs, network_id, point);
+
+        for data in unspent_outputs {
+            let output_id = &data.output_id;
+            let output = &data.output;
+            let rent = output.rent_cost(&rent_structure);
+            match output {
+                Output::Alias(alias_output) => {
+                    balance.base_coin.total += output.amount();
+                    balance.required_storage_deposit.alias += rent;
+                    total_rent_amount += rent;
+                    total_native_tokens.add_native_tokens(alias_output.native_tokens().clone())?;
+                    balance.aliases.push(alias_output.alias_id_non_null(output_id));
+                }
+                Output::Foundry(foundry_output) => {
+                    balance.base_coin.total += output.amount();
+                    balance.required_storage_deposit.foundry += rent;
+                    total_rent_amount += rent;
+                    total_native_tokens.add_native_tokens(foundry_output.native_tokens().clone())?;
+                    balance.foundries.push(foundry_output.id());
+                }
+                _ => {
+                    let unlock_conditions = output
+                        .unlock_conditions()
+                        .expect("output needs to have unlock conditions");
+                    let amount = if let [UnlockCondition::Address(_)] = unlock_conditions.as_ref() {
+                        output.amount()
+                    } else {
+                        let (required_address, _) =
+                            output.required_and_unlocked_address(point_timestamp, output_id, None)?;
+                        if unlock_conditions.is_time_locked(point_timestamp)
+                            || !owned_addresses.contains(&required_address)
+                        {
+                            // Not expired yet, could have become unlockable later
+                            if unlock_conditions
+                                .expiration()
+                                .map_or(true, |expiration| point_timestamp < expiration.timestamp())
+                            {
+                                balance.potentially_locked_outputs.insert(*output_id, false);
+                            }
+                            continue;
+                        }
+                        let unlockable_forever = unlock_conditions.expiration().map_or(true, |expiration| {
+                            expiration
+                                .return_address_expired(point_timestamp)
+                                .map_or(false, |return_address| owned_addresses.contains(return_address))
+                        });
+                        if !unlockable_forever {
+                            balance.potentially_locked_outputs.insert(*output_id, true);
+                            continue;
+                        }
+                        // The storage deposit has to be sent back, unless it returns to the account
+                        unlock_conditions
+                            .storage_deposit_return()
+                            .map_or(output.amount(), |sdr| {
+                                if owned_addresses.contains(sdr.return_address()) {
+                                    output.amount()
+                                } else {
+                                    output.amount() - sdr.amount()
+                                }
+                            })
+                    };
+
+                    if let Output::Nft(nft_output) = output {
+                        balance.nfts.push(nft_output.nft_id_non_null(output_id));
+                    }
+                    balance.base_coin.total += amount;
+                    if output.is_basic() {
+                        balance.required_storage_deposit.basic += rent;
+                        if output
+                            .native_tokens()
+                            .map(|native_tokens| !native_tokens.is_empty())
+                            .unwrap_or(false)
+                        {
+                            total_rent_amount += rent;
+                        }
+                    } else if output.is_nft() {
+                        balance.required_storage_deposit.nft += rent;
+                        total_rent_amount += rent;
+                    }
+                    if let Some(native_tokens) = output.native_tokens() {
+                        total_native_tokens.add_native_tokens(native_tokens.clone())?;
+                    }
+                }
+            }
+        }
+        let before_first_sync =
+            account_details
+                .first_synced_milestone
+                .map_or(true, |first_synced_milestone| match point {
+                    BalancePoint::Milestone { index } => index < first_synced_milestone.index,
+                    BalancePoint::Timestamp { timestamp_seconds } => {
+                        timestamp_seconds < first_synced_milestone.timestamp
+                    }
+                });
+
+        // Nothing was locked by pending transactions in the past
+        let balance = self.finish(
+            balance,
+            &account_details,
+            &HashSet::new(),
+            network_id,
+            total_rent_amount,
+            total_native_tokens,
+        )?;
+
+        Ok(HistoricalBalance {
+            point,
+            balance,
+            unknown_spent_outputs,
+            before_first_sync,
+        })
+    }
+
+    // Returns the timestamp of a milestone, from the stored outputs if possible
+    async fn milestone_timestamp(&self, index: u32) -> Result<u32> {
+        let stored_timestamp = self.details().await.outputs.values().find_map(|data| {
+            if data.metadata.milestone_index_booked() == index {
+                Some(data.metadata.milestone_timestamp_booked())
+            } else if data.metadata.milestone_index_spent() == Some(index) {
+                data.metadata.milestone_timestamp_spent()
+            } else {
+                None
+            }
+        });
+
+        match stored_timestamp {
+            Some(timestamp) => Ok(timestamp),
+            None => Ok(self.client().get_milestone_by_index(index).await?.essence().timestamp()),
+        }
+    }
+
     fn finish(
         &self,
         mut balance: Balance,
         account_details: &AccountDetails,
+        locked_outputs: &HashSet<OutputId>,
         network_id: u64,
         total_rent_amount: u64,
         total_native_tokens: NativeTokensBuilder,
     ) -> Result<Balance> {
         // for `available` get locked_outputs, sum outputs amount and subtract from total_amount
-        log::debug!("[BALANCE] locked outputs: {:#?}", account_details.locked_outputs);
+        log::debug!("[BALANCE] locked outputs: {:#?}", locked_outputs);
 
         let mut locked_amount = 0;
         let mut locked_native_tokens = NativeTokensBuilder::default();
 
-        for locked_output in &account_details.locked_outputs {
+        for locked_output in locked_outputs {
             // Skip potentially_locked_outputs, as their amounts aren't added to the balance
             if balance.potentially_locked_outputs.contains_key(locked_output) {
                 continue;
@@ -354,3 +511,130 @@ impl Account {
         Ok(balance)
     }
 }
+
+// Returns the outputs of the network which were booked and unspent at the point and, sorted, the spent outputs booked
+// before the point whose spending milestone is unknown
+fn outputs_at_point(
+    outputs: &HashMap<OutputId, OutputData>,
+    network_id: u64,
+    point: BalancePoint,
+) -> (Vec<&OutputData>, Vec<OutputId>) {
+    let mut unspent_outputs = Vec::new();
+    let mut unknown_spent_outputs = Vec::new();
+
+    for data in outputs.values() {
+        if data.network_id != network_id {
+            continue;
+        }
+        let (booked, spent) = match point {
+            BalancePoint::Milestone { index } => (
+                data.metadata.milestone_index_booked() <= index,
+                data.metadata.milestone_index_spent().map(|spent| spent <= index),
+            ),
+            BalancePoint::Timestamp { timestamp_seconds } => (
+                data.metadata.milestone_timestamp_booked() <= timestamp_seconds,
+                data.metadata
+                    .milestone_timestamp_spent()
+                    .map(|spent| spent <= timestamp_seconds),
+            ),
+        };
+        if !booked {
+            continue;
+        }
+        match spent {
+            Some(true) => {}
+            None if data.is_spent => unknown_spent_outputs.push(data.output_id),
+            _ => unspent_outputs.push(data),
+        }
+    }
+    unknown_spent_outputs.sort();
+
+    (unspent_outputs, unknown_spent_outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Address, Ed25519Address},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputMetadata},
+        payload::transaction::TransactionId,
+        protocol::protocol_parameters,
+        BlockId,
+    };
+
+    // An output booked at the milestone, spent at the other milestone if known, milestone timestamps are the index
+    // times 100
+    fn output_data(index: u16, booked: u32, spent: Option<Option<u32>>, network_id: u64) -> OutputData {
+        let address = Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let output_id = OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), index).unwrap();
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap();
+        let spent_index = spent.flatten();
+
+        OutputData {
+            metadata: OutputMetadata::new(
+                BlockId::null(),
+                output_id,
+                spent.is_some(),
+                spent_index,
+                spent_index.map(|index| index * 100),
+                None,
+                booked,
+                booked * 100,
+                20,
+            ),
+            is_spent: spent.is_some(),
+            network_id,
+            ..OutputData::mock(output_id, output, address)
+        }
+    }
+
+    #[test]
+    fn historical_outputs() {
+        let outputs = [
+            output_data(0, 5, None, 1),
+            // Spent before the point
+            output_data(1, 5, Some(Some(8)), 1),
+            // Spent after the point
+            output_data(2, 5, Some(Some(12)), 1),
+            // Booked after the point
+            output_data(3, 11, None, 1),
+            // Spent, but the spending milestone was pruned before the account synced the output
+            output_data(5, 5, Some(None), 1),
+            output_data(4, 5, Some(None), 1),
+            // Another network
+            output_data(6, 5, None, 2),
+        ]
+        .into_iter()
+        .map(|data| (data.output_id, data))
+        .collect::<HashMap<_, _>>();
+        let output_id = |index| OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), index).unwrap();
+
+        for point in [
+            BalancePoint::Milestone { index: 10 },
+            BalancePoint::Timestamp {
+                timestamp_seconds: 1_000,
+            },
+        ] {
+            let (unspent_outputs, unknown_spent_outputs) = outputs_at_point(&outputs, 1, point);
+            let mut unspent_output_ids = unspent_outputs.iter().map(|data| data.output_id).collect::<Vec<_>>();
+            unspent_output_ids.sort();
+
+            assert_eq!(unspent_output_ids, [output_id(0), output_id(2)], "{point:?}");
+            assert_eq!(unknown_spent_outputs, [output_id(4), output_id(5)], "{point:?}");
+        }
+
+        // Outputs booked or spent at the point are applied already
+        let (unspent_outputs, _) = outputs_at_point(&outputs, 1, BalancePoint::Milestone { index: 12 });
+        assert!(unspent_outputs.iter().all(|data| data.output_id != output_id(2)));
+        assert!(unspent_outputs.iter().any(|data| data.output_id == output_id(3)));
+
+        let (unspent_outputs, unknown_spent_outputs) =
+            outputs_at_point(&outputs, 1, BalancePoint::Milestone { index: 4 });
+        assert!(unspent_outputs.is_empty());
+        assert!(unknown_spent_outputs.is_empty());
+    }
+}
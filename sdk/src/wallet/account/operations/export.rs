@@ -0,0 +1,417 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::{Address, Hrp},
+        input::Input,
+        output::{Output, OutputId, Rent, RentStructure, TokenId},
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
+    wallet::account::{
+        operations::{
+            history::{account_owned_addresses, history_entry, HistoryEntryKind},
+            transaction::dry_run::output_owner,
+        },
+        types::Transaction,
+        Account, AccountDetails, FilterOptions,
+    },
+};
+
+/// Options to export the history of an account.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportOptions {
+    /// Only export transactions with a timestamp at or after this one, in milliseconds since the unix epoch.
+    pub lower_bound_timestamp: Option<u128>,
+    /// Only export transactions with a timestamp at or before this one, in milliseconds since the unix epoch.
+    pub upper_bound_timestamp: Option<u128>,
+    /// Only export transactions which consumed or created an output of the account matching these filters.
+    pub filter: Option<FilterOptions>,
+}
+
+/// Whether the balance of a token increased or decreased.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportDirection {
+    /// The balance increased.
+    Incoming,
+    /// The balance decreased.
+    Outgoing,
+}
+
+/// A balance movement of a single token caused by a transaction, created by [`Account::export_history()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRow {
+    /// Milliseconds since the unix epoch when the transaction was created or received.
+    #[serde(with = "crate::utils::serde::string")]
+    pub timestamp: u128,
+    /// Index of the milestone which confirmed the transaction, if known from the outputs of the account.
+    pub milestone_index: Option<u32>,
+    /// The transaction id.
+    pub transaction_id: TransactionId,
+    /// Whether the balance increased or decreased.
+    pub direction: ExportDirection,
+    /// What the transaction did for the account.
+    pub kind: HistoryEntryKind,
+    /// Bech32 encoded counterparties of the transaction, separated by `;`.
+    pub counterparty: Option<String>,
+    /// Names of the counterparties in the address book, separated by `;`.
+    pub counterparty_name: Option<String>,
+    /// The native token, `None` for the base coin.
+    pub token_id: Option<TokenId>,
+    /// Decimal amount by which the balance changed.
+    pub amount: String,
+    /// Decimal part of a base coin amount which only covers the storage deposit of the moved outputs or has to be
+    /// returned, `0` for native tokens.
+    pub storage_deposit: String,
+    /// The note of the transaction.
+    pub note: Option<String>,
+    /// Tags of the outputs of the account consumed or created by the transaction, separated by `;`.
+    pub tags: Option<String>,
+}
+
+impl ExportRow {
+    /// Writes the rows as a JSON array.
+    pub fn to_json(rows: &[Self]) -> crate::wallet::Result<String> {
+        Ok(serde_json::to_string_pretty(rows)?)
+    }
+
+    /// Writes the rows as CSV with the header
+    /// `timestamp,milestoneIndex,transactionId,direction,kind,counterparty,counterpartyName,tokenId,amount,
+    /// storageDeposit, note,tags`.
+    #[cfg(feature = "csv")]
+    pub fn to_csv(rows: &[Self]) -> crate::wallet::Result<String> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        for row in rows {
+            writer
+                .serialize(row)
+                .map_err(|e| crate::wallet::Error::Export(e.to_string()))?;
+        }
+        let bytes = writer
+            .into_inner()
+            .map_err(|e| crate::wallet::Error::Export(e.to_string()))?;
+
+        String::from_utf8(bytes).map_err(|e| crate::wallet::Error::Export(e.to_string()))
+    }
+}
+
+impl Account {
+    /// Exports every balance movement of the sent and received transactions of the account, one row per transaction
+    /// and token. The rows are sorted by timestamp, transaction id, token id and direction, so exports of the same
+    /// account state are identical. Received transactions are only known if they were synced with
+    /// [`SyncOptions::sync_incoming_transactions`](crate::wallet::account::SyncOptions).
+    pub async fn export_history(&self, options: ExportOptions) -> crate::wallet::Result<Vec<ExportRow>> {
+        log::debug!("[export_history]");
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let rent_structure = self.client().get_rent_structure().await?;
+
+        let account_details = self.details().await;
+        let account_addresses = account_owned_addresses(&account_details);
+
+        let mut rows = Vec::new();
+        for transaction in account_details
+            .transactions
+            .values()
+            .chain(account_details.incoming_transactions.values())
+        {
+            if options
+                .lower_bound_timestamp
+                .map_or(false, |timestamp| transaction.timestamp < timestamp)
+                || options
+                    .upper_bound_timestamp
+                    .map_or(false, |timestamp| transaction.timestamp > timestamp)
+            {
+                continue;
+            }
+            if let Some(filter) = &options.filter {
+                let account_outputs = transaction_output_ids(transaction)
+                    .into_iter()
+                    .filter_map(|output_id| account_details.outputs.get(&output_id));
+                if self.filter_outputs(account_outputs, filter.clone())?.is_empty() {
+                    continue;
+                }
+            }
+
+            rows.extend(transaction_export_rows(
+                transaction,
+                &account_details,
+                &account_addresses,
+                bech32_hrp,
+                &rent_structure,
+            )?);
+        }
+        drop(account_details);
+
+        rows.sort_by(|a, b| {
+            a.timestamp
+                .cmp(&b.timestamp)
+                .then_with(|| a.transaction_id.cmp(&b.transaction_id))
+                .then_with(|| a.token_id.cmp(&b.token_id))
+                .then_with(|| a.direction.cmp(&b.direction))
+        });
+
+        Ok(rows)
+    }
+}
+
+// Builds the rows of the balance movements of a transaction, one per token and direction
+fn transaction_export_rows(
+    transaction: &Transaction,
+    account_details: &AccountDetails,
+    account_addresses: &HashSet<Address>,
+    bech32_hrp: Hrp,
+    rent_structure: &RentStructure,
+) -> crate::wallet::Result<Vec<ExportRow>> {
+    let entry = history_entry(transaction, &account_details.outputs, account_addresses, bech32_hrp)?;
+    let milestone_index = transaction_milestone_index(transaction, account_details);
+    let counterparty = (!entry.counterparties.is_empty()).then(|| {
+        entry
+            .counterparties
+            .iter()
+            .map(|counterparty| counterparty.to_string())
+            .collect::<Vec<_>>()
+            .join(";")
+    });
+    let counterparty_names = entry
+        .counterparties
+        .iter()
+        .filter_map(|counterparty| account_details.labels.name(counterparty.inner()))
+        .collect::<Vec<_>>();
+    let counterparty_name = (!counterparty_names.is_empty()).then(|| counterparty_names.join(";"));
+    let mut tags = Vec::new();
+    for output_id in transaction_output_ids(transaction) {
+        for tag in account_details.labels.output_tags(&output_id) {
+            if !tags.contains(tag) {
+                tags.push(tag.clone());
+            }
+        }
+    }
+    let tags = (!tags.is_empty()).then(|| tags.join(";"));
+
+    let mut rows = Vec::new();
+    for delta in entry
+        .balance_delta
+        .iter()
+        .filter(|delta| delta.consumed != delta.created)
+    {
+        let direction = if delta.is_decrease() {
+            ExportDirection::Outgoing
+        } else {
+            ExportDirection::Incoming
+        };
+        let amount = if delta.is_decrease() {
+            delta.consumed - delta.created
+        } else {
+            delta.created - delta.consumed
+        };
+        let storage_deposit = match delta.token_id {
+            Some(_) => 0,
+            None => moved_storage_deposit(transaction, direction, account_addresses, rent_structure),
+        };
+
+        rows.push(ExportRow {
+            timestamp: entry.timestamp,
+            milestone_index,
+            transaction_id: entry.transaction_id,
+            direction,
+            kind: entry.kind,
+            counterparty: counterparty.clone(),
+            counterparty_name: counterparty_name.clone(),
+            token_id: delta.token_id,
+            amount: amount.to_string(),
+            storage_deposit: storage_deposit.to_string(),
+            note: entry.note.clone(),
+            tags: tags.clone(),
+        });
+    }
+
+    Ok(rows)
+}
+
+// Returns the ids of the inputs and outputs of a transaction
+fn transaction_output_ids(transaction: &Transaction) -> Vec<OutputId> {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+
+    essence
+        .inputs()
+        .iter()
+        .filter_map(|input| match input {
+            Input::Utxo(input) => Some(*input.output_id()),
+            Input::Treasury(_) => None,
+        })
+        .chain(
+            (0..essence.outputs().len() as u16)
+                .filter_map(|index| OutputId::new(transaction.transaction_id, index).ok()),
+        )
+        .collect()
+}
+
+// The milestone which booked the outputs of the transaction, or spent its inputs, as stored with the outputs of the
+// account
+fn transaction_milestone_index(transaction: &Transaction, account_details: &AccountDetails) -> Option<u32> {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+
+    (0..essence.outputs().len() as u16)
+        .filter_map(|index| OutputId::new(transaction.transaction_id, index).ok())
+        .find_map(|output_id| account_details.outputs.get(&output_id))
+        .map(|output_data| output_data.metadata.milestone_index_booked())
+        .or_else(|| {
+            transaction_output_ids(transaction)
+                .iter()
+                .filter_map(|output_id| account_details.outputs.get(output_id))
+                .find_map(|output_data| output_data.metadata.milestone_index_spent())
+        })
+}
+
+// Sums the storage deposit of the outputs which were sent to someone else or received by the account
+fn moved_storage_deposit(
+    transaction: &Transaction,
+    direction: ExportDirection,
+    account_addresses: &HashSet<Address>,
+    rent_structure: &RentStructure,
+) -> u64 {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+
+    essence
+        .outputs()
+        .iter()
+        .filter(|output| {
+            let owned = output_owner(output).map_or(false, |owner| account_addresses.contains(owner));
+            owned == (direction == ExportDirection::Incoming)
+        })
+        .map(|output| output_storage_deposit(output, rent_structure))
+        .sum()
+}
+
+// The part of the amount of an output which has to be returned, or only covers the storage deposit of its native
+// tokens, NFT, alias or foundry
+fn output_storage_deposit(output: &Output, rent_structure: &RentStructure) -> u64 {
+    if let Some(storage_deposit_return) = output
+        .unlock_conditions()
+        .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+    {
+        return storage_deposit_return.amount();
+    }
+
+    match output {
+        Output::Basic(basic_output) if basic_output.native_tokens().is_empty() => 0,
+        _ => output.amount().min(output.rent_cost(rent_structure)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::{Bech32Address, Ed25519Address},
+            output::{
+                unlock_condition::{AddressUnlockCondition, StorageDepositReturnUnlockCondition},
+                BasicOutputBuilder, NftId, NftOutputBuilder, OutputMetadata,
+            },
+            protocol::protocol_parameters,
+            BlockId,
+        },
+        wallet::account::types::OutputData,
+    };
+
+    fn other_address() -> Address {
+        Address::from(Ed25519Address::new([2; Ed25519Address::LENGTH]))
+    }
+
+    fn basic(amount: u64, address: Address) -> Output {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap()
+    }
+
+    #[test]
+    fn export_rows_of_transactions() {
+        let protocol_parameters = protocol_parameters();
+        let rent_structure = protocol_parameters.rent_structure();
+        let mut account_details = AccountDetails::mock();
+        let account_address = *account_details.public_addresses[0].address.as_ref();
+
+        // Sends 1 Mi to another address, which has to return the storage deposit of 50_000
+        let input_id = OutputId::new(TransactionId::new([9; TransactionId::LENGTH]), 0).unwrap();
+        account_details.outputs.insert(
+            input_id,
+            OutputData::mock(input_id, basic(3_000_000, account_address), account_address),
+        );
+        let sent_output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(other_address()))
+            .add_unlock_condition(
+                StorageDepositReturnUnlockCondition::new(account_address, 50_000, protocol_parameters.token_supply())
+                    .unwrap(),
+            )
+            .finish_output(protocol_parameters.token_supply())
+            .unwrap();
+        let sent = Transaction::mock(&[input_id], vec![sent_output, basic(2_000_000, account_address)]);
+        let remainder_id = OutputId::new(sent.transaction_id, 1).unwrap();
+        let mut remainder = OutputData::mock(remainder_id, basic(2_000_000, account_address), account_address);
+        remainder.metadata = OutputMetadata::new(BlockId::null(), remainder_id, false, None, None, None, 5, 0, 5);
+        account_details.outputs.insert(remainder_id, remainder);
+
+        let account_addresses = account_owned_addresses(&account_details);
+        let rows = transaction_export_rows(
+            &sent,
+            &account_details,
+            &account_addresses,
+            *protocol_parameters.bech32_hrp(),
+            rent_structure,
+        )
+        .unwrap();
+        assert_eq!(
+            rows,
+            [ExportRow {
+                timestamp: 0,
+                milestone_index: Some(5),
+                transaction_id: sent.transaction_id,
+                direction: ExportDirection::Outgoing,
+                kind: HistoryEntryKind::Outgoing,
+                counterparty: Some(Bech32Address::new(*protocol_parameters.bech32_hrp(), other_address()).to_string()),
+                counterparty_name: None,
+                token_id: None,
+                amount: "1000000".to_string(),
+                storage_deposit: "50000".to_string(),
+                note: None,
+                tags: None,
+            }]
+        );
+
+        // Receives an NFT whose amount only covers its storage deposit, the sender is unknown as the input isn't
+        // stored
+        let nft = NftOutputBuilder::new_with_minimum_storage_deposit(*rent_structure, NftId::null())
+            .add_unlock_condition(AddressUnlockCondition::new(account_address))
+            .finish_output(protocol_parameters.token_supply())
+            .unwrap();
+        let mut received = Transaction::mock(
+            &[OutputId::new(TransactionId::new([8; TransactionId::LENGTH]), 0).unwrap()],
+            vec![nft.clone()],
+        );
+        received.incoming = true;
+
+        let rows = transaction_export_rows(
+            &received,
+            &account_details,
+            &account_addresses,
+            *protocol_parameters.bech32_hrp(),
+            rent_structure,
+        )
+        .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].direction, ExportDirection::Incoming);
+        assert_eq!(rows[0].kind, HistoryEntryKind::NftMove);
+        assert_eq!(rows[0].counterparty, None);
+        assert_eq!(rows[0].milestone_index, None);
+        assert_eq!(rows[0].amount, nft.amount().to_string());
+        assert_eq!(rows[0].storage_deposit, nft.amount().to_string());
+    }
+}
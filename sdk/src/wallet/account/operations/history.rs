@@ -0,0 +1,672 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            address::{Address, Bech32Address, Hrp},
+            input::Input,
+            output::{ChainId, Output, OutputId, TokenId, TokenScheme},
+            payload::{
+                transaction::{TransactionEssence, TransactionId},
+                Payload,
+            },
+            BlockId,
+        },
+    },
+    wallet::account::{
+        build_transaction_from_payload_and_inputs,
+        operations::{
+            syncing::outputs::get_inputs_for_transaction_payload,
+            transaction::dry_run::{balance_delta_entry, output_owner},
+        },
+        types::{InclusionState, OutputData, Transaction},
+        Account, AccountDetails, TokenBalanceDelta,
+    },
+};
+
+/// What a transaction did for the account.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HistoryEntryKind {
+    /// Funds were received from someone else.
+    Incoming,
+    /// Funds were sent to someone else.
+    Outgoing,
+    /// Funds were only moved between addresses of the account.
+    SelfTransfer,
+    /// Native tokens or NFTs were minted.
+    Mint,
+    /// Native tokens were burned or melted, or aliases, foundries or NFTs were destroyed.
+    Burn,
+    /// An NFT was sent or received.
+    NftMove,
+    /// The state of an alias changed, without sending funds to someone else.
+    AliasStateChange,
+}
+
+/// An entry of the transaction history of an account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEntry {
+    /// The id of the transaction.
+    pub transaction_id: TransactionId,
+    /// The block the transaction was sent or included in, if known.
+    pub block_id: Option<BlockId>,
+    /// What the transaction did for the account.
+    pub kind: HistoryEntryKind,
+    /// The inclusion state of the transaction.
+    pub inclusion_state: InclusionState,
+    /// Milliseconds since the unix epoch when the transaction was created or, if it's incoming, confirmed.
+    #[serde(with = "crate::utils::serde::string")]
+    pub timestamp: u128,
+    /// The senders of a received transaction or the recipients of a sent transaction.
+    pub counterparties: Vec<Bech32Address>,
+    /// Balance change of the account per token, the base coin comes first.
+    pub balance_delta: Vec<TokenBalanceDelta>,
+    /// The note of the transaction.
+    pub note: Option<String>,
+    /// Whether inputs of the transaction are pruned, so the counterparties and the balance delta can be incomplete.
+    pub incomplete: bool,
+}
+
+/// Options to filter and paginate the history of an account.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryOptions {
+    /// Only return entries of these kinds.
+    pub kinds: Option<HashSet<HistoryEntryKind>>,
+    /// Only return entries with this counterparty.
+    pub counterparty: Option<Bech32Address>,
+    /// Only return entries changing the balance of this native token.
+    pub token_id: Option<TokenId>,
+    /// Only return entries with a timestamp at or after this one, in milliseconds since the unix epoch.
+    pub lower_bound_timestamp: Option<u128>,
+    /// Only return entries with a timestamp at or before this one, in milliseconds since the unix epoch.
+    pub upper_bound_timestamp: Option<u128>,
+    /// Amount of matching entries to skip, the newest entries come first.
+    #[serde(default)]
+    pub offset: usize,
+    /// Maximum amount of returned entries.
+    pub limit: Option<usize>,
+    /// Request pruned incoming transactions and inputs again before building the history. The requests prefer the
+    /// configured permanodes, which keep the pruned data.
+    #[serde(default)]
+    pub fetch_pruned: bool,
+}
+
+impl HistoryOptions {
+    fn matches(&self, entry: &HistoryEntry) -> bool {
+        self.kinds.as_ref().map_or(true, |kinds| kinds.contains(&entry.kind))
+            && self
+                .counterparty
+                .as_ref()
+                .map_or(true, |counterparty| entry.counterparties.contains(counterparty))
+            && self.token_id.map_or(true, |token_id| {
+                entry
+                    .balance_delta
+                    .iter()
+                    .any(|delta| delta.token_id == Some(token_id) && delta.consumed != delta.created)
+            })
+            && self
+                .lower_bound_timestamp
+                .map_or(true, |timestamp| entry.timestamp >= timestamp)
+            && self
+                .upper_bound_timestamp
+                .map_or(true, |timestamp| entry.timestamp <= timestamp)
+    }
+}
+
+/// A page of the history of an account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryPage {
+    /// The entries of the page, newest first.
+    pub entries: Vec<HistoryEntry>,
+    /// The amount of entries matching the filters, across all pages.
+    pub total: usize,
+}
+
+impl Account {
+    /// Returns the history of the sent and received transactions of the account. Received transactions are only known
+    /// if they were synced with [`SyncOptions::sync_incoming_transactions`](crate::wallet::account::SyncOptions).
+    pub async fn history(&self, options: HistoryOptions) -> crate::wallet::Result<HistoryPage> {
+        log::debug!("[history]");
+        if options.fetch_pruned {
+            self.request_pruned_transaction_data().await?;
+        }
+
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let account_details = self.details().await;
+        let account_addresses = account_owned_addresses(&account_details);
+
+        let mut entries = account_details
+            .transactions
+            .values()
+            .chain(account_details.incoming_transactions.values())
+            .map(|transaction| history_entry(transaction, &account_details.outputs, &account_addresses, bech32_hrp))
+            .collect::<crate::wallet::Result<Vec<_>>>()?;
+        drop(account_details);
+
+        entries.retain(|entry| options.matches(entry));
+        // Newest first, the transaction id keeps the order of entries with the same timestamp stable across pages
+        entries.sort_by(|a, b| {
+            b.timestamp
+                .cmp(&a.timestamp)
+                .then_with(|| a.transaction_id.cmp(&b.transaction_id))
+        });
+
+        let total = entries.len();
+        let entries = entries
+            .into_iter()
+            .skip(options.offset)
+            .take(options.limit.unwrap_or(usize::MAX))
+            .collect();
+
+        Ok(HistoryPage { entries, total })
+    }
+
+    /// Requests the incoming transactions which weren't available when syncing and the missing inputs of stored
+    /// transactions again.
+    async fn request_pruned_transaction_data(&self) -> crate::wallet::Result<()> {
+        log::debug!("[history] request_pruned_transaction_data");
+        let (inaccessible_transaction_ids, incomplete_transactions) = {
+            let account_details = self.details().await;
+            (
+                account_details
+                    .inaccessible_incoming_transactions
+                    .iter()
+                    .copied()
+                    .collect::<Vec<_>>(),
+                account_details
+                    .transactions
+                    .values()
+                    .chain(account_details.incoming_transactions.values())
+                    .filter(|transaction| {
+                        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                        transaction.inputs.len() < essence.inputs().len()
+                    })
+                    .map(|transaction| (transaction.transaction_id, transaction.payload.clone()))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        let mut found_transactions = Vec::new();
+        for transaction_id in inaccessible_transaction_ids {
+            match self.client().get_included_block(&transaction_id).await {
+                Ok(block) => {
+                    if let Some(Payload::Transaction(transaction_payload)) = block.payload() {
+                        let inputs = get_inputs_for_transaction_payload(self.client(), transaction_payload)
+                            .await?
+                            .into_iter()
+                            .map(OutputWithMetadataResponse::from)
+                            .collect();
+                        found_transactions.push(build_transaction_from_payload_and_inputs(
+                            transaction_id,
+                            *transaction_payload.clone(),
+                            inputs,
+                        )?);
+                    }
+                }
+                Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => {
+                    log::debug!("[history] transaction {transaction_id} is still not available");
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        let mut found_inputs = Vec::new();
+        for (transaction_id, transaction_payload) in incomplete_transactions {
+            let inputs = get_inputs_for_transaction_payload(self.client(), &transaction_payload).await?;
+            found_inputs.push((
+                transaction_id,
+                inputs
+                    .into_iter()
+                    .map(OutputWithMetadataResponse::from)
+                    .collect::<Vec<_>>(),
+            ));
+        }
+
+        let mut account_details = self.details_mut().await;
+        for transaction in found_transactions {
+            log::debug!("[history] found pruned transaction {}", transaction.transaction_id);
+            account_details
+                .inaccessible_incoming_transactions
+                .remove(&transaction.transaction_id);
+            account_details
+                .incoming_transactions
+                .insert(transaction.transaction_id, transaction);
+        }
+        {
+            let account_details = &mut *account_details;
+            for (transaction_id, inputs) in found_inputs {
+                if let Some(transaction) = account_details
+                    .transactions
+                    .get_mut(&transaction_id)
+                    .or(account_details.incoming_transactions.get_mut(&transaction_id))
+                {
+                    if inputs.len() > transaction.inputs.len() {
+                        transaction.inputs = inputs;
+                    }
+                }
+            }
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+}
+
+// Returns the addresses of the account, outputs owned by aliases and NFTs of the account belong to the account too
+pub(crate) fn account_owned_addresses(account_details: &AccountDetails) -> HashSet<Address> {
+    let mut account_addresses = account_details
+        .public_addresses
+        .iter()
+        .chain(account_details.internal_addresses.iter())
+        .map(|address| address.address.inner)
+        .collect::<HashSet<Address>>();
+    for output_data in account_details.outputs.values() {
+        match output_data
+            .output
+            .chain_id()
+            .map(|chain_id| chain_id.or_from_output_id(&output_data.output_id))
+        {
+            Some(ChainId::Alias(alias_id)) => account_addresses.insert(Address::from(alias_id)),
+            Some(ChainId::Nft(nft_id)) => account_addresses.insert(Address::from(nft_id)),
+            _ => false,
+        };
+    }
+
+    account_addresses
+}
+
+// Builds the history entry of a transaction, inputs which aren't stored with the transaction are taken from the
+// outputs of the account
+pub(crate) fn history_entry(
+    transaction: &Transaction,
+    account_outputs: &HashMap<OutputId, OutputData>,
+    account_addresses: &HashSet<Address>,
+    bech32_hrp: Hrp,
+) -> crate::wallet::Result<HistoryEntry> {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+
+    let mut stored_inputs = HashMap::new();
+    for input in &transaction.inputs {
+        stored_inputs.insert(
+            input.metadata.output_id()?,
+            Output::try_from_dto_unverified(&input.output)?,
+        );
+    }
+    let mut inputs = Vec::new();
+    for input in essence.inputs() {
+        if let Input::Utxo(input) = input {
+            let output_id = *input.output_id();
+            if let Some(output) = stored_inputs.remove(&output_id).or_else(|| {
+                account_outputs
+                    .get(&output_id)
+                    .map(|output_data| output_data.output.clone())
+            }) {
+                inputs.push((output_id, output));
+            }
+        }
+    }
+    let mut outputs = Vec::new();
+    for (index, output) in essence.outputs().iter().enumerate() {
+        outputs.push((OutputId::new(transaction.transaction_id, index as u16)?, output));
+    }
+
+    let owned = |output: &Output| output_owner(output).map_or(false, |owner| account_addresses.contains(owner));
+    // The inputs of sent transactions can be pruned too
+    let sent = !transaction.incoming || inputs.iter().any(|(_, output)| owned(output));
+
+    let mut balance_delta = vec![TokenBalanceDelta {
+        token_id: None,
+        consumed: U256::zero(),
+        created: U256::zero(),
+    }];
+    for (_, output) in inputs.iter().filter(|(_, output)| owned(output)) {
+        add_to_balance_delta(&mut balance_delta, output, true);
+    }
+    for (_, output) in outputs.iter().filter(|(_, output)| owned(*output)) {
+        add_to_balance_delta(&mut balance_delta, output, false);
+    }
+
+    // Recipients of sent transactions, senders of received ones
+    let mut counterparties = Vec::new();
+    let counterparty_outputs = if sent {
+        outputs.iter().map(|(_, output)| *output).collect::<Vec<_>>()
+    } else {
+        inputs.iter().map(|(_, output)| output).collect::<Vec<_>>()
+    };
+    for output in counterparty_outputs {
+        if let Some(owner) = output_owner(output).filter(|owner| !account_addresses.contains(owner)) {
+            let counterparty = Bech32Address::new(bech32_hrp, *owner);
+            if !counterparties.contains(&counterparty) {
+                counterparties.push(counterparty);
+            }
+        }
+    }
+
+    let kind = if sent {
+        sent_transaction_kind(&inputs, &outputs, owned)
+    } else if outputs.iter().any(|(_, output)| output.is_nft() && owned(*output)) {
+        HistoryEntryKind::NftMove
+    } else {
+        HistoryEntryKind::Incoming
+    };
+
+    Ok(HistoryEntry {
+        transaction_id: transaction.transaction_id,
+        block_id: transaction.block_id,
+        kind,
+        inclusion_state: transaction.inclusion_state,
+        timestamp: transaction.timestamp,
+        counterparties,
+        balance_delta,
+        note: transaction.note.clone(),
+        incomplete: inputs.len() != essence.inputs().len(),
+    })
+}
+
+fn add_to_balance_delta(balance_delta: &mut Vec<TokenBalanceDelta>, output: &Output, consumed: bool) {
+    let mut add = |token_id, amount| {
+        let delta = balance_delta_entry(balance_delta, token_id);
+        if consumed {
+            delta.consumed += amount;
+        } else {
+            delta.created += amount;
+        }
+    };
+
+    add(None, U256::from(output.amount()));
+    for native_token in output
+        .native_tokens()
+        .into_iter()
+        .flat_map(|native_tokens| native_tokens.iter())
+    {
+        add(Some(*native_token.token_id()), native_token.amount());
+    }
+}
+
+// Minting and burning are checked first, because they also transition aliases and foundries
+fn sent_transaction_kind(
+    inputs: &[(OutputId, Output)],
+    outputs: &[(OutputId, &Output)],
+    owned: impl Fn(&Output) -> bool,
+) -> HistoryEntryKind {
+    let input_chains = inputs
+        .iter()
+        .filter_map(|(output_id, output)| {
+            output
+                .chain_id()
+                .map(|chain_id| (chain_id.or_from_output_id(output_id), output))
+        })
+        .collect::<HashMap<ChainId, &Output>>();
+    let output_chains = outputs
+        .iter()
+        .filter_map(|(output_id, output)| {
+            output
+                .chain_id()
+                .map(|chain_id| (chain_id.or_from_output_id(output_id), *output))
+        })
+        .collect::<HashMap<ChainId, &Output>>();
+
+    // New NFTs have a null id in the output
+    let mut minted = outputs
+        .iter()
+        .any(|(_, output)| output.is_nft() && output.chain_id().map_or(false, |chain_id| chain_id.is_null()));
+    let mut melted = false;
+    for (chain_id, output) in &output_chains {
+        if let Output::Foundry(foundry) = output {
+            let TokenScheme::Simple(token_scheme) = foundry.token_scheme();
+            let (input_minted_tokens, input_melted_tokens) = match input_chains.get(chain_id) {
+                Some(Output::Foundry(input)) => {
+                    let TokenScheme::Simple(input_token_scheme) = input.token_scheme();
+                    (input_token_scheme.minted_tokens(), input_token_scheme.melted_tokens())
+                }
+                _ => (U256::zero(), U256::zero()),
+            };
+            minted |= token_scheme.minted_tokens() > input_minted_tokens;
+            melted |= token_scheme.melted_tokens() > input_melted_tokens;
+        }
+    }
+    if minted {
+        return HistoryEntryKind::Mint;
+    }
+
+    let mut native_tokens_delta = HashMap::<TokenId, (U256, U256)>::new();
+    for (_, output) in inputs {
+        for native_token in output.native_tokens().into_iter().flat_map(|n| n.iter()) {
+            native_tokens_delta.entry(*native_token.token_id()).or_default().0 += native_token.amount();
+        }
+    }
+    for (_, output) in outputs {
+        for native_token in output.native_tokens().into_iter().flat_map(|n| n.iter()) {
+            native_tokens_delta.entry(*native_token.token_id()).or_default().1 += native_token.amount();
+        }
+    }
+    let destroyed_chains = input_chains
+        .keys()
+        .any(|chain_id| !output_chains.contains_key(chain_id));
+    if melted || destroyed_chains || native_tokens_delta.values().any(|(input, output)| input > output) {
+        return HistoryEntryKind::Burn;
+    }
+
+    let nft_moved = output_chains.iter().any(|(chain_id, output)| {
+        output.is_nft()
+            && input_chains
+                .get(chain_id)
+                .map_or(false, |input| owned(*input) != owned(*output))
+    });
+    if nft_moved {
+        return HistoryEntryKind::NftMove;
+    }
+
+    if outputs.iter().any(|(_, output)| !owned(*output)) {
+        return HistoryEntryKind::Outgoing;
+    }
+
+    let alias_state_changed =
+        output_chains
+            .iter()
+            .any(|(chain_id, output)| match (output, input_chains.get(chain_id)) {
+                (Output::Alias(alias), Some(Output::Alias(input))) => alias.state_index() != input.state_index(),
+                _ => false,
+            });
+    if alias_state_changed {
+        HistoryEntryKind::AliasStateChange
+    } else {
+        HistoryEntryKind::SelfTransfer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::Ed25519Address,
+        output::{
+            dto::{OutputDto, OutputMetadataDto},
+            unlock_condition::{
+                AddressUnlockCondition, GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition,
+            },
+            AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, OutputMetadata,
+        },
+        protocol::protocol_parameters,
+    };
+
+    fn account_address() -> Address {
+        Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]))
+    }
+
+    fn other_address() -> Address {
+        Address::from(Ed25519Address::new([2; Ed25519Address::LENGTH]))
+    }
+
+    fn basic(amount: u64, address: Address) -> Output {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap()
+    }
+
+    fn nft(nft_id: NftId, address: Address) -> Output {
+        NftOutputBuilder::new_with_amount(1_000_000, nft_id)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap()
+    }
+
+    fn alias(state_index: u32) -> Output {
+        AliasOutputBuilder::new_with_amount(1_000_000, AliasId::new([3; AliasId::LENGTH]))
+            .with_state_index(state_index)
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(account_address()))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(account_address()))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap()
+    }
+
+    // Builds the history entry of a transaction whose inputs are stored with it
+    fn entry(inputs: Vec<Output>, outputs: Vec<Output>, incoming: bool) -> HistoryEntry {
+        let protocol_parameters = protocol_parameters();
+        let inputs = inputs
+            .into_iter()
+            .enumerate()
+            .map(|(index, output)| {
+                (
+                    OutputId::new(TransactionId::new([9; TransactionId::LENGTH]), index as u16).unwrap(),
+                    output,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut transaction = Transaction::mock(
+            &inputs.iter().map(|(output_id, _)| *output_id).collect::<Vec<_>>(),
+            outputs,
+        );
+        transaction.inclusion_state = InclusionState::Confirmed;
+        transaction.incoming = incoming;
+        transaction.inputs = inputs
+            .iter()
+            .map(|(output_id, output)| OutputWithMetadataResponse {
+                metadata: OutputMetadataDto::from(&OutputMetadata::new(
+                    BlockId::null(),
+                    *output_id,
+                    true,
+                    None,
+                    None,
+                    None,
+                    0,
+                    0,
+                    0,
+                )),
+                output: OutputDto::from(output),
+            })
+            .collect();
+
+        history_entry(
+            &transaction,
+            &HashMap::new(),
+            &HashSet::from([account_address()]),
+            *protocol_parameters.bech32_hrp(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn history_entry_kinds() {
+        let nft_id = NftId::new([4; NftId::LENGTH]);
+
+        let incoming = entry(
+            vec![basic(2_000_000, other_address())],
+            vec![basic(1_000_000, account_address()), basic(1_000_000, other_address())],
+            true,
+        );
+        assert_eq!(incoming.kind, HistoryEntryKind::Incoming);
+        assert_eq!(
+            incoming.counterparties,
+            [Bech32Address::new(*protocol_parameters().bech32_hrp(), other_address())]
+        );
+        assert_eq!(
+            incoming.balance_delta,
+            [TokenBalanceDelta {
+                token_id: None,
+                consumed: U256::zero(),
+                created: U256::from(1_000_000),
+            }]
+        );
+        assert!(!incoming.incomplete);
+
+        let outgoing = entry(
+            vec![basic(2_000_000, account_address())],
+            vec![basic(1_000_000, other_address()), basic(1_000_000, account_address())],
+            false,
+        );
+        assert_eq!(outgoing.kind, HistoryEntryKind::Outgoing);
+        assert_eq!(
+            outgoing.counterparties,
+            [Bech32Address::new(*protocol_parameters().bech32_hrp(), other_address())]
+        );
+        assert_eq!(
+            outgoing.balance_delta,
+            [TokenBalanceDelta {
+                token_id: None,
+                consumed: U256::from(2_000_000),
+                created: U256::from(1_000_000),
+            }]
+        );
+
+        let self_transfer = entry(
+            vec![basic(1_000_000, account_address()), basic(1_000_000, account_address())],
+            vec![basic(2_000_000, account_address())],
+            false,
+        );
+        assert_eq!(self_transfer.kind, HistoryEntryKind::SelfTransfer);
+        assert!(self_transfer.counterparties.is_empty());
+
+        let mint = entry(
+            vec![basic(2_000_000, account_address())],
+            vec![
+                nft(NftId::null(), account_address()),
+                basic(1_000_000, account_address()),
+            ],
+            false,
+        );
+        assert_eq!(mint.kind, HistoryEntryKind::Mint);
+
+        let burn = entry(
+            vec![nft(nft_id, account_address())],
+            vec![basic(1_000_000, account_address())],
+            false,
+        );
+        assert_eq!(burn.kind, HistoryEntryKind::Burn);
+
+        let sent_nft = entry(
+            vec![nft(nft_id, account_address())],
+            vec![nft(nft_id, other_address())],
+            false,
+        );
+        assert_eq!(sent_nft.kind, HistoryEntryKind::NftMove);
+
+        let received_nft = entry(
+            vec![nft(nft_id, other_address())],
+            vec![nft(nft_id, account_address())],
+            true,
+        );
+        assert_eq!(received_nft.kind, HistoryEntryKind::NftMove);
+
+        let alias_state_change = entry(vec![alias(0)], vec![alias(1)], false);
+        assert_eq!(alias_state_change.kind, HistoryEntryKind::AliasStateChange);
+        assert_eq!(
+            entry(vec![alias(0)], vec![alias(0)], false).kind,
+            HistoryEntryKind::SelfTransfer
+        );
+    }
+}
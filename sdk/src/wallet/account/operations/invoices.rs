@@ -0,0 +1,499 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+};
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::Bech32Address,
+        output::{NativeToken, NativeTokensBuilder, Output, OutputId, TokenId},
+        payload::transaction::TransactionId,
+    },
+    utils::unix_timestamp_now,
+    wallet::{
+        account::{
+            types::{OutputData, Transaction},
+            Account, PaymentUri,
+        },
+        Error,
+    },
+};
+
+/// Parameters to create an [`Invoice`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceParams {
+    /// The expected amount of the base coin.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The expected native tokens.
+    #[serde(default)]
+    pub native_tokens: Vec<NativeToken>,
+    /// A reference the payment has to contain in a tag or metadata feature. Invoices with a reference are paid to the
+    /// first address of the account, invoices without one to a freshly generated address.
+    pub reference: Option<String>,
+    /// Unix timestamp in seconds after which the invoice expires, if it's not paid.
+    pub expiration: Option<u32>,
+    /// A description for the payer.
+    pub description: Option<String>,
+}
+
+/// The payment state of an [`Invoice`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InvoiceStatus {
+    /// Nothing was received yet.
+    Pending,
+    /// Less than the expected amount or native tokens was received.
+    Underpaid,
+    /// Exactly the expected amount and native tokens were received.
+    Paid,
+    /// More than the expected amount or native tokens was received.
+    Overpaid,
+    /// Nothing was received before the expiration.
+    Expired,
+}
+
+/// A payment request of the account, outputs paying it are matched while syncing.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Invoice {
+    /// The identifier of the invoice, unique within the account.
+    pub id: u32,
+    /// The address of the account the invoice has to be paid to.
+    pub address: Bech32Address,
+    /// The expected amount of the base coin.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The expected native tokens.
+    pub native_tokens: Vec<NativeToken>,
+    /// The reference the payment has to contain in a tag or metadata feature.
+    pub reference: Option<String>,
+    /// Unix timestamp in seconds after which the invoice expires, if it's not paid.
+    pub expiration: Option<u32>,
+    /// A description for the payer.
+    pub description: Option<String>,
+    /// Milliseconds since the unix epoch when the invoice was created.
+    #[serde(with = "crate::utils::serde::string")]
+    pub timestamp: u128,
+    /// The payment state.
+    pub status: InvoiceStatus,
+    /// The outputs that paid the invoice.
+    pub outputs: Vec<OutputId>,
+    /// The received amount of the base coin, without storage deposits that have to be returned.
+    #[serde(with = "crate::utils::serde::string")]
+    pub received_amount: u64,
+    /// The received native tokens.
+    pub received_native_tokens: Vec<NativeToken>,
+}
+
+impl Invoice {
+    /// Returns whether the invoice is paid, with or without overpayment.
+    pub fn is_paid(&self) -> bool {
+        matches!(self.status, InvoiceStatus::Paid | InvoiceStatus::Overpaid)
+    }
+
+    /// Encodes the invoice as a payment URI, to be shown as a QR code, e.g.
+    /// `smr:rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy?amount=1000000&tag=invoice-123`.
+    pub fn to_uri(&self) -> crate::wallet::Result<String> {
+        PaymentUri::from(self).to_uri()
+    }
+
+    // Adds a payment and updates the status, the invoice is left unchanged if the native tokens can't be added
+    fn add_payment(&mut self, output_id: OutputId, output: &Output) -> crate::wallet::Result<()> {
+        let mut received_native_tokens = NativeTokensBuilder::new();
+        for native_token in self.received_native_tokens.iter().chain(
+            output
+                .native_tokens()
+                .into_iter()
+                .flat_map(|native_tokens| native_tokens.iter()),
+        ) {
+            received_native_tokens.add_native_token(*native_token)?;
+        }
+        self.received_native_tokens = received_native_tokens.finish_vec()?;
+
+        let storage_deposit_return = output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+            .map_or(0, |storage_deposit_return| storage_deposit_return.amount());
+        self.received_amount += output.amount().saturating_sub(storage_deposit_return);
+        self.outputs.push(output_id);
+        self.status = self.payment_status();
+
+        Ok(())
+    }
+
+    // Compares what was received with what is expected
+    fn payment_status(&self) -> InvoiceStatus {
+        let mut received = self
+            .received_native_tokens
+            .iter()
+            .map(|native_token| (*native_token.token_id(), native_token.amount()))
+            .collect::<BTreeMap<TokenId, U256>>();
+        let mut orderings = vec![self.received_amount.cmp(&self.amount)];
+        for native_token in &self.native_tokens {
+            let received_amount = received.remove(native_token.token_id()).unwrap_or_default();
+            orderings.push(received_amount.cmp(&native_token.amount()));
+        }
+        // Native tokens that weren't expected
+        if !received.is_empty() {
+            orderings.push(Ordering::Greater);
+        }
+
+        if orderings.contains(&Ordering::Less) {
+            InvoiceStatus::Underpaid
+        } else if orderings.contains(&Ordering::Greater) {
+            InvoiceStatus::Overpaid
+        } else {
+            InvoiceStatus::Paid
+        }
+    }
+}
+
+impl From<&Invoice> for PaymentUri {
+    fn from(invoice: &Invoice) -> Self {
+        Self {
+            address: invoice.address,
+            amount: invoice.amount,
+            native_tokens: invoice.native_tokens.clone(),
+            nft_id: None,
+            tag: invoice.reference.clone(),
+            metadata: None,
+            expiration: invoice.expiration,
+            message: invoice.description.clone(),
+        }
+    }
+}
+
+impl Account {
+    /// Creates an invoice, outputs paying it are matched while syncing. Without a reference, the invoice gets a freshly
+    /// generated address of the account.
+    /// ```ignore
+    /// let invoice = account
+    ///     .create_invoice(InvoiceParams {
+    ///         amount: 1_000_000,
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// println!("{}", invoice.to_uri()?);
+    /// ```
+    pub async fn create_invoice(&self, params: InvoiceParams) -> crate::wallet::Result<Invoice> {
+        log::debug!("[INVOICE] create_invoice {params:?}");
+        if params.amount == 0 && params.native_tokens.is_empty() {
+            return Err(Error::Invoice(
+                "an invoice needs an amount or native tokens".to_string(),
+            ));
+        }
+        let timestamp = unix_timestamp_now();
+        if params
+            .expiration
+            .map_or(false, |expiration| u64::from(expiration) <= timestamp.as_secs())
+        {
+            return Err(Error::Invoice("the expiration is in the past".to_string()));
+        }
+        // Sums up duplicated native tokens
+        let mut native_tokens = NativeTokensBuilder::new();
+        for native_token in params.native_tokens {
+            native_tokens.add_native_token(native_token)?;
+        }
+        let native_tokens = native_tokens.finish_vec()?;
+
+        let address = match &params.reference {
+            Some(reference) => {
+                if reference.is_empty() {
+                    return Err(Error::Invoice("the reference can't be empty".to_string()));
+                }
+                if self
+                    .details()
+                    .await
+                    .invoices
+                    .iter()
+                    .any(|invoice| invoice.reference.as_ref() == Some(reference) && !invoice.is_paid())
+                {
+                    return Err(Error::Invoice(format!(
+                        "an unpaid invoice with the reference {reference} already exists"
+                    )));
+                }
+                // PANIC: the first address is generated when the account is created.
+                *self.addresses().await?[0].address()
+            }
+            None => *self.generate_ed25519_addresses(1, None).await?[0].address(),
+        };
+
+        let mut account_details = self.details_mut().await;
+        // Identifiers of removed invoices aren't reused, invoices stored before the counter existed are skipped
+        let id = account_details
+            .invoices
+            .iter()
+            .map(|invoice| invoice.id + 1)
+            .chain([account_details.next_invoice_id])
+            .max()
+            .unwrap_or_default();
+        account_details.next_invoice_id = id + 1;
+
+        let invoice = Invoice {
+            id,
+            address,
+            amount: params.amount,
+            native_tokens,
+            reference: params.reference,
+            expiration: params.expiration,
+            description: params.description,
+            timestamp: timestamp.as_millis(),
+            status: InvoiceStatus::Pending,
+            outputs: Vec::new(),
+            received_amount: 0,
+            received_native_tokens: Vec::new(),
+        };
+        account_details.invoices.push(invoice.clone());
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(invoice)
+    }
+
+    /// Returns the invoices of the account.
+    pub async fn invoices(&self) -> Vec<Invoice> {
+        self.details().await.invoices.clone()
+    }
+
+    /// Returns an invoice of the account.
+    pub async fn get_invoice(&self, invoice_id: u32) -> Option<Invoice> {
+        self.details()
+            .await
+            .invoices
+            .iter()
+            .find(|invoice| invoice.id == invoice_id)
+            .cloned()
+    }
+
+    /// Removes an invoice, outputs paying it aren't matched anymore.
+    pub async fn remove_invoice(&self, invoice_id: u32) -> crate::wallet::Result<()> {
+        log::debug!("[INVOICE] remove_invoice {invoice_id}");
+        let mut account_details = self.details_mut().await;
+        let count = account_details.invoices.len();
+
+        account_details.invoices.retain(|invoice| invoice.id != invoice_id);
+
+        if account_details.invoices.len() == count {
+            return Err(Error::Invoice(format!("invoice {invoice_id} not found")));
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+}
+
+/// Adds a new output to the first unpaid invoice it pays and returns the id of the invoice. Outputs created by
+/// transactions of the account, like remainders, don't pay invoices, neither do outputs which can still return to
+/// the sender or aren't spendable yet, or outputs booked after the expiration of an invoice.
+pub(crate) fn match_invoice(
+    invoices: &mut [Invoice],
+    transactions: &HashMap<TransactionId, Transaction>,
+    output_data: &OutputData,
+) -> Option<u32> {
+    if transactions.contains_key(output_data.output_id.transaction_id()) {
+        return None;
+    }
+    let output = &output_data.output;
+    let unlock_conditions = output.unlock_conditions()?;
+    if unlock_conditions.expiration().is_some() || unlock_conditions.timelock().is_some() {
+        return None;
+    }
+    let address = unlock_conditions.address()?.address();
+    let features = output.features();
+    let contains_reference = |reference: &str| {
+        features.map_or(false, |features| {
+            features.tag().map_or(false, |tag| tag.tag() == reference.as_bytes())
+                || features
+                    .metadata()
+                    .map_or(false, |metadata| metadata.data() == reference.as_bytes())
+        })
+    };
+    let position = invoices.iter().position(|invoice| {
+        !invoice.is_paid()
+            && invoice.address.inner() == address
+            && !invoice.outputs.contains(&output_data.output_id)
+            && invoice.expiration.map_or(true, |expiration| {
+                output_data.metadata.milestone_timestamp_booked() <= expiration
+            })
+            && invoice.reference.as_deref().map_or(true, contains_reference)
+    })?;
+
+    let invoice = &mut invoices[position];
+    match invoice.add_payment(output_data.output_id, output) {
+        Ok(()) => {
+            log::debug!("[INVOICE] output {} pays invoice {}", output_data.output_id, invoice.id);
+            Some(invoice.id)
+        }
+        Err(err) => {
+            log::debug!(
+                "[INVOICE] output {} can't be added to invoice {}: {err}",
+                output_data.output_id,
+                invoice.id
+            );
+            None
+        }
+    }
+}
+
+/// Marks pending invoices whose expiration passed as expired.
+pub(crate) fn expire_invoices(invoices: &mut [Invoice]) {
+    let now = unix_timestamp_now().as_secs();
+
+    for invoice in invoices {
+        if invoice.status == InvoiceStatus::Pending
+            && invoice
+                .expiration
+                .map_or(false, |expiration| u64::from(expiration) <= now)
+        {
+            invoice.status = InvoiceStatus::Expired;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Address, Ed25519Address},
+        output::{
+            unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition, TimelockUnlockCondition},
+            BasicOutputBuilder, OutputMetadata, UnlockCondition,
+        },
+        protocol::protocol_parameters,
+        BlockId,
+    };
+
+    fn address() -> Address {
+        Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]))
+    }
+
+    fn output_data(transaction_id: TransactionId, amount: u64) -> OutputData {
+        let output = BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address()))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap();
+
+        OutputData::mock(OutputId::new(transaction_id, 0).unwrap(), output, address())
+    }
+
+    // A transaction sent by the account, creating a remainder output
+    fn own_transaction() -> Transaction {
+        Transaction::mock(
+            &[OutputId::new(TransactionId::new([9; TransactionId::LENGTH]), 0).unwrap()],
+            vec![output_data(TransactionId::null(), 1_000_000).output],
+        )
+    }
+
+    fn invoice() -> Invoice {
+        Invoice {
+            id: 0,
+            address: Bech32Address::new(*protocol_parameters().bech32_hrp(), address()),
+            amount: 1_000_000,
+            native_tokens: Vec::new(),
+            reference: None,
+            expiration: None,
+            description: None,
+            timestamp: 0,
+            status: InvoiceStatus::Pending,
+            outputs: Vec::new(),
+            received_amount: 0,
+            received_native_tokens: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn invoice_matching() {
+        let mut invoices = vec![invoice()];
+        let own_transaction = own_transaction();
+        let own_transaction_id = own_transaction.transaction_id;
+        let transactions = HashMap::from([(own_transaction_id, own_transaction)]);
+
+        // Remainders of the account don't pay invoices
+        let remainder = output_data(own_transaction_id, 1_000_000);
+        assert_eq!(match_invoice(&mut invoices, &transactions, &remainder), None);
+        assert_eq!(invoices[0].status, InvoiceStatus::Pending);
+
+        let payment = output_data(TransactionId::new([1; TransactionId::LENGTH]), 1_000_000);
+        assert_eq!(match_invoice(&mut invoices, &transactions, &payment), Some(0));
+        assert_eq!(invoices[0].status, InvoiceStatus::Paid);
+        assert_eq!(invoices[0].outputs, [payment.output_id]);
+
+        // Later outputs to the same address aren't attached to the paid invoice
+        let later = output_data(TransactionId::new([2; TransactionId::LENGTH]), 1_000_000);
+        assert_eq!(match_invoice(&mut invoices, &transactions, &later), None);
+        assert_eq!(invoices[0].status, InvoiceStatus::Paid);
+        assert_eq!(invoices[0].received_amount, 1_000_000);
+    }
+
+    #[test]
+    fn unmatched_outputs() {
+        let mut invoices = vec![Invoice {
+            expiration: Some(1_000),
+            ..invoice()
+        }];
+        let transactions = HashMap::new();
+
+        // Outputs which can return to the sender or aren't spendable yet don't pay invoices
+        for unlock_condition in [
+            UnlockCondition::from(
+                ExpirationUnlockCondition::new(Ed25519Address::new([2; Ed25519Address::LENGTH]), 2_000).unwrap(),
+            ),
+            UnlockCondition::from(TimelockUnlockCondition::new(2_000).unwrap()),
+        ] {
+            let output = BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address()))
+                .add_unlock_condition(unlock_condition)
+                .finish_output(protocol_parameters().token_supply())
+                .unwrap();
+            let output_data = OutputData::mock(
+                OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), 0).unwrap(),
+                output,
+                address(),
+            );
+            assert_eq!(match_invoice(&mut invoices, &transactions, &output_data), None);
+        }
+
+        // Outputs booked after the expiration don't pay the invoice
+        let mut late_payment = output_data(TransactionId::new([2; TransactionId::LENGTH]), 1_000_000);
+        late_payment.metadata = OutputMetadata::new(
+            BlockId::null(),
+            late_payment.output_id,
+            false,
+            None,
+            None,
+            None,
+            10,
+            1_001,
+            10,
+        );
+        assert_eq!(match_invoice(&mut invoices, &transactions, &late_payment), None);
+        assert_eq!(invoices[0].status, InvoiceStatus::Pending);
+        assert!(invoices[0].outputs.is_empty());
+
+        // Native tokens which can't be added leave the output unmatched and the invoice unchanged
+        let token_id = TokenId::new([3; TokenId::LENGTH]);
+        invoices[0].received_native_tokens = vec![NativeToken::new(token_id, U256::MAX).unwrap()];
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address()))
+            .add_native_token(NativeToken::new(token_id, U256::from(1)).unwrap())
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap();
+        let overflowing_payment = OutputData::mock(
+            OutputId::new(TransactionId::new([3; TransactionId::LENGTH]), 0).unwrap(),
+            output,
+            address(),
+        );
+        assert_eq!(match_invoice(&mut invoices, &transactions, &overflowing_payment), None);
+        assert_eq!(invoices[0].received_amount, 0);
+        assert!(invoices[0].outputs.is_empty());
+    }
+}
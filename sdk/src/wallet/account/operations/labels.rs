@@ -0,0 +1,266 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::{Address, Bech32Address},
+        output::OutputId,
+        ConvertTo,
+    },
+    wallet::{account::Account, Error},
+};
+
+/// An entry of the address book, a named address of someone else.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contact {
+    /// Bech32 encoded address of the contact
+    pub address: Bech32Address,
+    /// Name of the contact
+    pub name: String,
+}
+
+/// A label of an address of the account, e.g. "invoice #123".
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressLabel {
+    /// Bech32 encoded address of the account
+    pub address: Bech32Address,
+    /// The label
+    pub label: String,
+}
+
+/// The address book and the labels of an account.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Labels {
+    /// Named addresses of others
+    #[serde(default)]
+    pub contacts: Vec<Contact>,
+    /// Labels of addresses of the account
+    #[serde(default)]
+    pub address_labels: Vec<AddressLabel>,
+    /// Tags of outputs of the account
+    #[serde(default)]
+    pub output_tags: HashMap<OutputId, Vec<String>>,
+}
+
+impl Labels {
+    /// Returns the name of a contact or the label of an address of the account.
+    pub fn name(&self, address: &Address) -> Option<&str> {
+        self.contacts
+            .iter()
+            .find(|contact| contact.address.inner() == address)
+            .map(|contact| contact.name.as_str())
+            .or_else(|| self.address_label(address))
+    }
+
+    /// Returns the label of an address of the account.
+    pub fn address_label(&self, address: &Address) -> Option<&str> {
+        self.address_labels
+            .iter()
+            .find(|address_label| address_label.address.inner() == address)
+            .map(|address_label| address_label.label.as_str())
+    }
+
+    /// Returns the tags of an output.
+    pub fn output_tags(&self, output_id: &OutputId) -> &[String] {
+        self.output_tags.get(output_id).map_or(&[], |tags| tags.as_slice())
+    }
+
+    // Returns the label of the address owning an output and the tags of the output, as emitted with output events
+    #[cfg(feature = "events")]
+    pub(crate) fn output_labels(&self, output_id: &OutputId, address: &Address) -> (Option<String>, Vec<String>) {
+        (
+            self.address_label(address).map(String::from),
+            self.output_tags(output_id).to_vec(),
+        )
+    }
+
+    /// Returns the contacts, address labels and output tags containing the query, ignoring the case.
+    pub fn search(&self, query: &str) -> Vec<LabelMatch> {
+        let query = query.to_lowercase();
+        let matches = |text: &str| text.to_lowercase().contains(&query);
+
+        let mut label_matches = self
+            .contacts
+            .iter()
+            .filter(|contact| matches(contact.name.as_str()) || matches(contact.address.to_string().as_str()))
+            .cloned()
+            .map(LabelMatch::Contact)
+            .chain(
+                self.address_labels
+                    .iter()
+                    .filter(|address_label| matches(address_label.label.as_str()))
+                    .cloned()
+                    .map(LabelMatch::AddressLabel),
+            )
+            .collect::<Vec<_>>();
+
+        let mut output_tags = self
+            .output_tags
+            .iter()
+            .flat_map(|(output_id, tags)| {
+                tags.iter()
+                    .filter(|tag| matches(tag.as_str()))
+                    .map(|tag| LabelMatch::OutputTag {
+                        output_id: *output_id,
+                        tag: tag.clone(),
+                    })
+            })
+            .collect::<Vec<_>>();
+        // The tags are stored in a map, sorting keeps the results stable
+        output_tags.sort_by(|a, b| match (a, b) {
+            (
+                LabelMatch::OutputTag { output_id, tag },
+                LabelMatch::OutputTag {
+                    output_id: other_output_id,
+                    tag: other_tag,
+                },
+            ) => output_id.cmp(other_output_id).then_with(|| tag.cmp(other_tag)),
+            _ => std::cmp::Ordering::Equal,
+        });
+        label_matches.extend(output_tags);
+
+        label_matches
+    }
+}
+
+/// A result of [`Account::search_labels()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum LabelMatch {
+    /// A contact whose name or address matches.
+    Contact(Contact),
+    /// A label of an address of the account.
+    AddressLabel(AddressLabel),
+    /// A tag of an output.
+    #[serde(rename_all = "camelCase")]
+    OutputTag { output_id: OutputId, tag: String },
+}
+
+impl Account {
+    /// Returns the address book and the labels of the account.
+    pub async fn labels(&self) -> Labels {
+        self.details().await.labels.clone()
+    }
+
+    /// Adds a contact to the address book, or renames it if the address is already known.
+    pub async fn set_contact(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        name: impl Into<String> + Send,
+    ) -> crate::wallet::Result<()> {
+        let address = address.convert()?;
+        let name = name.into();
+
+        let mut account_details = self.details_mut().await;
+        let contacts = &mut account_details.labels.contacts;
+        match contacts
+            .iter_mut()
+            .find(|contact| contact.address.inner() == address.inner())
+        {
+            Some(contact) => {
+                contact.address = address;
+                contact.name = name;
+            }
+            None => contacts.push(Contact { address, name }),
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    /// Removes a contact from the address book.
+    pub async fn remove_contact(&self, address: impl ConvertTo<Bech32Address>) -> crate::wallet::Result<()> {
+        let address = address.convert()?;
+
+        let mut account_details = self.details_mut().await;
+        account_details
+            .labels
+            .contacts
+            .retain(|contact| contact.address.inner() != address.inner());
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    /// Sets the label of an address of the account, `None` removes it.
+    pub async fn set_address_label(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        label: Option<String>,
+    ) -> crate::wallet::Result<()> {
+        let address = address.convert()?;
+
+        let mut account_details = self.details_mut().await;
+        if !account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+            .any(|account_address| account_address.address.inner() == address.inner())
+        {
+            return Err(Error::AddressNotFoundInAccount(address));
+        }
+
+        let address_labels = &mut account_details.labels.address_labels;
+        address_labels.retain(|address_label| address_label.address.inner() != address.inner());
+        if let Some(label) = label {
+            address_labels.push(AddressLabel { address, label });
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    /// Adds a tag to an output of the account.
+    pub async fn add_output_tag(
+        &self,
+        output_id: OutputId,
+        tag: impl Into<String> + Send,
+    ) -> crate::wallet::Result<()> {
+        let tag = tag.into();
+
+        let mut account_details = self.details_mut().await;
+        if !account_details.outputs.contains_key(&output_id) {
+            return Err(Error::OutputNotFound(output_id));
+        }
+
+        let tags = account_details.labels.output_tags.entry(output_id).or_default();
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    /// Removes a tag from an output of the account.
+    pub async fn remove_output_tag(&self, output_id: OutputId, tag: &str) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        let output_tags = &mut account_details.labels.output_tags;
+        if let Some(tags) = output_tags.get_mut(&output_id) {
+            tags.retain(|t| t != tag);
+            if tags.is_empty() {
+                output_tags.remove(&output_id);
+            }
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    /// Returns the contacts, address labels and output tags containing the query, ignoring the case.
+    pub async fn search_labels(&self, query: &str) -> Vec<LabelMatch> {
+        self.details().await.labels.search(query)
+    }
+}
@@ -5,8 +5,16 @@
 pub(crate) mod address_generation;
 /// The module to get the accounts balance
 pub(crate) mod balance;
+/// The module to export the history of an account
+pub(crate) mod export;
 /// Helper functions
 pub(crate) mod helpers;
+/// The module for the transaction history
+pub(crate) mod history;
+/// The module for invoices and matching outputs paying them
+pub(crate) mod invoices;
+/// The module for the address book and labels
+pub(crate) mod labels;
 /// The module for claiming of outputs with
 /// [`UnlockCondition`](crate::types::block::output::UnlockCondition)s that aren't only
 /// [`AddressUnlockCondition`](crate::types::block::output::unlock_condition::AddressUnlockCondition)
@@ -18,8 +26,12 @@ pub(crate) mod output_finder;
 /// The module for participation
 #[cfg(feature = "participation")]
 pub(crate) mod participation;
+/// The module for payment URIs
+pub(crate) mod payment_uri;
 /// The module for retrying blocks or transactions
 pub(crate) mod retry;
+/// The module for intents which are executed once their conditions are met
+pub(crate) mod scheduled_intents;
 /// The module for synchronization of an account
 pub(crate) mod syncing;
 /// The module for transactions
@@ -0,0 +1,293 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, str::FromStr};
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::{Bech32Address, Hrp},
+        output::{
+            feature::{MetadataFeature, TagFeature},
+            NativeToken, NativeTokens, NftId, TokenId,
+        },
+        protocol::ProtocolParameters,
+    },
+    wallet::{
+        account::{Account, Assets, Features, OutputParams, Unlocks},
+        Error,
+    },
+};
+
+/// A payment request, encoded as URI like `smr:<bech32 address>?amount=1000000&tag=invoice`.
+///
+/// The case-insensitive scheme is `iota` for the IOTA networks (`iota`/`atoi` HRP) and `smr` for the Shimmer networks
+/// (`smr`/`rms` HRP). The query can contain these parameters, all of them at most once except `token`:
+/// - `amount`: decimal amount of the base coin
+/// - `token`: `<token id>:<decimal amount>` of a native token
+/// - `nft`: id of an NFT
+/// - `tag`: UTF-8 text of the tag feature
+/// - `metadata`: UTF-8 text of the metadata feature
+/// - `expiration`: unix timestamp in seconds after which the payment returns to the sender if it wasn't claimed
+/// - `message`: UTF-8 text for the payer, not part of the payment
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentUri {
+    /// The recipient.
+    pub address: Bech32Address,
+    /// The amount of the base coin, `0` if not requested.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The native tokens.
+    #[serde(default)]
+    pub native_tokens: Vec<NativeToken>,
+    /// The NFT.
+    pub nft_id: Option<NftId>,
+    /// The tag.
+    pub tag: Option<String>,
+    /// The metadata.
+    pub metadata: Option<String>,
+    /// Unix timestamp in seconds after which the payment returns to the sender.
+    pub expiration: Option<u32>,
+    /// A message for the payer.
+    pub message: Option<String>,
+}
+
+impl PaymentUri {
+    /// Creates a payment URI without any parameters.
+    pub fn new(address: Bech32Address) -> Self {
+        Self {
+            address,
+            amount: 0,
+            native_tokens: Vec::new(),
+            nft_id: None,
+            tag: None,
+            metadata: None,
+            expiration: None,
+            message: None,
+        }
+    }
+
+    /// Parses a payment URI and validates it against the protocol parameters of the network.
+    pub fn parse(uri: &str, protocol_parameters: &ProtocolParameters) -> crate::wallet::Result<Self> {
+        let payment_uri = Self::from_str(uri)?;
+        payment_uri.validate(protocol_parameters)?;
+
+        Ok(payment_uri)
+    }
+
+    /// Validates the payment URI against the protocol parameters of the network.
+    pub fn validate(&self, protocol_parameters: &ProtocolParameters) -> crate::wallet::Result<()> {
+        if self.address.hrp() != protocol_parameters.bech32_hrp() {
+            Err(crate::client::Error::Bech32HrpMismatch {
+                provided: self.address.hrp().to_string(),
+                expected: protocol_parameters.bech32_hrp().to_string(),
+            })?;
+        }
+        if self.amount > protocol_parameters.token_supply() {
+            return Err(Error::PaymentUri(format!(
+                "amount {} exceeds the token supply {}",
+                self.amount,
+                protocol_parameters.token_supply()
+            )));
+        }
+        if self.native_tokens.len() > NativeTokens::COUNT_MAX as usize {
+            return Err(Error::PaymentUri(format!(
+                "{} native tokens exceed the maximum of {}",
+                self.native_tokens.len(),
+                NativeTokens::COUNT_MAX
+            )));
+        }
+        if let Some(tag) = &self.tag {
+            if tag.is_empty() || tag.len() > *TagFeature::LENGTH_RANGE.end() as usize {
+                return Err(Error::PaymentUri(format!("invalid tag length {}", tag.len())));
+            }
+        }
+        if let Some(metadata) = &self.metadata {
+            if metadata.is_empty() || metadata.len() > *MetadataFeature::LENGTH_RANGE.end() as usize {
+                return Err(Error::PaymentUri(format!("invalid metadata length {}", metadata.len())));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the payment URI, fails if there is no scheme for the HRP of the address.
+    pub fn to_uri(&self) -> crate::wallet::Result<String> {
+        let scheme = payment_uri_scheme(self.address.hrp())?;
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+
+        if self.amount != 0 {
+            query.append_pair("amount", &self.amount.to_string());
+        }
+        for native_token in &self.native_tokens {
+            query.append_pair(
+                "token",
+                &format!("{}:{}", native_token.token_id(), native_token.amount()),
+            );
+        }
+        if let Some(nft_id) = &self.nft_id {
+            query.append_pair("nft", &nft_id.to_string());
+        }
+        if let Some(tag) = &self.tag {
+            query.append_pair("tag", tag);
+        }
+        if let Some(metadata) = &self.metadata {
+            query.append_pair("metadata", metadata);
+        }
+        if let Some(expiration) = self.expiration {
+            query.append_pair("expiration", &expiration.to_string());
+        }
+        if let Some(message) = &self.message {
+            query.append_pair("message", message);
+        }
+        let query = query.finish();
+
+        if query.is_empty() {
+            Ok(format!("{scheme}:{}", self.address))
+        } else {
+            Ok(format!("{scheme}:{}?{query}", self.address))
+        }
+    }
+}
+
+impl FromStr for PaymentUri {
+    type Err = Error;
+
+    /// Parses a payment URI, rejecting unknown, repeated or empty parameters.
+    fn from_str(uri: &str) -> crate::wallet::Result<Self> {
+        let (scheme, rest) = uri
+            .split_once(':')
+            .ok_or_else(|| Error::PaymentUri("missing scheme".to_string()))?;
+        let (address, query) = match rest.split_once('?') {
+            Some((address, query)) => (address, Some(query)),
+            None => (rest, None),
+        };
+
+        let address = Bech32Address::try_from_str(address)
+            .map_err(|_| Error::PaymentUri(format!("invalid address {address}")))?;
+        let expected_scheme = payment_uri_scheme(address.hrp())?;
+        if !scheme.eq_ignore_ascii_case(expected_scheme) {
+            return Err(Error::PaymentUri(format!(
+                "scheme {scheme} doesn't match the address, expected {expected_scheme}"
+            )));
+        }
+
+        let mut payment_uri = Self::new(address);
+        let Some(query) = query else {
+            return Ok(payment_uri);
+        };
+        if query.is_empty() {
+            return Err(Error::PaymentUri("empty query".to_string()));
+        }
+
+        let mut keys = HashSet::new();
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            if value.is_empty() {
+                return Err(Error::PaymentUri(format!("empty parameter {key}")));
+            }
+            if key != "token" && !keys.insert(key.clone()) {
+                return Err(Error::PaymentUri(format!("repeated parameter {key}")));
+            }
+
+            match key.as_ref() {
+                "amount" => {
+                    payment_uri.amount = parse_decimal(&key, &value, u64::from_str)?;
+                    if payment_uri.amount == 0 {
+                        return Err(Error::PaymentUri("amount must not be zero".to_string()));
+                    }
+                }
+                "token" => {
+                    let (token_id, amount) = value
+                        .split_once(':')
+                        .ok_or_else(|| Error::PaymentUri(format!("invalid token {value}")))?;
+                    let token_id = TokenId::from_str(token_id)
+                        .map_err(|_| Error::PaymentUri(format!("invalid token id {token_id}")))?;
+                    if payment_uri
+                        .native_tokens
+                        .iter()
+                        .any(|native_token| native_token.token_id() == &token_id)
+                    {
+                        return Err(Error::PaymentUri(format!("repeated token {token_id}")));
+                    }
+                    let amount = parse_decimal(&key, amount, U256::from_dec_str)?;
+                    payment_uri.native_tokens.push(
+                        NativeToken::new(token_id, amount)
+                            .map_err(|_| Error::PaymentUri(format!("invalid amount of token {token_id}")))?,
+                    );
+                }
+                "nft" => {
+                    payment_uri.nft_id =
+                        Some(NftId::from_str(&value).map_err(|_| Error::PaymentUri(format!("invalid nft {value}")))?);
+                }
+                "tag" => payment_uri.tag = Some(value.into_owned()),
+                "metadata" => payment_uri.metadata = Some(value.into_owned()),
+                "expiration" => payment_uri.expiration = Some(parse_decimal(&key, &value, u32::from_str)?),
+                "message" => payment_uri.message = Some(value.into_owned()),
+                _ => return Err(Error::PaymentUri(format!("unknown parameter {key}"))),
+            }
+        }
+
+        Ok(payment_uri)
+    }
+}
+
+impl From<PaymentUri> for OutputParams {
+    fn from(payment_uri: PaymentUri) -> Self {
+        let assets = (!payment_uri.native_tokens.is_empty() || payment_uri.nft_id.is_some()).then(|| Assets {
+            native_tokens: (!payment_uri.native_tokens.is_empty()).then_some(payment_uri.native_tokens),
+            nft_id: payment_uri.nft_id,
+        });
+        let features = (payment_uri.tag.is_some() || payment_uri.metadata.is_some()).then(|| Features {
+            tag: payment_uri.tag.map(|tag| prefix_hex::encode(tag.as_bytes())),
+            metadata: payment_uri
+                .metadata
+                .map(|metadata| prefix_hex::encode(metadata.as_bytes())),
+            issuer: None,
+            sender: None,
+        });
+        let unlocks = payment_uri.expiration.map(|expiration| Unlocks {
+            expiration_unix_time: Some(expiration),
+            timelock_unix_time: None,
+        });
+
+        Self {
+            recipient_address: payment_uri.address,
+            amount: payment_uri.amount,
+            assets,
+            features,
+            unlocks,
+            storage_deposit: None,
+        }
+    }
+}
+
+// Returns the payment URI scheme of a network
+fn payment_uri_scheme(hrp: &Hrp) -> crate::wallet::Result<&'static str> {
+    match hrp.to_string().as_str() {
+        "iota" | "atoi" => Ok("iota"),
+        "smr" | "rms" => Ok("smr"),
+        hrp => Err(Error::PaymentUri(format!("no payment URI scheme for the HRP {hrp}"))),
+    }
+}
+
+// Parses a decimal number without sign or other characters
+fn parse_decimal<T, E>(key: &str, value: &str, parse: impl Fn(&str) -> Result<T, E>) -> crate::wallet::Result<T> {
+    if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(Error::PaymentUri(format!("invalid {key} {value}")));
+    }
+
+    parse(value).map_err(|_| Error::PaymentUri(format!("invalid {key} {value}")))
+}
+
+impl Account {
+    /// Parses a payment URI and validates it against the protocol parameters of the network. The payment can be
+    /// prepared by converting it into [`OutputParams`] for [`Account::prepare_output()`].
+    pub async fn parse_payment_uri(&self, uri: &str) -> crate::wallet::Result<PaymentUri> {
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
+
+        PaymentUri::parse(uri, &protocol_parameters)
+    }
+}
@@ -0,0 +1,429 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{ScheduledIntentEvent, WalletEvent};
+use crate::{
+    types::block::{output::OutputId, payload::transaction::TransactionId},
+    wallet::{
+        account::{operations::helpers::time::can_output_be_unlocked_now, types::Transaction, Account, AccountDetails},
+        Error,
+    },
+};
+
+// Claiming outputs might require the double amount of outputs, because of storage deposit return unlock conditions and
+// also a remainder, so only this many outputs are claimed in a single transaction.
+const SCHEDULED_CLAIM_OUTPUTS_MAX: usize = 60;
+
+/// An intent that is executed by the account once its conditions are met.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledIntent {
+    /// The identifier of the intent, unique within the account.
+    pub id: u32,
+    /// What should be done.
+    pub kind: ScheduledIntentKind,
+}
+
+/// The kind of a [`ScheduledIntent`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ScheduledIntentKind {
+    /// Claim an output as soon as its timelock or expiration allows it. The intent is removed once the output got
+    /// claimed or spent otherwise, or once its expiration returned it to an address outside of the account.
+    #[serde(rename_all = "camelCase")]
+    ClaimOutput { output_id: OutputId },
+    /// Reclaim sent outputs whose expiration returned them to the account. The intent stays registered until it's
+    /// removed.
+    ReclaimExpiredOutputs,
+}
+
+/// What happened when a [`ScheduledIntent`] was evaluated.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ScheduledIntentAction {
+    /// A transaction was sent for the intent.
+    #[serde(rename_all = "camelCase")]
+    Executed { transaction_id: TransactionId },
+    /// Sending the transaction failed, the intent is retried on the next evaluation.
+    Failed { error: String },
+    /// The intent can't be executed anymore and got removed.
+    Obsolete,
+}
+
+impl Account {
+    /// Registers an intent which is evaluated on each background syncing tick or when calling
+    /// [`Account::execute_scheduled_intents()`].
+    /// ```ignore
+    /// let intent = account
+    ///     .schedule_intent(ScheduledIntentKind::ClaimOutput { output_id })
+    ///     .await?;
+    /// println!("Scheduled intent {}", intent.id);
+    /// ```
+    pub async fn schedule_intent(&self, kind: ScheduledIntentKind) -> crate::wallet::Result<ScheduledIntent> {
+        log::debug!("[SCHEDULER] schedule_intent {kind:?}");
+        let mut account_details = self.details_mut().await;
+
+        if let ScheduledIntentKind::ClaimOutput { output_id } = &kind {
+            if !account_details.unspent_outputs.contains_key(output_id) {
+                return Err(Error::ScheduledIntent(format!(
+                    "output {output_id} is not an unspent output of the account"
+                )));
+            }
+        }
+        if account_details
+            .scheduled_intents
+            .iter()
+            .any(|intent| intent.kind == kind)
+        {
+            return Err(Error::ScheduledIntent(format!("{kind:?} is already scheduled")));
+        }
+
+        // Identifiers of removed intents aren't reused, intents stored before the counter existed are skipped
+        let id = account_details
+            .scheduled_intents
+            .iter()
+            .map(|intent| intent.id + 1)
+            .chain([account_details.next_scheduled_intent_id])
+            .max()
+            .unwrap_or_default();
+        account_details.next_scheduled_intent_id = id + 1;
+
+        let intent = ScheduledIntent { id, kind };
+        account_details.scheduled_intents.push(intent.clone());
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(intent)
+    }
+
+    /// Returns the registered intents.
+    pub async fn scheduled_intents(&self) -> Vec<ScheduledIntent> {
+        self.details().await.scheduled_intents.clone()
+    }
+
+    /// Removes a registered intent.
+    pub async fn remove_scheduled_intent(&self, intent_id: u32) -> crate::wallet::Result<()> {
+        log::debug!("[SCHEDULER] remove_scheduled_intent {intent_id}");
+        let mut account_details = self.details_mut().await;
+        let count = account_details.scheduled_intents.len();
+
+        account_details
+            .scheduled_intents
+            .retain(|intent| intent.id != intent_id);
+
+        if account_details.scheduled_intents.len() == count {
+            return Err(Error::ScheduledIntent(format!("intent {intent_id} not found")));
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    /// Executes the registered intents whose conditions are met, based on the last synced state of the account.
+    /// Intents that can't be executed anymore are removed, failed intents are retried on the next call. Returns the
+    /// sent transactions.
+    pub async fn execute_scheduled_intents(&self) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[SCHEDULER] execute_scheduled_intents");
+        let local_time = self.client().get_time_checked().await?;
+
+        let ScheduledIntentsEvaluation {
+            ready: ready_intents,
+            obsolete: obsolete_intents,
+        } = evaluate_scheduled_intents(&*self.details().await, local_time)?;
+
+        #[cfg(feature = "events")]
+        let account_index = self.details().await.index;
+        let mut transactions = Vec::new();
+        let mut finished_intents = obsolete_intents.iter().map(|intent| intent.id).collect::<Vec<_>>();
+
+        #[cfg(feature = "events")]
+        for intent in obsolete_intents {
+            log::debug!("[SCHEDULER] intent {} is obsolete", intent.id);
+            self.emit(
+                account_index,
+                WalletEvent::ScheduledIntent(Box::new(ScheduledIntentEvent {
+                    intent,
+                    action: ScheduledIntentAction::Obsolete,
+                })),
+            )
+            .await;
+        }
+
+        for (intent, output_ids) in ready_intents {
+            log::debug!(
+                "[SCHEDULER] execute intent {} with {} outputs",
+                intent.id,
+                output_ids.len()
+            );
+
+            #[cfg_attr(not(feature = "events"), allow(unused_variables))]
+            let action = match self.claim_outputs(output_ids).await {
+                Ok(transaction) => {
+                    if let ScheduledIntentKind::ClaimOutput { .. } = intent.kind {
+                        finished_intents.push(intent.id);
+                    }
+                    let transaction_id = transaction.transaction_id;
+                    transactions.push(transaction);
+                    ScheduledIntentAction::Executed { transaction_id }
+                }
+                Err(err) => {
+                    log::debug!("[SCHEDULER] intent {} failed: {err}", intent.id);
+                    ScheduledIntentAction::Failed { error: err.to_string() }
+                }
+            };
+
+            #[cfg(feature = "events")]
+            self.emit(
+                account_index,
+                WalletEvent::ScheduledIntent(Box::new(ScheduledIntentEvent { intent, action })),
+            )
+            .await;
+        }
+
+        if !finished_intents.is_empty() {
+            let mut account_details = self.details_mut().await;
+            account_details
+                .scheduled_intents
+                .retain(|intent| !finished_intents.contains(&intent.id));
+            #[cfg(feature = "storage")]
+            self.save(Some(&account_details)).await?;
+        }
+
+        Ok(transactions)
+    }
+}
+
+// Intents whose conditions are met together with the outputs to claim for them, and intents that can't be executed
+// anymore
+struct ScheduledIntentsEvaluation {
+    ready: Vec<(ScheduledIntent, Vec<OutputId>)>,
+    obsolete: Vec<ScheduledIntent>,
+}
+
+// Evaluates the conditions of the registered intents at the given time
+fn evaluate_scheduled_intents(
+    account_details: &AccountDetails,
+    local_time: u32,
+) -> crate::wallet::Result<ScheduledIntentsEvaluation> {
+    let mut ready = Vec::new();
+    let mut obsolete = Vec::new();
+    // An output can only be claimed by one of the intents
+    let mut scheduled_output_ids = HashSet::new();
+
+    for intent in &account_details.scheduled_intents {
+        let mut output_ids = Vec::new();
+
+        match &intent.kind {
+            ScheduledIntentKind::ClaimOutput { output_id } => match account_details.unspent_outputs.get(output_id) {
+                Some(output_data) => {
+                    // Once expired, only the return address can unlock the output
+                    let returned_to_other_address = output_data
+                        .output
+                        .unlock_conditions()
+                        .and_then(|unlock_conditions| unlock_conditions.expiration())
+                        .and_then(|expiration| expiration.return_address_expired(local_time))
+                        .map_or(false, |return_address| {
+                            !account_details
+                                .addresses_with_unspent_outputs
+                                .iter()
+                                .any(|address| address.address.inner == *return_address)
+                        });
+
+                    if returned_to_other_address {
+                        obsolete.push(intent.clone());
+                    } else if !account_details.locked_outputs.contains(output_id)
+                        && can_output_be_unlocked_now(
+                            &account_details.addresses_with_unspent_outputs,
+                            &[],
+                            output_data,
+                            local_time,
+                            None,
+                        )?
+                    {
+                        output_ids.push(*output_id);
+                    }
+                }
+                None => obsolete.push(intent.clone()),
+            },
+            ScheduledIntentKind::ReclaimExpiredOutputs => {
+                output_ids.extend(
+                    account_details
+                        .unspent_outputs
+                        .values()
+                        .filter(|output_data| {
+                            !account_details.locked_outputs.contains(&output_data.output_id)
+                                && output_data
+                                    .output
+                                    .unlock_conditions()
+                                    .and_then(|unlock_conditions| unlock_conditions.expiration())
+                                    .and_then(|expiration| expiration.return_address_expired(local_time))
+                                    .map_or(false, |return_address| {
+                                        account_details
+                                            .addresses_with_unspent_outputs
+                                            .iter()
+                                            .any(|address| address.address.inner == *return_address)
+                                    })
+                        })
+                        .map(|output_data| output_data.output_id),
+                );
+            }
+        }
+
+        output_ids.retain(|output_id| !scheduled_output_ids.contains(output_id));
+        output_ids.truncate(SCHEDULED_CLAIM_OUTPUTS_MAX);
+        scheduled_output_ids.extend(output_ids.iter().copied());
+
+        if !output_ids.is_empty() {
+            ready.push((intent.clone(), output_ids));
+        }
+    }
+
+    Ok(ScheduledIntentsEvaluation { ready, obsolete })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::{Address, Ed25519Address},
+            output::{
+                unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition, TimelockUnlockCondition},
+                BasicOutputBuilder,
+            },
+            protocol::protocol_parameters,
+        },
+        wallet::account::types::{AddressWithUnspentOutputs, OutputData},
+    };
+
+    #[test]
+    fn scheduled_intents_are_ready_once_their_condition_is_met() {
+        let token_supply = protocol_parameters().token_supply();
+        let mut account_details = AccountDetails::mock();
+        let account_address = account_details.public_addresses[0].address;
+        let other_address = Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let transaction_id = TransactionId::new([1; TransactionId::LENGTH]);
+
+        let timelocked_output_id = OutputId::new(transaction_id, 0).unwrap();
+        let timelocked_output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*account_address.as_ref()))
+            .add_unlock_condition(TimelockUnlockCondition::new(200).unwrap())
+            .finish_output(token_supply)
+            .unwrap();
+        // Sent to someone else and returned to the account once expired
+        let expiring_output_id = OutputId::new(transaction_id, 1).unwrap();
+        let expiring_output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(other_address))
+            .add_unlock_condition(ExpirationUnlockCondition::new(*account_address.as_ref(), 200).unwrap())
+            .finish_output(token_supply)
+            .unwrap();
+        let spent_output_id = OutputId::new(transaction_id, 2).unwrap();
+
+        for (output_id, output) in [
+            (timelocked_output_id, timelocked_output),
+            (expiring_output_id, expiring_output),
+        ] {
+            account_details.unspent_outputs.insert(
+                output_id,
+                OutputData::mock(output_id, output, *account_address.as_ref()),
+            );
+        }
+        account_details
+            .addresses_with_unspent_outputs
+            .push(AddressWithUnspentOutputs {
+                address: account_address,
+                key_index: 0,
+                internal: false,
+                output_ids: vec![timelocked_output_id, expiring_output_id],
+            });
+        account_details.scheduled_intents = vec![
+            ScheduledIntent {
+                id: 0,
+                kind: ScheduledIntentKind::ClaimOutput {
+                    output_id: timelocked_output_id,
+                },
+            },
+            ScheduledIntent {
+                id: 1,
+                kind: ScheduledIntentKind::ClaimOutput {
+                    output_id: spent_output_id,
+                },
+            },
+            ScheduledIntent {
+                id: 2,
+                kind: ScheduledIntentKind::ReclaimExpiredOutputs,
+            },
+        ];
+
+        // Before the timelock and the expiration
+        let evaluation = evaluate_scheduled_intents(&account_details, 100).unwrap();
+        assert!(evaluation.ready.is_empty());
+        assert_eq!(evaluation.obsolete, [account_details.scheduled_intents[1].clone()]);
+
+        let evaluation = evaluate_scheduled_intents(&account_details, 300).unwrap();
+        assert_eq!(
+            evaluation.ready,
+            [
+                (account_details.scheduled_intents[0].clone(), vec![timelocked_output_id]),
+                (account_details.scheduled_intents[2].clone(), vec![expiring_output_id]),
+            ]
+        );
+
+        // Locked outputs are used by another transaction already
+        account_details.locked_outputs.insert(timelocked_output_id);
+        let evaluation = evaluate_scheduled_intents(&account_details, 300).unwrap();
+        assert_eq!(
+            evaluation.ready,
+            [(account_details.scheduled_intents[2].clone(), vec![expiring_output_id])]
+        );
+    }
+
+    #[test]
+    fn claim_intents_are_obsolete_once_returned_to_the_sender() {
+        let token_supply = protocol_parameters().token_supply();
+        let mut account_details = AccountDetails::mock();
+        let account_address = account_details.public_addresses[0].address;
+        let sender_address = Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+
+        // Received from someone else, who gets it back once expired
+        let output_id = OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), 0).unwrap();
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*account_address.as_ref()))
+            .add_unlock_condition(ExpirationUnlockCondition::new(sender_address, 200).unwrap())
+            .finish_output(token_supply)
+            .unwrap();
+        account_details.unspent_outputs.insert(
+            output_id,
+            OutputData::mock(output_id, output, *account_address.as_ref()),
+        );
+        account_details
+            .addresses_with_unspent_outputs
+            .push(AddressWithUnspentOutputs {
+                address: account_address,
+                key_index: 0,
+                internal: false,
+                output_ids: vec![output_id],
+            });
+        account_details.scheduled_intents = vec![ScheduledIntent {
+            id: 0,
+            kind: ScheduledIntentKind::ClaimOutput { output_id },
+        }];
+
+        let evaluation = evaluate_scheduled_intents(&account_details, 100).unwrap();
+        assert_eq!(
+            evaluation.ready,
+            [(account_details.scheduled_intents[0].clone(), vec![output_id])]
+        );
+        assert!(evaluation.obsolete.is_empty());
+
+        let evaluation = evaluate_scheduled_intents(&account_details, 300).unwrap();
+        assert!(evaluation.ready.is_empty());
+        assert_eq!(evaluation.obsolete, account_details.scheduled_intents);
+    }
+}
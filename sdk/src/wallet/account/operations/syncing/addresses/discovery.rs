@@ -0,0 +1,157 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use crate::{
+    client::secret::GenerateAddressOptions,
+    types::block::address::Address,
+    wallet::account::{
+        operations::syncing::{CancellationToken, SyncOptions},
+        types::AccountAddress,
+        Account,
+    },
+};
+
+impl Account {
+    /// Returns the amount of unused addresses which are kept after the last used address of the public and the
+    /// internal chain.
+    pub async fn address_gap_limit(&self) -> Option<u32> {
+        self.details().await.address_gap_limit
+    }
+
+    /// Sets the amount of unused addresses which are kept after the last used address of the public and the internal
+    /// chain. When set, syncing all addresses generates and syncs new addresses until that many unused addresses
+    /// follow the last used one, like other BIP-44 wallets using the same seed. `None` disables the discovery.
+    pub async fn set_address_gap_limit(&self, address_gap_limit: Option<u32>) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        account_details.address_gap_limit = address_gap_limit;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Generates addresses until the address gap limit of the account is reached on both chains and syncs them, so
+    /// outputs on addresses beyond the generated ones are found.
+    pub(crate) async fn discover_addresses(
+        &self,
+        options: &SyncOptions,
+        cancellation: &CancellationToken,
+    ) -> crate::wallet::Result<()> {
+        let Some(address_gap_limit) = self.address_gap_limit().await else {
+            return Ok(());
+        };
+        // Only a sync of all addresses shows which addresses are used
+        if !options.addresses.is_empty()
+            || options.address_start_index != 0
+            || options.address_start_index_internal != 0
+        {
+            return Ok(());
+        }
+
+        self.discover_addresses_with_gap_limit(address_gap_limit, options, cancellation)
+            .await
+    }
+
+    /// Generates addresses until `address_gap_limit` unused addresses follow the last used one on both chains and
+    /// syncs them. The existing addresses need to be synced already.
+    pub(crate) async fn discover_addresses_with_gap_limit(
+        &self,
+        address_gap_limit: u32,
+        options: &SyncOptions,
+        cancellation: &CancellationToken,
+    ) -> crate::wallet::Result<()> {
+        loop {
+            cancellation.check()?;
+            let (public_amount, internal_amount, address_start_index, address_start_index_internal) = {
+                let account_details = self.details().await;
+                let used_addresses = account_details
+                    .outputs
+                    .values()
+                    .map(|output_data| output_data.address)
+                    .collect::<HashSet<_>>();
+                (
+                    missing_gap_addresses(&account_details.public_addresses, &used_addresses, address_gap_limit),
+                    missing_gap_addresses(&account_details.internal_addresses, &used_addresses, address_gap_limit),
+                    account_details.public_addresses.len() as u32,
+                    account_details.internal_addresses.len() as u32,
+                )
+            };
+
+            if public_amount == 0 && internal_amount == 0 {
+                return Ok(());
+            }
+            log::debug!(
+                "[SYNC] generating {public_amount} public and {internal_amount} internal addresses for the gap limit {address_gap_limit}"
+            );
+            self.generate_ed25519_addresses(public_amount, None).await?;
+            self.generate_ed25519_addresses(internal_amount, Some(GenerateAddressOptions::internal()))
+                .await?;
+
+            // Only sync the new addresses, if some of them are used, the next round generates more
+            self.sync_internal(
+                &SyncOptions {
+                    address_start_index,
+                    address_start_index_internal,
+                    ..options.clone()
+                },
+                cancellation,
+            )
+            .await?;
+        }
+    }
+}
+
+// Returns how many addresses need to be generated to have `address_gap_limit` unused addresses after the last used one
+fn missing_gap_addresses(
+    addresses: &[AccountAddress],
+    used_addresses: &HashSet<Address>,
+    address_gap_limit: u32,
+) -> u32 {
+    let unused_addresses_in_row = addresses
+        .iter()
+        .rev()
+        .take_while(|address| !used_addresses.contains(&address.address.inner))
+        .count() as u32;
+
+    address_gap_limit.saturating_sub(unused_addresses_in_row)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Bech32Address, Ed25519Address},
+        protocol::protocol_parameters,
+    };
+
+    #[test]
+    fn missing_gap_addresses_after_last_used_address() {
+        let addresses = (0..4)
+            .map(|key_index| AccountAddress {
+                address: Bech32Address::new(
+                    *protocol_parameters().bech32_hrp(),
+                    Ed25519Address::new([key_index as u8; Ed25519Address::LENGTH]),
+                ),
+                key_index,
+                internal: false,
+                used: false,
+            })
+            .collect::<Vec<_>>();
+        let used = |key_indexes: &[usize]| {
+            key_indexes
+                .iter()
+                .map(|key_index| addresses[*key_index].address.inner)
+                .collect::<HashSet<_>>()
+        };
+
+        // Without a used address all addresses count towards the gap
+        assert_eq!(missing_gap_addresses(&addresses, &used(&[]), 5), 1);
+        assert_eq!(missing_gap_addresses(&addresses, &used(&[]), 3), 0);
+        // Only the unused addresses after the last used one count
+        assert_eq!(missing_gap_addresses(&addresses, &used(&[0, 1]), 5), 3);
+        assert_eq!(missing_gap_addresses(&addresses, &used(&[3]), 5), 5);
+        assert_eq!(missing_gap_addresses(&addresses, &used(&[0]), 2), 0);
+        assert_eq!(missing_gap_addresses(&[], &used(&[]), 2), 2);
+    }
+}
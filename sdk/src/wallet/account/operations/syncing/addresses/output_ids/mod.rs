@@ -11,14 +11,18 @@ use std::collections::HashSet;
 use futures::FutureExt;
 use instant::Instant;
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::SyncStage;
 use crate::{
     types::block::{
         address::{Address, Bech32Address},
         output::OutputId,
     },
     wallet::account::{
-        constants::PARALLEL_REQUESTS_AMOUNT, operations::syncing::SyncOptions,
-        types::address::AddressWithUnspentOutputs, Account,
+        constants::PARALLEL_REQUESTS_AMOUNT,
+        operations::syncing::{CancellationToken, SyncOptions},
+        types::address::AddressWithUnspentOutputs,
+        Account,
     },
 };
 
@@ -153,9 +157,12 @@ impl Account {
         &self,
         options: &SyncOptions,
         addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
+        cancellation: &CancellationToken,
     ) -> crate::wallet::Result<(Vec<AddressWithUnspentOutputs>, Vec<OutputId>)> {
         log::debug!("[SYNC] start get_output_ids_for_addresses");
         let address_output_ids_start_time = Instant::now();
+        #[cfg(feature = "events")]
+        let (address_count, mut scanned_addresses) = (addresses_with_unspent_outputs.len(), 0);
 
         let mut addresses_with_outputs = Vec::new();
         // spent outputs or alias/nft/foundries that don't get synced anymore, because of other sync options
@@ -165,6 +172,7 @@ impl Account {
             .chunks(PARALLEL_REQUESTS_AMOUNT)
             .map(|x: &[AddressWithUnspentOutputs]| x.to_vec())
         {
+            cancellation.check()?;
             let results;
             #[cfg(target_family = "wasm")]
             {
@@ -196,6 +204,13 @@ impl Account {
                 results = futures::future::try_join_all(tasks).await?;
             }
 
+            #[cfg(feature = "events")]
+            {
+                scanned_addresses += results.len();
+                self.emit_sync_progress(SyncStage::AddressesScanned, scanned_addresses, Some(address_count))
+                    .await;
+            }
+
             for res in results {
                 let (mut address, output_ids): (AddressWithUnspentOutputs, Vec<OutputId>) = res?;
                 // only return addresses with outputs
@@ -225,6 +240,16 @@ impl Account {
             "[SYNC] finished get_output_ids_for_addresses in {:.2?}",
             address_output_ids_start_time.elapsed()
         );
+        #[cfg(feature = "events")]
+        self.emit_sync_progress(
+            SyncStage::OutputIdsFound,
+            addresses_with_outputs
+                .iter()
+                .map(|address| address.output_ids.len())
+                .sum(),
+            None,
+        )
+        .await;
         Ok((addresses_with_outputs, spent_or_not_anymore_synced_outputs))
     }
 }
@@ -3,8 +3,13 @@
 
 use instant::Instant;
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::SyncStage;
 use crate::wallet::{
-    account::{constants::PARALLEL_REQUESTS_AMOUNT, types::address::AddressWithUnspentOutputs, Account, OutputData},
+    account::{
+        constants::PARALLEL_REQUESTS_AMOUNT, operations::syncing::CancellationToken,
+        types::address::AddressWithUnspentOutputs, Account, OutputData,
+    },
     task,
 };
 
@@ -13,9 +18,15 @@ impl Account {
     pub(crate) async fn get_outputs_from_address_output_ids(
         &self,
         addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
+        cancellation: &CancellationToken,
     ) -> crate::wallet::Result<(Vec<AddressWithUnspentOutputs>, Vec<OutputData>)> {
         log::debug!("[SYNC] start get_outputs_from_address_output_ids");
         let address_outputs_start_time = Instant::now();
+        #[cfg(feature = "events")]
+        let output_count = addresses_with_unspent_outputs
+            .iter()
+            .map(|address| address.output_ids.len())
+            .sum();
 
         let mut addresses_with_outputs = Vec::new();
         let mut outputs_data = Vec::new();
@@ -25,6 +36,7 @@ impl Account {
             .chunks(PARALLEL_REQUESTS_AMOUNT)
             .map(|x: &[AddressWithUnspentOutputs]| x.to_vec())
         {
+            cancellation.check()?;
             let mut tasks = Vec::new();
             for address in addresses_chunk {
                 let account = self.clone();
@@ -46,6 +58,9 @@ impl Account {
                 addresses_with_outputs.push(address);
                 outputs_data.extend(outputs.into_iter());
             }
+            #[cfg(feature = "events")]
+            self.emit_sync_progress(SyncStage::OutputsFetched, outputs_data.len(), Some(output_count))
+                .await;
         }
         log::debug!(
             "[SYNC] finished get_outputs_from_address_output_ids in {:.2?}",
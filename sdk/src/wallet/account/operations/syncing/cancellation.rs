@@ -0,0 +1,39 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// A token to cancel a running sync or account recovery. Clones share the same state, so the token can be cancelled
+/// from another task. The sync only stops at points where the account isn't partially updated and returns
+/// [`Error::SyncCancelled`](crate::wallet::Error::SyncCancelled).
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a new token that isn't cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the sync or account recovery using this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns whether the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Returns an error if the token was cancelled.
+    pub(crate) fn check(&self) -> crate::wallet::Result<()> {
+        if self.is_cancelled() {
+            log::debug!("[SYNC] cancelled");
+            return Err(crate::wallet::Error::SyncCancelled);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,525 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use futures::{pin_mut, StreamExt};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::SyncStage;
+use crate::{
+    client::node_api::indexer::query_parameters::QueryParameter,
+    types::block::{
+        address::{Address, AliasAddress, NftAddress},
+        output::{dto::OutputMetadataDto, Output, OutputId, OutputWithMetadata, UnlockCondition},
+    },
+    wallet::account::{
+        operations::syncing::{
+            cancellation::CancellationToken,
+            options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
+            SyncOptions,
+        },
+        types::{AddressWithUnspentOutputs, OutputData},
+        Account,
+    },
+};
+
+/// The confirmed milestone up to which all addresses of an account were synced, so the next sync only needs to apply
+/// the UTXO changes of the milestones confirmed afterwards.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncedMilestone {
+    /// The index of the milestone.
+    pub index: u32,
+    /// The amount of addresses of the account at that time, outputs of addresses generated afterwards can only be
+    /// found by a full sync.
+    pub address_count: usize,
+    /// The kinds of outputs that were synced, outputs of other kinds can only be found by a full sync.
+    pub output_kinds: SyncedOutputKinds,
+}
+
+/// The sync options that decide which kinds of outputs are synced for the addresses of an account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncedOutputKinds {
+    /// See [`SyncOptions::account`].
+    pub account: AccountSyncOptions,
+    /// See [`SyncOptions::alias`].
+    pub alias: AliasSyncOptions,
+    /// See [`SyncOptions::nft`].
+    pub nft: NftSyncOptions,
+    /// See [`SyncOptions::sync_only_most_basic_outputs`].
+    pub sync_only_most_basic_outputs: bool,
+}
+
+impl From<&SyncOptions> for SyncedOutputKinds {
+    fn from(options: &SyncOptions) -> Self {
+        Self {
+            account: options.account.clone(),
+            alias: options.alias.clone(),
+            nft: options.nft.clone(),
+            sync_only_most_basic_outputs: options.sync_only_most_basic_outputs,
+        }
+    }
+}
+
+/// The changes to apply to the account after syncing: the spent or unsynced output ids with their metadata, the
+/// addresses with their unspent output ids, the new outputs and the milestone index they were synced at.
+pub(crate) type SyncedChanges = (
+    HashMap<OutputId, Option<OutputMetadataDto>>,
+    Vec<AddressWithUnspentOutputs>,
+    Vec<OutputData>,
+    Option<u32>,
+);
+
+impl Account {
+    /// Returns the milestone up to which all addresses of the account were synced.
+    pub async fn synced_milestone(&self) -> Option<SyncedMilestone> {
+        self.details().await.synced_milestone.clone()
+    }
+
+    /// Requests the UTXO changes of the milestones confirmed since the last synced milestone and returns the changes
+    /// relevant for the account. Returns `None` if a full sync is needed, because the account wasn't synced before,
+    /// addresses were generated in the meantime, other kinds of outputs are synced now, too many milestones were
+    /// confirmed or their changes are pruned.
+    pub(crate) async fn request_incremental_changes(
+        &self,
+        options: &SyncOptions,
+        cancellation: &CancellationToken,
+    ) -> crate::wallet::Result<Option<SyncedChanges>> {
+        log::debug!("[SYNC] request_incremental_changes");
+
+        let synced_milestone_index = {
+            let account_details = self.details().await;
+            let address_count = account_details.public_addresses.len() + account_details.internal_addresses.len();
+            match &account_details.synced_milestone {
+                Some(synced_milestone)
+                    if synced_milestone.address_count == address_count
+                        && synced_milestone.output_kinds == SyncedOutputKinds::from(options) =>
+                {
+                    synced_milestone.index
+                }
+                _ => {
+                    log::debug!("[SYNC] no synced milestone for the current addresses and options, doing a full sync");
+                    return Ok(None);
+                }
+            }
+        };
+
+        let confirmed_milestone_index = self
+            .client()
+            .get_info()
+            .await?
+            .node_info
+            .status
+            .confirmed_milestone
+            .index;
+        let milestone_count = confirmed_milestone_index.saturating_sub(synced_milestone_index);
+
+        if milestone_count > options.incremental_max_milestones {
+            log::debug!("[SYNC] {milestone_count} milestones since the last sync, doing a full sync");
+            return Ok(None);
+        }
+
+        let mut created_output_ids = Vec::new();
+        let mut consumed_output_ids = HashSet::new();
+
+        for index in synced_milestone_index + 1..=confirmed_milestone_index {
+            cancellation.check()?;
+            let utxo_changes = match self.client().get_utxo_changes_by_index(index).await {
+                Ok(utxo_changes) => utxo_changes,
+                Err(err) => {
+                    log::debug!("[SYNC] UTXO changes of milestone {index} unavailable, doing a full sync: {err}");
+                    return Ok(None);
+                }
+            };
+            for output_id in utxo_changes.created_outputs {
+                created_output_ids.push(OutputId::from_str(&output_id)?);
+            }
+            for output_id in utxo_changes.consumed_outputs {
+                consumed_output_ids.insert(OutputId::from_str(&output_id)?);
+            }
+        }
+        log::debug!(
+            "[SYNC] {} created and {} consumed outputs since milestone {synced_milestone_index}",
+            created_output_ids.len(),
+            consumed_output_ids.len()
+        );
+        // The UTXO changes contain the outputs of all addresses
+        #[cfg(feature = "events")]
+        {
+            let address_count = {
+                let account_details = self.details().await;
+                account_details.public_addresses.len() + account_details.internal_addresses.len()
+            };
+            self.emit_sync_progress(SyncStage::AddressesScanned, address_count, Some(address_count))
+                .await;
+        }
+
+        let created_outputs = self.client().get_outputs_ignore_errors(&created_output_ids).await?;
+        // Pruned outputs could belong to the account
+        if created_outputs.len() != created_output_ids.len() {
+            log::debug!("[SYNC] created outputs unavailable, doing a full sync");
+            return Ok(None);
+        }
+
+        let (mut owners, mut addresses_with_unspent_outputs, spent_output_ids) = {
+            let account_details = self.details().await;
+
+            // Maps the addresses that can own outputs of the account to the Ed25519 address they belong to
+            let mut owners = account_details
+                .public_addresses
+                .iter()
+                .chain(account_details.internal_addresses.iter())
+                .map(|address| (address.address.inner, address.address.inner))
+                .collect::<HashMap<_, _>>();
+            for output_data in account_details.unspent_outputs.values() {
+                if let Some(address) = chain_address(&output_data.output, &output_data.output_id) {
+                    owners.insert(address, output_data.address);
+                }
+            }
+
+            let mut addresses_with_unspent_outputs = account_details.addresses_with_unspent_outputs.clone();
+            for address in &mut addresses_with_unspent_outputs {
+                address
+                    .output_ids
+                    .retain(|output_id| !consumed_output_ids.contains(output_id));
+            }
+
+            let spent_output_ids = consumed_output_ids
+                .iter()
+                .filter(|output_id| account_details.unspent_outputs.contains_key(output_id))
+                .copied()
+                .collect::<Vec<_>>();
+
+            (owners, addresses_with_unspent_outputs, spent_output_ids)
+        };
+
+        // The metadata contains the milestone the outputs were spent in, it's optional as outputs could be pruned
+        let spent_output_metadata_responses = self
+            .client()
+            .get_outputs_metadata_ignore_errors(&spent_output_ids)
+            .await?;
+        let mut spent_output_metadata_map: HashMap<OutputId, Option<OutputMetadataDto>> = spent_output_ids
+            .into_iter()
+            .map(|output_id| (output_id, None))
+            .collect();
+        for output_metadata_response in spent_output_metadata_responses {
+            let output_id = output_metadata_response.output_id()?;
+            spent_output_metadata_map.insert(output_id, Some(output_metadata_response));
+        }
+
+        // Outputs can be owned by alias or nft outputs created in the same milestones, so this is repeated until no new
+        // outputs of the account are found
+        let mut outputs_by_address: HashMap<Address, Vec<OutputWithMetadata>> = HashMap::new();
+        let mut remaining_outputs = created_outputs;
+        loop {
+            let mut found_outputs = false;
+            let mut not_owned_outputs = Vec::new();
+
+            for output in remaining_outputs {
+                match output_owner(output.output(), &owners, options) {
+                    Some(address) => {
+                        if let Some(chain_address) = chain_address(output.output(), output.metadata().output_id()) {
+                            owners.insert(chain_address, address);
+                        }
+                        outputs_by_address.entry(address).or_default().push(output);
+                        found_outputs = true;
+                    }
+                    None => not_owned_outputs.push(output),
+                }
+            }
+
+            remaining_outputs = not_owned_outputs;
+            if !found_outputs {
+                break;
+            }
+        }
+        #[cfg(feature = "events")]
+        self.emit_sync_progress(
+            SyncStage::OutputIdsFound,
+            outputs_by_address.values().map(Vec::len).sum(),
+            None,
+        )
+        .await;
+
+        let account_addresses = self.addresses().await?;
+        let mut outputs_data = Vec::new();
+        for (address, outputs) in outputs_by_address {
+            let position = match addresses_with_unspent_outputs
+                .iter()
+                .position(|a| a.address.inner == address)
+            {
+                Some(position) => position,
+                None => {
+                    // PANIC: the owners only contain addresses of the account.
+                    let account_address = account_addresses.iter().find(|a| a.address.inner == address).unwrap();
+                    addresses_with_unspent_outputs.push(AddressWithUnspentOutputs {
+                        address: account_address.address,
+                        key_index: account_address.key_index,
+                        internal: account_address.internal,
+                        output_ids: Vec::new(),
+                    });
+                    addresses_with_unspent_outputs.len() - 1
+                }
+            };
+            let address_with_unspent_outputs = &mut addresses_with_unspent_outputs[position];
+            address_with_unspent_outputs.output_ids.extend(
+                outputs
+                    .iter()
+                    .filter(|output| !output.metadata().is_spent())
+                    .map(|output| *output.metadata().output_id()),
+            );
+
+            outputs_data.extend(
+                self.output_response_to_output_data(outputs, address_with_unspent_outputs)
+                    .await?,
+            );
+        }
+        addresses_with_unspent_outputs.retain(|address| !address.output_ids.is_empty());
+        #[cfg(feature = "events")]
+        self.emit_sync_progress(SyncStage::OutputsFetched, outputs_data.len(), Some(outputs_data.len()))
+            .await;
+
+        Ok(Some((
+            spent_output_metadata_map,
+            addresses_with_unspent_outputs,
+            outputs_data,
+            Some(confirmed_milestone_index.max(synced_milestone_index)),
+        )))
+    }
+
+    /// Stores the milestone up to which all addresses of the account were synced with the options.
+    pub(crate) async fn set_synced_milestone(&self, index: u32, options: &SyncOptions) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        let address_count = account_details.public_addresses.len() + account_details.internal_addresses.len();
+
+        account_details.synced_milestone = Some(SyncedMilestone {
+            index,
+            address_count,
+            output_kinds: SyncedOutputKinds::from(options),
+        });
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    /// Returns the milestone index up to which the indexer applied the ledger changes, which can lag behind the
+    /// confirmed milestone of the node.
+    pub(crate) async fn indexer_ledger_index(&self) -> crate::wallet::Result<u32> {
+        // Any query returns the ledger index, a single output id keeps the response small
+        let pages = self.client().basic_output_ids_stream([QueryParameter::PageSize(1)])?;
+        pin_mut!(pages);
+
+        // The first page is always returned
+        let page = pages
+            .next()
+            .await
+            .ok_or_else(|| crate::client::Error::NoOutput("indexer ledger index".to_string()))?;
+
+        Ok(page?.ledger_index)
+    }
+}
+
+// Returns the address of an alias or nft output
+fn chain_address(output: &Output, output_id: &OutputId) -> Option<Address> {
+    match output {
+        Output::Alias(alias_output) => Some(Address::Alias(AliasAddress::from(
+            alias_output.alias_id_non_null(output_id),
+        ))),
+        Output::Nft(nft_output) => Some(Address::Nft(NftAddress::from(nft_output.nft_id_non_null(output_id)))),
+        _ => None,
+    }
+}
+
+// Returns the Ed25519 address of the account an output belongs to, matching the indexer queries of a full sync
+fn output_owner(output: &Output, owners: &HashMap<Address, Address>, options: &SyncOptions) -> Option<Address> {
+    if options.sync_only_most_basic_outputs {
+        let Output::Basic(basic_output) = output else {
+            return None;
+        };
+        let [UnlockCondition::Address(address_unlock_condition)] = basic_output.unlock_conditions().as_ref() else {
+            return None;
+        };
+        return owners.get(address_unlock_condition.address()).copied();
+    }
+
+    output
+        .unlock_conditions()?
+        .iter()
+        .filter_map(|unlock_condition| match (output, unlock_condition) {
+            (Output::Basic(_) | Output::Nft(_), UnlockCondition::Address(unlock_condition)) => {
+                Some(unlock_condition.address())
+            }
+            (Output::Basic(_) | Output::Nft(_), UnlockCondition::StorageDepositReturn(unlock_condition)) => {
+                Some(unlock_condition.return_address())
+            }
+            (Output::Basic(_) | Output::Nft(_), UnlockCondition::Expiration(unlock_condition)) => {
+                Some(unlock_condition.return_address())
+            }
+            (Output::Alias(_), UnlockCondition::StateControllerAddress(unlock_condition)) => {
+                Some(unlock_condition.address())
+            }
+            (Output::Alias(_), UnlockCondition::GovernorAddress(unlock_condition)) => Some(unlock_condition.address()),
+            (Output::Foundry(_), UnlockCondition::ImmutableAliasAddress(unlock_condition)) => {
+                Some(unlock_condition.address())
+            }
+            _ => None,
+        })
+        .filter(|address| output_kind_synced(output, address, options))
+        .find_map(|address| owners.get(address).copied())
+}
+
+fn output_kind_synced(output: &Output, address: &Address, options: &SyncOptions) -> bool {
+    let (basic_outputs, nft_outputs, alias_outputs, foundry_outputs) = match address {
+        Address::Ed25519(_) => (
+            options.account.basic_outputs,
+            options.account.nft_outputs,
+            options.account.alias_outputs,
+            false,
+        ),
+        Address::Alias(_) => (
+            options.alias.basic_outputs,
+            options.alias.nft_outputs,
+            options.alias.alias_outputs,
+            options.alias.foundry_outputs,
+        ),
+        Address::Nft(_) => (
+            options.nft.basic_outputs,
+            options.nft.nft_outputs,
+            options.nft.alias_outputs,
+            false,
+        ),
+    };
+
+    match output {
+        Output::Basic(_) => basic_outputs,
+        Output::Nft(_) => nft_outputs,
+        Output::Alias(_) => alias_outputs,
+        Output::Foundry(_) => foundry_outputs,
+        Output::Treasury(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use super::*;
+    use crate::types::block::{
+        address::Ed25519Address,
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
+                ImmutableAliasAddressUnlockCondition, StateControllerAddressUnlockCondition,
+                StorageDepositReturnUnlockCondition,
+            },
+            AliasId, AliasOutputBuilder, BasicOutputBuilder, FoundryOutputBuilder, NftId, NftOutputBuilder,
+            SimpleTokenScheme, TokenScheme,
+        },
+        protocol::protocol_parameters,
+    };
+
+    #[test]
+    fn output_owners() {
+        let token_supply = protocol_parameters().token_supply();
+        let account_address = Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let other_address = Address::from(Ed25519Address::new([2; Ed25519Address::LENGTH]));
+        let alias_id = AliasId::new([3; AliasId::LENGTH]);
+        let alias_address = Address::Alias(AliasAddress::from(alias_id));
+        let nft_address = Address::Nft(NftAddress::from(NftId::new([4; NftId::LENGTH])));
+        let owners = HashMap::from([
+            (account_address, account_address),
+            (alias_address, account_address),
+            (nft_address, account_address),
+        ]);
+
+        let basic_output = |address: Address| {
+            BasicOutputBuilder::new_with_amount(1_000_000).add_unlock_condition(AddressUnlockCondition::new(address))
+        };
+        let owned = basic_output(account_address).finish_output(token_supply).unwrap();
+        let expiring = basic_output(other_address)
+            .add_unlock_condition(ExpirationUnlockCondition::new(account_address, 100).unwrap())
+            .finish_output(token_supply)
+            .unwrap();
+        let storage_deposit_return = basic_output(other_address)
+            .add_unlock_condition(
+                StorageDepositReturnUnlockCondition::new(account_address, 100_000, token_supply).unwrap(),
+            )
+            .finish_output(token_supply)
+            .unwrap();
+        let not_owned = basic_output(other_address).finish_output(token_supply).unwrap();
+        let owned_by_alias = basic_output(alias_address).finish_output(token_supply).unwrap();
+        let nft = NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+            .add_unlock_condition(AddressUnlockCondition::new(nft_address))
+            .finish_output(token_supply)
+            .unwrap();
+        let alias = AliasOutputBuilder::new_with_amount(1_000_000, AliasId::null())
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(account_address))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(account_address))
+            .finish_output(token_supply)
+            .unwrap();
+        let foundry = FoundryOutputBuilder::new_with_amount(
+            1_000_000,
+            1,
+            TokenScheme::Simple(SimpleTokenScheme::new(U256::from(10), U256::from(0), U256::from(10)).unwrap()),
+        )
+        .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(AliasAddress::from(alias_id)))
+        .finish_output(token_supply)
+        .unwrap();
+
+        // By default only foundries are synced for alias addresses and nothing for nft addresses
+        let options = SyncOptions::default();
+        assert_eq!(output_owner(&owned, &owners, &options), Some(account_address));
+        assert_eq!(output_owner(&expiring, &owners, &options), Some(account_address));
+        assert_eq!(
+            output_owner(&storage_deposit_return, &owners, &options),
+            Some(account_address)
+        );
+        assert_eq!(output_owner(&alias, &owners, &options), Some(account_address));
+        assert_eq!(output_owner(&foundry, &owners, &options), Some(account_address));
+        assert_eq!(output_owner(&not_owned, &owners, &options), None);
+        assert_eq!(output_owner(&owned_by_alias, &owners, &options), None);
+        assert_eq!(output_owner(&nft, &owners, &options), None);
+
+        let mut options = SyncOptions::default();
+        options.alias.basic_outputs = true;
+        options.nft.nft_outputs = true;
+        assert_eq!(output_owner(&owned_by_alias, &owners, &options), Some(account_address));
+        assert_eq!(output_owner(&nft, &owners, &options), Some(account_address));
+
+        let options = SyncOptions {
+            sync_only_most_basic_outputs: true,
+            ..Default::default()
+        };
+        assert_eq!(output_owner(&owned, &owners, &options), Some(account_address));
+        assert_eq!(output_owner(&expiring, &owners, &options), None);
+        assert_eq!(output_owner(&alias, &owners, &options), None);
+        assert_eq!(output_owner(&foundry, &owners, &options), None);
+    }
+
+    #[test]
+    fn output_kinds_synced() {
+        let token_supply = protocol_parameters().token_supply();
+        let ed25519_address = Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let alias_address = Address::Alias(AliasAddress::from(AliasId::new([3; AliasId::LENGTH])));
+        let basic = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(ed25519_address))
+            .finish_output(token_supply)
+            .unwrap();
+
+        let mut options = SyncOptions::default();
+        assert!(output_kind_synced(&basic, &ed25519_address, &options));
+        assert!(!output_kind_synced(&basic, &alias_address, &options));
+
+        options.account.basic_outputs = false;
+        options.alias.basic_outputs = true;
+        assert!(!output_kind_synced(&basic, &ed25519_address, &options));
+        assert!(output_kind_synced(&basic, &alias_address, &options));
+    }
+}
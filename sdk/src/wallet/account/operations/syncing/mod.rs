@@ -2,22 +2,32 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod addresses;
+pub(crate) mod cancellation;
 pub(crate) mod foundries;
+pub(crate) mod incremental;
 pub(crate) mod options;
 pub(crate) mod outputs;
 pub(crate) mod transactions;
 
 use std::collections::{HashMap, HashSet};
 
-pub use self::options::SyncOptions;
+use self::incremental::SyncedChanges;
+pub use self::{
+    cancellation::CancellationToken,
+    incremental::{SyncedMilestone, SyncedOutputKinds},
+    options::SyncOptions,
+};
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{SyncProgressEvent, SyncStage, WalletEvent};
 use crate::{
     types::block::{
         address::{Address, AliasAddress, NftAddress, ToBech32Ext},
         output::{dto::OutputMetadataDto, FoundryId, Output, OutputId},
     },
+    utils::unix_timestamp_now,
     wallet::account::{
         constants::MIN_SYNC_INTERVAL,
-        types::{AddressWithUnspentOutputs, OutputData},
+        types::{AddressWithUnspentOutputs, MilestonePoint, OutputData},
         Account, Balance,
     },
 };
@@ -45,6 +55,18 @@ impl Account {
     /// Sync the account by fetching new information from the nodes. Will also retry pending transactions
     /// if necessary. A custom default can be set using set_default_sync_options.
     pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Balance> {
+        self.sync_with_cancellation(options, &CancellationToken::new()).await
+    }
+
+    /// Sync the account like [`Account::sync()`], but stop with
+    /// [`Error::SyncCancelled`](crate::wallet::Error::SyncCancelled) when the token gets cancelled. The account is
+    /// only updated as a whole, so a cancelled sync either applied all changes of a synced set of addresses or
+    /// none.
+    pub async fn sync_with_cancellation(
+        &self,
+        options: Option<SyncOptions>,
+        cancellation: &CancellationToken,
+    ) -> crate::wallet::Result<Balance> {
         let options = match options {
             Some(opt) => opt,
             None => self.default_sync_options().await,
@@ -67,16 +89,19 @@ impl Account {
             return self.balance().await;
         }
 
-        self.sync_internal(&options).await?;
+        self.sync_internal(&options, cancellation).await?;
+        // Find outputs on addresses beyond the generated ones
+        self.discover_addresses(&options, cancellation).await?;
 
         // Sync transactions after updating account with outputs, so we can use them to check the transaction
         // status
         if options.sync_pending_transactions {
+            cancellation.check()?;
             let confirmed_tx_with_unknown_output = self.sync_pending_transactions().await?;
             // Sync again if we don't know the output yet, to prevent having no unspent outputs after syncing
             if confirmed_tx_with_unknown_output {
                 log::debug!("[SYNC] a transaction for which no output is known got confirmed, syncing outputs again");
-                self.sync_internal(&options).await?;
+                self.sync_internal(&options, cancellation).await?;
             }
         };
 
@@ -88,35 +113,39 @@ impl Account {
         Ok(balance)
     }
 
-    async fn sync_internal(&self, options: &SyncOptions) -> crate::wallet::Result<()> {
+    // Cancelling is only possible until the account gets updated
+    async fn sync_internal(
+        &self,
+        options: &SyncOptions,
+        cancellation: &CancellationToken,
+    ) -> crate::wallet::Result<()> {
         log::debug!("[SYNC] sync_internal");
+        cancellation.check()?;
+
+        // Only a sync of all addresses can be continued incrementally
+        let incremental = options.incremental
+            && options.addresses.is_empty()
+            && options.address_start_index == 0
+            && options.address_start_index_internal == 0;
+
+        let incremental_changes = if incremental {
+            self.request_incremental_changes(options, cancellation).await?
+        } else {
+            None
+        };
 
-        let addresses_to_sync = self.get_addresses_to_sync(options).await?;
-        log::debug!("[SYNC] addresses_to_sync {}", addresses_to_sync.len());
-
-        let (spent_or_not_synced_output_ids, addresses_with_unspent_outputs, outputs_data): (
-            Vec<OutputId>,
-            Vec<AddressWithUnspentOutputs>,
-            Vec<OutputData>,
-        ) = self.request_outputs_recursively(addresses_to_sync, options).await?;
-
-        // Request possible spent outputs
-        log::debug!("[SYNC] spent_or_not_synced_outputs: {spent_or_not_synced_output_ids:?}");
-        let spent_or_unsynced_output_metadata_responses = self
-            .client()
-            .get_outputs_metadata_ignore_errors(&spent_or_not_synced_output_ids)
-            .await?;
-
-        // Add the output response to the output ids, the output response is optional, because an output could be
-        // pruned and then we can't get the metadata
-        let mut spent_or_unsynced_output_metadata_map: HashMap<OutputId, Option<OutputMetadataDto>> =
-            spent_or_not_synced_output_ids.into_iter().map(|o| (o, None)).collect();
-        for output_metadata_response in spent_or_unsynced_output_metadata_responses {
-            let output_id = output_metadata_response.output_id()?;
-            spent_or_unsynced_output_metadata_map.insert(output_id, Some(output_metadata_response));
-        }
+        let (
+            spent_or_unsynced_output_metadata_map,
+            addresses_with_unspent_outputs,
+            outputs_data,
+            synced_milestone_index,
+        ) = match incremental_changes {
+            Some(changes) => changes,
+            None => self.request_full_changes(options, incremental, cancellation).await?,
+        };
 
         if options.sync_incoming_transactions {
+            cancellation.check()?;
             let transaction_ids = outputs_data
                 .iter()
                 .map(|output| *output.output_id.transaction_id())
@@ -126,6 +155,7 @@ impl Account {
         }
 
         if options.sync_native_token_foundries {
+            cancellation.check()?;
             let native_token_foundry_ids = outputs_data
                 .iter()
                 .filter_map(|output| output.output.native_tokens())
@@ -140,6 +170,7 @@ impl Account {
             self.request_and_store_foundry_outputs(native_token_foundry_ids).await?;
         }
 
+        cancellation.check()?;
         // Updates account with balances, output ids, outputs
         self.update_account(
             addresses_with_unspent_outputs,
@@ -147,7 +178,84 @@ impl Account {
             spent_or_unsynced_output_metadata_map,
             options,
         )
-        .await
+        .await?;
+
+        if let Some(index) = synced_milestone_index {
+            self.set_synced_milestone(index, options).await?;
+        }
+        if self.details().await.first_synced_milestone.is_none() {
+            self.set_first_synced_milestone().await?;
+        }
+
+        Ok(())
+    }
+
+    // Stores the confirmed milestone of the first sync, outputs spent before it can be unknown to the account
+    async fn set_first_synced_milestone(&self) -> crate::wallet::Result<()> {
+        let confirmed_milestone = self.client().get_info().await?.node_info.status.confirmed_milestone;
+
+        let mut account_details = self.details_mut().await;
+        account_details.first_synced_milestone = Some(MilestonePoint {
+            index: confirmed_milestone.index,
+            timestamp: confirmed_milestone
+                .timestamp
+                .unwrap_or_else(|| unix_timestamp_now().as_secs() as u32),
+        });
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    // Requests the outputs of all addresses to sync from the indexer
+    async fn request_full_changes(
+        &self,
+        options: &SyncOptions,
+        store_synced_milestone: bool,
+        cancellation: &CancellationToken,
+    ) -> crate::wallet::Result<SyncedChanges> {
+        // Requested first, so the next incremental sync doesn't miss changes of milestones applied meanwhile. The
+        // outputs are requested from the indexer, so its ledger index is used instead of the confirmed
+        // milestone of the node.
+        let synced_milestone_index = if store_synced_milestone {
+            Some(self.indexer_ledger_index().await?)
+        } else {
+            None
+        };
+
+        let addresses_to_sync = self.get_addresses_to_sync(options).await?;
+        log::debug!("[SYNC] addresses_to_sync {}", addresses_to_sync.len());
+
+        let (spent_or_not_synced_output_ids, addresses_with_unspent_outputs, outputs_data): (
+            Vec<OutputId>,
+            Vec<AddressWithUnspentOutputs>,
+            Vec<OutputData>,
+        ) = self
+            .request_outputs_recursively(addresses_to_sync, options, cancellation)
+            .await?;
+
+        // Request possible spent outputs
+        log::debug!("[SYNC] spent_or_not_synced_outputs: {spent_or_not_synced_output_ids:?}");
+        let spent_or_unsynced_output_metadata_responses = self
+            .client()
+            .get_outputs_metadata_ignore_errors(&spent_or_not_synced_output_ids)
+            .await?;
+
+        // Add the output response to the output ids, the output response is optional, because an output could be
+        // pruned and then we can't get the metadata
+        let mut spent_or_unsynced_output_metadata_map: HashMap<OutputId, Option<OutputMetadataDto>> =
+            spent_or_not_synced_output_ids.into_iter().map(|o| (o, None)).collect();
+        for output_metadata_response in spent_or_unsynced_output_metadata_responses {
+            let output_id = output_metadata_response.output_id()?;
+            spent_or_unsynced_output_metadata_map.insert(output_id, Some(output_metadata_response));
+        }
+
+        Ok((
+            spent_or_unsynced_output_metadata_map,
+            addresses_with_unspent_outputs,
+            outputs_data,
+            synced_milestone_index,
+        ))
     }
 
     // First request all outputs directly related to the ed25519 addresses, then for each nft and alias output we got,
@@ -157,6 +265,7 @@ impl Account {
         &self,
         addresses_to_sync: Vec<AddressWithUnspentOutputs>,
         options: &SyncOptions,
+        cancellation: &CancellationToken,
     ) -> crate::wallet::Result<(Vec<OutputId>, Vec<AddressWithUnspentOutputs>, Vec<OutputData>)> {
         // Cache the alias and nft address with the related ed2559 address, so we can update the account address with
         // the new output ids
@@ -165,15 +274,16 @@ impl Account {
             (Vec::new(), Vec::new(), Vec::new());
 
         loop {
+            cancellation.check()?;
             let new_outputs_data = if new_alias_and_nft_addresses.is_empty() {
                 // Get outputs for addresses and add them also the the addresses_with_unspent_outputs
                 let (addresses_with_output_ids, spent_or_not_synced_output_ids_inner) = self
-                    .get_output_ids_for_addresses(options, addresses_to_sync.clone())
+                    .get_output_ids_for_addresses(options, addresses_to_sync.clone(), cancellation)
                     .await?;
                 spent_or_not_synced_output_ids = spent_or_not_synced_output_ids_inner;
                 // Get outputs for addresses and add them also the the addresses_with_unspent_outputs
                 let (addresses_with_unspent_outputs_inner, outputs_data_inner) = self
-                    .get_outputs_from_address_output_ids(addresses_with_output_ids)
+                    .get_outputs_from_address_output_ids(addresses_with_output_ids, cancellation)
                     .await?;
                 addresses_with_unspent_outputs = addresses_with_unspent_outputs_inner;
                 outputs_data.extend(outputs_data_inner.clone().into_iter());
@@ -243,4 +353,18 @@ impl Account {
             outputs_data,
         ))
     }
+
+    #[cfg(feature = "events")]
+    pub(crate) async fn emit_sync_progress(&self, stage: SyncStage, processed: usize, total: Option<usize>) {
+        let account_index = self.details().await.index;
+        self.emit(
+            account_index,
+            WalletEvent::SyncProgress(SyncProgressEvent {
+                stage,
+                processed,
+                total,
+            }),
+        )
+        .await;
+    }
 }
@@ -7,6 +7,8 @@ use crate::types::block::address::Bech32Address;
 
 const DEFAULT_ADDRESS_START_INDEX: u32 = 0;
 const DEFAULT_FORCE_SYNCING: bool = false;
+const DEFAULT_INCREMENTAL: bool = false;
+const DEFAULT_INCREMENTAL_MAX_MILESTONES: u32 = 30;
 const DEFAULT_SYNC_INCOMING_TRANSACTIONS: bool = false;
 const DEFAULT_SYNC_ONLY_MOST_BASIC_OUTPUTS: bool = false;
 const DEFAULT_SYNC_PENDING_TRANSACTIONS: bool = true;
@@ -56,6 +58,19 @@ pub struct SyncOptions {
     /// Sync native token foundries, so their metadata can be returned in the balance.
     #[serde(default = "default_sync_native_token_foundries")]
     pub sync_native_token_foundries: bool,
+    /// Only apply the UTXO changes of the milestones confirmed since the last sync of all addresses, instead of
+    /// requesting the outputs of every address from the indexer. A full sync is done if the account wasn't synced
+    /// before, addresses were generated since, or the changes are pruned.
+    #[serde(default = "default_incremental")]
+    pub incremental: bool,
+    /// Maximum amount of milestones whose UTXO changes are applied by an incremental sync, if more milestones were
+    /// confirmed since the last sync, a full sync is done.
+    #[serde(default = "default_incremental_max_milestones")]
+    pub incremental_max_milestones: u32,
+    /// Send transactions which conflicted because a transaction they depended on conflicted again, after each sync of
+    /// the background syncing.
+    #[serde(default)]
+    pub replan_transactions: bool,
 }
 
 fn default_address_start_index() -> u32 {
@@ -82,6 +97,14 @@ fn default_sync_native_token_foundries() -> bool {
     DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES
 }
 
+fn default_incremental() -> bool {
+    DEFAULT_INCREMENTAL
+}
+
+fn default_incremental_max_milestones() -> u32 {
+    DEFAULT_INCREMENTAL_MAX_MILESTONES
+}
+
 impl Default for SyncOptions {
     fn default() -> Self {
         Self {
@@ -96,6 +119,9 @@ impl Default for SyncOptions {
             sync_only_most_basic_outputs: default_sync_only_most_basic_outputs(),
             sync_native_token_foundries: default_sync_native_token_foundries(),
             force_syncing: default_force_syncing(),
+            incremental: default_incremental(),
+            incremental_max_milestones: default_incremental_max_milestones(),
+            replan_transactions: false,
         }
     }
 }
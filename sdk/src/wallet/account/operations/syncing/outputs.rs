@@ -1,9 +1,14 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "events")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use crypto::keys::slip10::Chain;
 use instant::Instant;
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::SyncStage;
 use crate::{
     client::{constants::HD_WALLET_TYPE, Client},
     types::{
@@ -132,13 +137,21 @@ impl Account {
                     .inaccessible_incoming_transactions
                     .contains(transaction_id))
         });
+        drop(account_details);
+
+        #[cfg(feature = "events")]
+        let transaction_count = transaction_ids.len();
+        #[cfg(feature = "events")]
+        let resolved_count = &AtomicUsize::new(0);
 
         // Limit parallel requests to 100, to avoid timeouts
         let results =
             futures::future::try_join_all(transaction_ids.chunks(100).map(|x| x.to_vec()).map(|transaction_ids| {
                 let client = self.client().clone();
                 async move {
-                    task::spawn(async move {
+                    #[cfg(feature = "events")]
+                    let chunk_len = transaction_ids.len();
+                    let chunk_results = task::spawn(async move {
                         futures::future::try_join_all(transaction_ids.iter().map(|transaction_id| async {
                             let transaction_id = *transaction_id;
                             match client.get_included_block(&transaction_id).await {
@@ -170,7 +183,17 @@ impl Account {
                         }))
                         .await
                     })
-                    .await?
+                    .await??;
+
+                    // The chunks are resolved in parallel, so the progress is counted across them
+                    #[cfg(feature = "events")]
+                    {
+                        let resolved = resolved_count.fetch_add(chunk_len, Ordering::Relaxed) + chunk_len;
+                        self.emit_sync_progress(SyncStage::TransactionsResolved, resolved, Some(transaction_count))
+                            .await;
+                    }
+
+                    crate::wallet::Result::Ok(chunk_results)
                 }
             }))
             .await?;
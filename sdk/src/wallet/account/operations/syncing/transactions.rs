@@ -60,6 +60,12 @@ impl Account {
                 continue;
             }
 
+            // a replaced transaction must not be reattached, it's resolved together with its replacement
+            if account_details.replaced_transactions.contains_key(transaction_id) {
+                log::debug!("[SYNC] skip replaced transaction {transaction_id}");
+                continue;
+            }
+
             // check if we have an output (remainder, if not sending to an own address) that got created by this
             // transaction, if that's the case, then the transaction got confirmed
             let transaction_output = account_details
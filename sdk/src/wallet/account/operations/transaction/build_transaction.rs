@@ -66,7 +66,7 @@ impl Account {
         let prepared_transaction_data = PreparedTransactionData {
             essence,
             inputs_data: inputs_for_signing,
-            remainder: selected_transaction_data.remainder,
+            remainders: selected_transaction_data.remainders,
         };
 
         log::debug!(
@@ -0,0 +1,465 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use crypto::keys::slip10::Chain;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{TransactionInclusionEvent, WalletEvent};
+use crate::{
+    client::{constants::HD_WALLET_TYPE, secret::types::InputSigningData},
+    types::block::{
+        input::Input,
+        output::{unlock_condition::UnlockCondition, Output, OutputId, OutputMetadata},
+        parent::Parents,
+        payload::transaction::{TransactionEssence, TransactionId, TransactionPayload},
+        BlockId,
+    },
+    wallet::{
+        account::{
+            types::{InclusionState, Transaction},
+            Account, AccountDetails, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+/// A pending transaction of the account that spends outputs of other pending transactions of the account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionDependency {
+    /// The pending transactions whose outputs are spent.
+    pub parents: HashSet<TransactionId>,
+    /// The outputs the transaction was requested with.
+    pub outputs: Vec<Output>,
+    /// The options the transaction was requested with.
+    pub options: TransactionOptions,
+}
+
+/// A transaction that conflicted because a transaction it depended on conflicted, to be sent again with new inputs.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReplan {
+    /// The id of the conflicting transaction.
+    pub transaction_id: TransactionId,
+    /// The outputs the transaction was requested with.
+    pub outputs: Vec<Output>,
+    /// The options the transaction was requested with.
+    pub options: TransactionOptions,
+}
+
+impl Account {
+    /// Returns the transactions which conflicted because a transaction they depended on conflicted, they're sent
+    /// again by [`Account::replan_transactions()`].
+    pub async fn transactions_to_replan(&self) -> Vec<TransactionReplan> {
+        self.details().await.transactions_to_replan.clone()
+    }
+
+    /// Sends the transactions which conflicted because a transaction they depended on conflicted again, with new
+    /// inputs. Transactions that can't be sent are retried on the next call. Returns the sent transactions.
+    pub async fn replan_transactions(&self) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] replan_transactions");
+        // Taken out, so they can't be sent twice by concurrent calls
+        let replans = std::mem::take(&mut self.details_mut().await.transactions_to_replan);
+        let mut failed_replans = Vec::new();
+        let mut transactions = Vec::new();
+
+        for replan in replans {
+            let options = replan_options(&*self.details().await, replan.options.clone());
+
+            match self.finish_transaction(replan.outputs.clone(), options).await {
+                Ok(transaction) => {
+                    log::debug!(
+                        "[TRANSACTION] replanned transaction {} as {}",
+                        replan.transaction_id,
+                        transaction.transaction_id
+                    );
+                    transactions.push(transaction);
+                }
+                Err(err) => {
+                    log::debug!(
+                        "[TRANSACTION] replanning transaction {} failed: {err}",
+                        replan.transaction_id
+                    );
+                    failed_replans.push(replan);
+                }
+            }
+        }
+
+        let mut account_details = self.details_mut().await;
+        account_details.transactions_to_replan.extend(failed_replans);
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(transactions)
+    }
+
+    /// Removes a transaction from the transactions to replan, so it's not sent again.
+    pub async fn discard_transaction_replan(&self, transaction_id: &TransactionId) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        let count = account_details.transactions_to_replan.len();
+
+        account_details
+            .transactions_to_replan
+            .retain(|replan| replan.transaction_id != *transaction_id);
+
+        if account_details.transactions_to_replan.len() == count {
+            return Err(Error::TransactionNotFound(*transaction_id));
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    /// Records the pending transactions whose outputs are spent by a transaction, so it can be sent again if one of
+    /// them conflicts.
+    pub(crate) async fn track_transaction_dependency(
+        &self,
+        transaction: &Transaction,
+        outputs: Vec<Output>,
+        options: TransactionOptions,
+    ) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        let parents = input_transaction_ids(&transaction.payload)
+            .filter(|transaction_id| {
+                *transaction_id != transaction.transaction_id
+                    && account_details.pending_transactions.contains(transaction_id)
+            })
+            .collect::<HashSet<_>>();
+
+        if parents.is_empty() {
+            return Ok(());
+        }
+
+        log::debug!(
+            "[TRANSACTION] transaction {} depends on {parents:?}",
+            transaction.transaction_id
+        );
+        account_details.transaction_dependencies.insert(
+            transaction.transaction_id,
+            TransactionDependency {
+                parents,
+                outputs,
+                options,
+            },
+        );
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    /// Returns the parents for a block with a transaction that spends outputs of pending transactions. The blocks of
+    /// these transactions are referenced, so they're applied to the ledger first, the rest are tips.
+    pub(crate) async fn unconfirmed_input_parents(
+        &self,
+        transaction_payload: &TransactionPayload,
+    ) -> crate::wallet::Result<Option<Parents>> {
+        let mut parents = {
+            let account_details = self.details().await;
+            input_transaction_ids(transaction_payload)
+                .filter(|transaction_id| account_details.pending_transactions.contains(transaction_id))
+                .filter_map(|transaction_id| {
+                    account_details
+                        .transactions
+                        .get(&transaction_id)
+                        .and_then(|transaction| transaction.block_id)
+                })
+                .collect::<Vec<BlockId>>()
+        };
+
+        if parents.is_empty() {
+            return Ok(None);
+        }
+
+        let parents_max = *Parents::COUNT_RANGE.end() as usize;
+        parents.sort_unstable();
+        parents.dedup();
+        parents.truncate(parents_max);
+
+        for tip in self.client().get_tips().await? {
+            if parents.len() == parents_max {
+                break;
+            }
+            if !parents.contains(&tip) {
+                parents.push(tip);
+            }
+        }
+
+        Ok(Some(Parents::from_vec(parents)?))
+    }
+
+    /// Resolves the dependencies of transactions after transactions got a final inclusion state.
+    /// Dependents of conflicting transactions can't be confirmed anymore, so they're marked as conflicting as well and
+    /// queued to be sent again.
+    pub(crate) async fn resolve_transaction_dependencies(
+        &self,
+        account_details: &mut AccountDetails,
+        finished_transactions: &[(TransactionId, InclusionState)],
+    ) {
+        #[cfg_attr(not(feature = "events"), allow(unused_variables))]
+        let conflicting_dependents = conflict_transaction_dependents(account_details, finished_transactions);
+
+        #[cfg(feature = "events")]
+        for transaction_id in conflicting_dependents {
+            self.emit(
+                account_details.index,
+                WalletEvent::TransactionInclusion(TransactionInclusionEvent {
+                    transaction_id,
+                    inclusion_state: InclusionState::Conflicting,
+                }),
+            )
+            .await;
+        }
+    }
+}
+
+// Marks the pending dependents of conflicting transactions as conflicting and queues them to be sent again, returns the
+// dependents which were pending
+fn conflict_transaction_dependents(
+    account_details: &mut AccountDetails,
+    finished_transactions: &[(TransactionId, InclusionState)],
+) -> Vec<TransactionId> {
+    let mut conflicting_dependents = Vec::new();
+    let mut conflicting_transactions = finished_transactions
+        .iter()
+        .filter(|(_, inclusion_state)| *inclusion_state == InclusionState::Conflicting)
+        .map(|(transaction_id, _)| *transaction_id)
+        .collect::<Vec<_>>();
+
+    while let Some(conflicting_transaction_id) = conflicting_transactions.pop() {
+        let dependents = account_details
+            .transaction_dependencies
+            .iter()
+            .filter(|(_, dependency)| dependency.parents.contains(&conflicting_transaction_id))
+            .map(|(transaction_id, _)| *transaction_id)
+            .collect::<Vec<_>>();
+
+        for transaction_id in dependents {
+            // PANIC: the id was just taken from the dependencies.
+            let dependency = account_details
+                .transaction_dependencies
+                .remove(&transaction_id)
+                .unwrap();
+
+            if account_details.pending_transactions.remove(&transaction_id) {
+                let mut inputs = Vec::new();
+                if let Some(transaction) = account_details.transactions.get_mut(&transaction_id) {
+                    transaction.inclusion_state = InclusionState::Conflicting;
+                    inputs.extend(transaction_inputs(&transaction.payload));
+                }
+                // Inputs which exist are available again
+                for output_id in inputs {
+                    if account_details.unspent_outputs.contains_key(&output_id) {
+                        account_details.locked_outputs.remove(&output_id);
+                    }
+                }
+                conflicting_dependents.push(transaction_id);
+            }
+            log::debug!("[SYNC] transaction {transaction_id} conflicts because {conflicting_transaction_id} did");
+
+            account_details.transactions_to_replan.push(TransactionReplan {
+                transaction_id,
+                outputs: dependency.outputs,
+                options: dependency.options,
+            });
+            conflicting_transactions.push(transaction_id);
+        }
+    }
+
+    // Dependencies are only needed as long as the transaction is pending
+    account_details
+        .transaction_dependencies
+        .retain(|transaction_id, _| account_details.pending_transactions.contains(transaction_id));
+
+    conflicting_dependents
+}
+
+/// Returns the outputs created by submitted pending transactions of the account which only need an address of the
+/// account to be unlocked, so they can be spent before the transactions are confirmed.
+pub(crate) fn unconfirmed_outputs_signing_data(
+    account_details: &AccountDetails,
+    forbidden_inputs: &HashSet<OutputId>,
+) -> crate::wallet::Result<Vec<InputSigningData>> {
+    let mut inputs = Vec::new();
+
+    for transaction_id in &account_details.pending_transactions {
+        // A replaced transaction will not be confirmed anymore
+        if account_details.replaced_transactions.contains_key(transaction_id) {
+            continue;
+        }
+        let Some(transaction) = account_details.transactions.get(transaction_id) else {
+            continue;
+        };
+        // Without a block it can't be referenced as parent
+        let Some(block_id) = transaction.block_id else {
+            continue;
+        };
+
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        for (index, output) in essence.outputs().iter().enumerate() {
+            let output_id = OutputId::new(*transaction_id, index as u16)?;
+
+            if forbidden_inputs.contains(&output_id) || account_details.outputs.contains_key(&output_id) {
+                continue;
+            }
+            // Other unlock conditions could prevent unlocking it right away
+            let Output::Basic(basic_output) = output else {
+                continue;
+            };
+            let [UnlockCondition::Address(address_unlock_condition)] = basic_output.unlock_conditions().as_ref() else {
+                continue;
+            };
+            let Some(address) = account_details
+                .public_addresses
+                .iter()
+                .chain(account_details.internal_addresses.iter())
+                .find(|address| address.address.inner == *address_unlock_condition.address())
+            else {
+                continue;
+            };
+
+            inputs.push(InputSigningData {
+                output: output.clone(),
+                // Not booked yet
+                output_metadata: OutputMetadata::new(block_id, output_id, false, None, None, None, 0, 0, 0),
+                chain: Some(Chain::from_u32_hardened([
+                    HD_WALLET_TYPE,
+                    account_details.coin_type,
+                    account_details.index,
+                    address.internal as u32,
+                    address.key_index,
+                ])),
+            });
+        }
+    }
+
+    Ok(inputs)
+}
+
+// Keeps only the custom and mandatory inputs which still exist
+fn replan_options(account_details: &AccountDetails, mut options: TransactionOptions) -> TransactionOptions {
+    for inputs in [&mut options.custom_inputs, &mut options.mandatory_inputs] {
+        if let Some(output_ids) = inputs {
+            output_ids.retain(|output_id| account_details.unspent_outputs.contains_key(output_id));
+            if output_ids.is_empty() {
+                *inputs = None;
+            }
+        }
+    }
+    options
+}
+
+fn transaction_inputs(transaction_payload: &TransactionPayload) -> impl Iterator<Item = OutputId> + '_ {
+    let TransactionEssence::Regular(essence) = transaction_payload.essence();
+    essence.inputs().iter().filter_map(|input| match input {
+        Input::Utxo(input) => Some(*input.output_id()),
+        _ => None,
+    })
+}
+
+fn input_transaction_ids(transaction_payload: &TransactionPayload) -> impl Iterator<Item = TransactionId> + '_ {
+    transaction_inputs(transaction_payload).map(|output_id| *output_id.transaction_id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::{Address, Ed25519Address},
+            output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+            protocol::protocol_parameters,
+        },
+        wallet::account::types::OutputData,
+    };
+
+    fn address() -> Address {
+        Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]))
+    }
+
+    fn output() -> Output {
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address()))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap()
+    }
+
+    // A pending transaction spending a single input
+    fn pending_transaction(input: OutputId) -> Transaction {
+        Transaction::mock(&[input], vec![output()])
+    }
+
+    fn track(account_details: &mut AccountDetails, transaction: Transaction, parent: TransactionId) {
+        account_details.pending_transactions.insert(transaction.transaction_id);
+        account_details.transaction_dependencies.insert(
+            transaction.transaction_id,
+            TransactionDependency {
+                parents: HashSet::from([parent]),
+                outputs: vec![output()],
+                options: TransactionOptions::default(),
+            },
+        );
+        account_details
+            .transactions
+            .insert(transaction.transaction_id, transaction);
+    }
+
+    #[test]
+    fn dependents_of_conflicting_parent_are_replanned() {
+        let mut account_details = AccountDetails::mock();
+
+        // The child spends an output of the parent, the grandchild spends an output of the child
+        let confirmed_output_id = OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), 0).unwrap();
+        let parent = pending_transaction(confirmed_output_id);
+        let parent_id = parent.transaction_id;
+        let child = pending_transaction(OutputId::new(parent_id, 0).unwrap());
+        let child_id = child.transaction_id;
+        let grandchild = pending_transaction(OutputId::new(child_id, 0).unwrap());
+        let grandchild_id = grandchild.transaction_id;
+        // Locked inputs which are unspent outputs of the account are unlocked again
+        let child_input = OutputId::new(parent_id, 0).unwrap();
+        account_details
+            .unspent_outputs
+            .insert(child_input, OutputData::mock(child_input, output(), address()));
+        account_details.locked_outputs.insert(child_input);
+
+        account_details.transactions.insert(parent_id, parent);
+        track(&mut account_details, child, parent_id);
+        track(&mut account_details, grandchild, child_id);
+
+        // A confirmed parent doesn't affect its dependents
+        let conflicting =
+            conflict_transaction_dependents(&mut account_details, &[(parent_id, InclusionState::Confirmed)]);
+        assert!(conflicting.is_empty());
+        assert_eq!(account_details.transaction_dependencies.len(), 2);
+
+        let mut conflicting =
+            conflict_transaction_dependents(&mut account_details, &[(parent_id, InclusionState::Conflicting)]);
+        conflicting.sort();
+        let mut expected = vec![child_id, grandchild_id];
+        expected.sort();
+        assert_eq!(conflicting, expected);
+
+        assert!(account_details.pending_transactions.is_empty());
+        assert!(account_details.transaction_dependencies.is_empty());
+        assert!(account_details.locked_outputs.is_empty());
+        for transaction_id in [child_id, grandchild_id] {
+            assert_eq!(
+                account_details.transactions[&transaction_id].inclusion_state,
+                InclusionState::Conflicting
+            );
+        }
+        assert_eq!(
+            account_details
+                .transactions_to_replan
+                .iter()
+                .map(|replan| replan.transaction_id)
+                .collect::<Vec<_>>(),
+            [child_id, grandchild_id]
+        );
+    }
+}
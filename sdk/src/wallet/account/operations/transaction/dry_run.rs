@@ -0,0 +1,577 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::{
+        input_selection::{is_alias_transition, Error as InputSelectionError},
+        PreparedTransactionData,
+    },
+    types::block::{
+        address::{Address, Bech32Address, Hrp},
+        output::{dto::OutputDto, ChainId, Output, OutputId, Rent, RentStructure, TokenId},
+        payload::transaction::{TransactionEssence, TransactionPayload},
+        semantic::{semantic_validation, ConflictReason, ValidationContext},
+        signature::{Ed25519Signature, Signature},
+        unlock::{AliasUnlock, NftUnlock, ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+    },
+    wallet::account::{operations::transaction::TransactionOptions, Account},
+};
+
+/// What a transaction would do if it was sent, created by [`Account::dry_run_transaction()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionDryRun {
+    /// The inputs that would be consumed
+    pub inputs: Vec<OutputId>,
+    /// The outputs that would be created
+    pub outputs: Vec<Output>,
+    /// Balance change of the account per token, the base coin comes first
+    pub balance_delta: Vec<TokenBalanceDelta>,
+    /// Alias, foundry and NFT chains that would be consumed without being transitioned into an output
+    pub consumed_chains: Vec<ChainId>,
+    /// Alias, foundry and NFT chains that would be created. New alias and NFT ids are derived from the transaction id,
+    /// so they're only known after signing and are reported as null ids
+    pub created_chains: Vec<ChainId>,
+    /// Amount that would be locked as storage deposit in the created outputs
+    pub storage_deposit_locked: u64,
+    /// Amount that recipients would have to return through storage deposit return unlock conditions
+    pub storage_deposit_returned: u64,
+    /// Where the remainders would be placed, the one holding the native tokens comes first
+    pub remainders: Vec<RemainderPlacement>,
+    /// Result of the semantic validation, [`ConflictReason::None`] if the transaction would be valid
+    pub conflict: ConflictReason,
+}
+
+/// Change of the account balance for a single token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenBalanceDelta {
+    /// The native token id, `None` for the base coin
+    pub token_id: Option<TokenId>,
+    /// Amount of the consumed inputs
+    pub consumed: U256,
+    /// Amount of the created outputs that stay in the account
+    pub created: U256,
+}
+
+impl TokenBalanceDelta {
+    /// Returns whether the balance of the token would decrease.
+    pub fn is_decrease(&self) -> bool {
+        self.consumed > self.created
+    }
+}
+
+/// Placement of the remainder output of a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemainderPlacement {
+    /// Index of the remainder in the outputs of the transaction
+    pub output_index: u16,
+    /// Address the remainder would be sent to
+    pub address: Bech32Address,
+    /// Amount of the remainder
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+}
+
+/// Dto for [`TransactionDryRun`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionDryRunDto {
+    /// The inputs that would be consumed
+    pub inputs: Vec<OutputId>,
+    /// The outputs that would be created
+    pub outputs: Vec<OutputDto>,
+    /// Balance change of the account per token, the base coin comes first
+    pub balance_delta: Vec<TokenBalanceDelta>,
+    /// Alias, foundry and NFT chains that would be consumed without being transitioned into an output
+    pub consumed_chains: Vec<ChainId>,
+    /// Alias, foundry and NFT chains that would be created
+    pub created_chains: Vec<ChainId>,
+    /// Amount that would be locked as storage deposit in the created outputs
+    pub storage_deposit_locked: String,
+    /// Amount that recipients would have to return through storage deposit return unlock conditions
+    pub storage_deposit_returned: String,
+    /// Where the remainders would be placed, the one holding the native tokens comes first
+    pub remainders: Vec<RemainderPlacement>,
+    /// Result of the semantic validation
+    pub conflict: ConflictReason,
+}
+
+impl From<&TransactionDryRun> for TransactionDryRunDto {
+    fn from(value: &TransactionDryRun) -> Self {
+        Self {
+            inputs: value.inputs.clone(),
+            outputs: value.outputs.iter().map(OutputDto::from).collect(),
+            balance_delta: value.balance_delta.clone(),
+            consumed_chains: value.consumed_chains.clone(),
+            created_chains: value.created_chains.clone(),
+            storage_deposit_locked: value.storage_deposit_locked.to_string(),
+            storage_deposit_returned: value.storage_deposit_returned.to_string(),
+            remainders: value.remainders.clone(),
+            conflict: value.conflict,
+        }
+    }
+}
+
+impl Account {
+    /// Runs input selection and the semantic validation for a transaction and reports what it would do, without
+    /// signing it or locking its inputs. If [`RemainderValueStrategy::ChangeAddress`] is used, a new remainder address
+    /// is still generated.
+    ///
+    /// [`RemainderValueStrategy::ChangeAddress`]: crate::wallet::account::RemainderValueStrategy::ChangeAddress
+    pub async fn dry_run_transaction(
+        &self,
+        outputs: impl Into<Vec<Output>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<TransactionDryRun> {
+        log::debug!("[TRANSACTION] dry_run_transaction");
+
+        let prepared_transaction_data = self
+            .prepare_transaction_internal(outputs, options, false, &HashSet::new())
+            .await?;
+        let current_time = self.client().get_time_checked().await?;
+        let rent_structure = self.client().get_rent_structure().await?;
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+
+        let account_addresses = {
+            let account_details = self.details().await;
+            account_details
+                .public_addresses()
+                .iter()
+                .chain(account_details.internal_addresses().iter())
+                .map(|address| *address.address.as_ref())
+                .collect::<HashSet<Address>>()
+        };
+
+        TransactionDryRun::new(
+            &prepared_transaction_data,
+            current_time,
+            &rent_structure,
+            bech32_hrp,
+            account_addresses,
+        )
+    }
+}
+
+impl TransactionDryRun {
+    // Builds the report of a prepared transaction, the outputs owned by the account addresses count as created for the
+    // balance delta.
+    pub(crate) fn new(
+        prepared_transaction_data: &PreparedTransactionData,
+        current_time: u32,
+        rent_structure: &RentStructure,
+        bech32_hrp: Hrp,
+        mut account_addresses: HashSet<Address>,
+    ) -> crate::wallet::Result<Self> {
+        let unlocks = placeholder_unlocks(prepared_transaction_data, current_time)?;
+        let transaction_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
+        let transaction_id = transaction_payload.id();
+        let TransactionEssence::Regular(essence) = transaction_payload.essence();
+
+        let inputs = prepared_transaction_data
+            .inputs_data
+            .iter()
+            .map(|input| (*input.output_id(), &input.output))
+            .collect::<Vec<(OutputId, &Output)>>();
+
+        let mut context = ValidationContext::new(
+            &transaction_id,
+            essence,
+            inputs.iter().map(|(id, input)| (id, *input)),
+            transaction_payload.unlocks(),
+            current_time,
+        );
+        // The placeholder unlocks don't contain valid signatures
+        context.verify_signatures = false;
+
+        let conflict = semantic_validation(context, inputs.as_slice(), transaction_payload.unlocks())?;
+
+        let input_chains = inputs
+            .iter()
+            .filter_map(|(output_id, output)| output.chain_id().map(|chain_id| chain_id.or_from_output_id(output_id)))
+            .collect::<Vec<ChainId>>();
+        // Outputs owned by consumed aliases and NFTs stay in the account
+        for chain_id in &input_chains {
+            match chain_id {
+                ChainId::Alias(alias_id) => account_addresses.insert(Address::from(*alias_id)),
+                ChainId::Nft(nft_id) => account_addresses.insert(Address::from(*nft_id)),
+                ChainId::Foundry(_) => false,
+            };
+        }
+        let output_chains = essence
+            .outputs()
+            .iter()
+            .filter_map(Output::chain_id)
+            .collect::<Vec<ChainId>>();
+
+        let mut balance_delta = vec![TokenBalanceDelta {
+            token_id: None,
+            consumed: U256::zero(),
+            created: U256::zero(),
+        }];
+
+        for (_, output) in &inputs {
+            balance_delta_entry(&mut balance_delta, None).consumed += U256::from(output.amount());
+            for native_token in output
+                .native_tokens()
+                .into_iter()
+                .flat_map(|native_tokens| native_tokens.iter())
+            {
+                balance_delta_entry(&mut balance_delta, Some(*native_token.token_id())).consumed +=
+                    native_token.amount();
+            }
+        }
+
+        let mut storage_deposit_locked = 0;
+        let mut storage_deposit_returned = 0;
+
+        for output in essence.outputs() {
+            storage_deposit_locked += output.rent_cost(rent_structure);
+
+            if let Some(storage_deposit_return) = output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+            {
+                storage_deposit_returned += storage_deposit_return.amount();
+            }
+
+            if !output_owner(output).map_or(false, |owner| account_addresses.contains(owner)) {
+                continue;
+            }
+
+            balance_delta_entry(&mut balance_delta, None).created += U256::from(output.amount());
+            for native_token in output
+                .native_tokens()
+                .into_iter()
+                .flat_map(|native_tokens| native_tokens.iter())
+            {
+                balance_delta_entry(&mut balance_delta, Some(*native_token.token_id())).created +=
+                    native_token.amount();
+            }
+        }
+
+        // Split remainders can be equal, so each of them takes the next output that isn't taken yet
+        let mut remainder_indexes = HashSet::new();
+        let remainders = prepared_transaction_data
+            .remainders
+            .iter()
+            .filter_map(|remainder| {
+                let output_index =
+                    essence.outputs().iter().enumerate().position(|(index, output)| {
+                        output == &remainder.output && !remainder_indexes.contains(&index)
+                    })?;
+                remainder_indexes.insert(output_index);
+
+                Some(RemainderPlacement {
+                    output_index: output_index as u16,
+                    address: Bech32Address::new(bech32_hrp, remainder.address),
+                    amount: remainder.output.amount(),
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            inputs: inputs.iter().map(|(output_id, _)| *output_id).collect(),
+            outputs: essence.outputs().to_vec(),
+            balance_delta,
+            consumed_chains: input_chains
+                .iter()
+                .filter(|chain_id| !output_chains.contains(chain_id))
+                .copied()
+                .collect(),
+            created_chains: output_chains
+                .iter()
+                .filter(|chain_id| chain_id.is_null() || !input_chains.contains(chain_id))
+                .copied()
+                .collect(),
+            storage_deposit_locked,
+            storage_deposit_returned,
+            remainders,
+            conflict,
+        })
+    }
+}
+
+// Get the balance delta of a token, adds it if it doesn't exist yet
+pub(crate) fn balance_delta_entry(
+    balance_delta: &mut Vec<TokenBalanceDelta>,
+    token_id: Option<TokenId>,
+) -> &mut TokenBalanceDelta {
+    match balance_delta.iter().position(|delta| delta.token_id == token_id) {
+        Some(index) => &mut balance_delta[index],
+        None => {
+            balance_delta.push(TokenBalanceDelta {
+                token_id,
+                consumed: U256::zero(),
+                created: U256::zero(),
+            });
+            // PANIC: an entry was just pushed.
+            balance_delta.last_mut().unwrap()
+        }
+    }
+}
+
+// Get the address that controls an output
+pub(crate) fn output_owner(output: &Output) -> Option<&Address> {
+    let unlock_conditions = output.unlock_conditions()?;
+
+    unlock_conditions
+        .address()
+        .map(|unlock_condition| unlock_condition.address())
+        .or_else(|| {
+            unlock_conditions
+                .state_controller_address()
+                .map(|unlock_condition| unlock_condition.address())
+        })
+        .or_else(|| {
+            unlock_conditions
+                .immutable_alias_address()
+                .map(|unlock_condition| unlock_condition.address())
+        })
+}
+
+// Build unlocks with the same structure as the ones created when signing, but with placeholder signatures. They can
+// only be used for a semantic validation that doesn't verify signatures.
+fn placeholder_unlocks(
+    prepared_transaction_data: &PreparedTransactionData,
+    current_time: u32,
+) -> crate::wallet::Result<Unlocks> {
+    let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+    let mut unlocks = Vec::new();
+    let mut unlock_indexes = HashMap::<Address, usize>::new();
+
+    for (current_unlock_index, input) in prepared_transaction_data.inputs_data.iter().enumerate() {
+        let alias_transition = is_alias_transition(&input.output, *input.output_id(), essence.outputs(), None);
+        let (input_address, _) =
+            input
+                .output
+                .required_and_unlocked_address(current_time, input.output_id(), alias_transition)?;
+
+        match unlock_indexes.get(&input_address) {
+            Some(unlock_index) => match input_address {
+                Address::Alias(_) => unlocks.push(Unlock::Alias(AliasUnlock::new(*unlock_index as u16)?)),
+                Address::Ed25519(_) => unlocks.push(Unlock::Reference(ReferenceUnlock::new(*unlock_index as u16)?)),
+                Address::Nft(_) => unlocks.push(Unlock::Nft(NftUnlock::new(*unlock_index as u16)?)),
+            },
+            None => match input_address {
+                Address::Ed25519(ed25519_address) => {
+                    // The address is used as public key, so the placeholder signatures are unique
+                    unlocks.push(Unlock::Signature(SignatureUnlock::new(Signature::Ed25519(
+                        Ed25519Signature::new(*ed25519_address, [0; Ed25519Signature::SIGNATURE_LENGTH]),
+                    ))));
+                    unlock_indexes.insert(input_address, current_unlock_index);
+                }
+                _ => return Err(InputSelectionError::MissingInputWithEd25519Address)?,
+            },
+        }
+
+        match &input.output {
+            Output::Alias(alias_output) => unlock_indexes.insert(
+                Address::Alias(alias_output.alias_address(input.output_id())),
+                current_unlock_index,
+            ),
+            Output::Nft(nft_output) => unlock_indexes.insert(
+                Address::Nft(nft_output.nft_address(input.output_id())),
+                current_unlock_index,
+            ),
+            _ => None,
+        };
+    }
+
+    Ok(Unlocks::new(unlocks)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        client::{api::RemainderData, secret::types::InputSigningData},
+        types::block::{
+            address::Ed25519Address,
+            input::{Input, UtxoInput},
+            output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, InputsCommitment, OutputMetadata},
+            payload::transaction::{RegularTransactionEssence, TransactionId},
+            protocol::protocol_parameters,
+            BlockId,
+        },
+    };
+
+    const TRANSACTION_ID: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
+
+    fn basic_output(address: Address, amount: u64) -> Output {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap()
+    }
+
+    fn prepared_transaction_data(
+        inputs: Vec<Output>,
+        outputs: Vec<Output>,
+        remainders: Vec<RemainderData>,
+    ) -> PreparedTransactionData {
+        let protocol_parameters = protocol_parameters();
+        let transaction_id = TransactionId::from_str(TRANSACTION_ID).unwrap();
+        let inputs_data = inputs
+            .into_iter()
+            .enumerate()
+            .map(|(index, output)| {
+                let output_id = OutputId::new(transaction_id, index as u16).unwrap();
+                InputSigningData {
+                    output,
+                    output_metadata: OutputMetadata::new(BlockId::null(), output_id, false, None, None, None, 0, 0, 0),
+                    chain: None,
+                }
+            })
+            .collect::<Vec<_>>();
+        let essence = RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs_data.iter().map(|input| &input.output)),
+        )
+        .with_inputs(
+            inputs_data
+                .iter()
+                .map(|input| Input::Utxo(UtxoInput::from(*input.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)
+        .unwrap();
+
+        PreparedTransactionData {
+            essence: TransactionEssence::Regular(essence),
+            inputs_data,
+            remainders,
+        }
+    }
+
+    #[test]
+    fn dry_run_report() {
+        let protocol_parameters = protocol_parameters();
+        let account_address = Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let recipient_address = Address::from(Ed25519Address::new([2; Ed25519Address::LENGTH]));
+        // The remainder is split into two equal outputs
+        let remainder = basic_output(account_address, 750_000);
+        let outputs = vec![
+            basic_output(recipient_address, 1_500_000),
+            remainder.clone(),
+            remainder.clone(),
+        ];
+        let prepared_transaction_data = prepared_transaction_data(
+            vec![
+                basic_output(account_address, 2_000_000),
+                basic_output(account_address, 1_000_000),
+            ],
+            outputs.clone(),
+            vec![
+                RemainderData {
+                    output: remainder,
+                    chain: None,
+                    address: account_address,
+                };
+                2
+            ],
+        );
+
+        let dry_run = TransactionDryRun::new(
+            &prepared_transaction_data,
+            100,
+            protocol_parameters.rent_structure(),
+            *protocol_parameters.bech32_hrp(),
+            HashSet::from([account_address]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            dry_run.inputs,
+            prepared_transaction_data
+                .inputs_data
+                .iter()
+                .map(|input| *input.output_id())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(dry_run.outputs, outputs);
+        assert_eq!(
+            dry_run.balance_delta,
+            [TokenBalanceDelta {
+                token_id: None,
+                consumed: U256::from(3_000_000),
+                created: U256::from(1_500_000),
+            }]
+        );
+        assert!(dry_run.balance_delta[0].is_decrease());
+        assert!(dry_run.consumed_chains.is_empty());
+        assert!(dry_run.created_chains.is_empty());
+        assert_eq!(
+            dry_run.storage_deposit_locked,
+            outputs
+                .iter()
+                .map(|output| output.rent_cost(protocol_parameters.rent_structure()))
+                .sum::<u64>()
+        );
+        assert_eq!(dry_run.storage_deposit_returned, 0);
+        assert_eq!(
+            dry_run.remainders,
+            [1, 2].map(|output_index| RemainderPlacement {
+                output_index,
+                address: Bech32Address::new(*protocol_parameters.bech32_hrp(), account_address),
+                amount: 750_000,
+            })
+        );
+        // The placeholder signatures aren't verified
+        assert_eq!(dry_run.conflict, ConflictReason::None);
+    }
+
+    #[test]
+    fn dry_run_report_conflict() {
+        let protocol_parameters = protocol_parameters();
+        let account_address = Address::from(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let recipient_address = Address::from(Ed25519Address::new([2; Ed25519Address::LENGTH]));
+        let prepared_transaction_data = prepared_transaction_data(
+            vec![basic_output(account_address, 1_000_000)],
+            vec![basic_output(recipient_address, 2_000_000)],
+            Vec::new(),
+        );
+
+        let dry_run = TransactionDryRun::new(
+            &prepared_transaction_data,
+            100,
+            protocol_parameters.rent_structure(),
+            *protocol_parameters.bech32_hrp(),
+            HashSet::from([account_address]),
+        )
+        .unwrap();
+
+        assert_eq!(dry_run.conflict, ConflictReason::CreatedConsumedAmountMismatch);
+        assert!(dry_run.remainders.is_empty());
+
+        // Without the dry-run, the placeholder signatures are rejected
+        let unlocks = placeholder_unlocks(&prepared_transaction_data, 100).unwrap();
+        let transaction_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks).unwrap();
+        let TransactionEssence::Regular(essence) = transaction_payload.essence();
+        let inputs = prepared_transaction_data
+            .inputs_data
+            .iter()
+            .map(|input| (*input.output_id(), &input.output))
+            .collect::<Vec<_>>();
+        let context = ValidationContext::new(
+            &transaction_payload.id(),
+            essence,
+            inputs.iter().map(|(id, input)| (id, *input)),
+            transaction_payload.unlocks(),
+            100,
+        );
+
+        assert_eq!(
+            semantic_validation(context, &inputs, transaction_payload.unlocks()).unwrap(),
+            ConflictReason::InvalidSignature
+        );
+    }
+}
@@ -0,0 +1,597 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, str::FromStr};
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::input_selection::Error as InputSelectionError,
+    types::block::{
+        address::Bech32Address,
+        input::Input,
+        output::{
+            feature::{MetadataFeature, TagFeature},
+            unlock_condition::AddressUnlockCondition,
+            BasicOutputBuilder, NativeToken, Output, Rent, TokenId,
+        },
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
+    wallet::{
+        account::{
+            operations::transaction::high_level::transaction_plan::{
+                node_transaction_state, unincluded_transaction_state, NodeTransactionState, STEP_OUTPUT_COUNT_MAX,
+            },
+            types::{InclusionState, Transaction},
+            Account, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+/// A recipient of a batch payout.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutRecipient {
+    /// Identifier of the recipient within the payout, used to track its status across reruns. Defaults to the row
+    /// number, starting at 1.
+    #[serde(default)]
+    pub id: String,
+    /// Bech32 encoded address
+    pub address: Bech32Address,
+    /// Amount
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// Native tokens
+    #[serde(default)]
+    pub native_tokens: Vec<NativeToken>,
+    /// Hex encoded tag
+    pub tag: Option<String>,
+    /// Hex encoded metadata
+    pub metadata: Option<String>,
+}
+
+/// A row of a CSV list of recipients. Native tokens are written as `tokenId:amount` pairs separated by `;`, with
+/// decimal amounts.
+#[cfg(feature = "csv")]
+#[derive(Deserialize)]
+struct CsvPayoutRecipient {
+    #[serde(default)]
+    id: Option<String>,
+    address: String,
+    amount: String,
+    #[serde(default)]
+    native_tokens: Option<String>,
+    #[serde(default)]
+    tag: Option<String>,
+    #[serde(default)]
+    metadata: Option<String>,
+}
+
+impl PayoutRecipient {
+    /// Parses a JSON array of recipients. All rows are parsed and the errors of every invalid row are returned.
+    pub fn from_json(json: &str) -> crate::wallet::Result<Vec<Self>> {
+        let rows = serde_json::from_str::<Vec<serde_json::Value>>(json)?;
+        let mut recipients = Vec::with_capacity(rows.len());
+        let mut errors = Vec::new();
+
+        for (index, row) in rows.into_iter().enumerate() {
+            match serde_json::from_value::<Self>(row) {
+                Ok(recipient) => recipients.push(recipient.with_default_id(index)),
+                Err(e) => errors.push(format!("row {}: {e}", index + 1)),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::InvalidBatchPayout(errors));
+        }
+
+        Ok(recipients)
+    }
+
+    /// Parses a CSV list of recipients with the header `id,address,amount,native_tokens,tag,metadata`, only `address`
+    /// and `amount` are mandatory. All rows are parsed and the errors of every invalid row are returned.
+    #[cfg(feature = "csv")]
+    pub fn from_csv(csv: &str) -> crate::wallet::Result<Vec<Self>> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(csv.as_bytes());
+        let mut recipients = Vec::new();
+        let mut errors = Vec::new();
+
+        for (index, row) in reader.deserialize::<CsvPayoutRecipient>().enumerate() {
+            match row.map_err(|e| e.to_string()).and_then(Self::try_from_csv_row) {
+                Ok(recipient) => recipients.push(recipient.with_default_id(index)),
+                Err(e) => errors.push(format!("row {}: {e}", index + 1)),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::InvalidBatchPayout(errors));
+        }
+
+        Ok(recipients)
+    }
+
+    #[cfg(feature = "csv")]
+    fn try_from_csv_row(row: CsvPayoutRecipient) -> Result<Self, String> {
+        let native_tokens = row
+            .native_tokens
+            .as_deref()
+            .unwrap_or_default()
+            .split(';')
+            .filter(|native_token| !native_token.is_empty())
+            .map(|native_token| {
+                let (token_id, amount) = native_token
+                    .split_once(':')
+                    .ok_or_else(|| format!("invalid native token {native_token}"))?;
+                let token_id = TokenId::from_str(token_id).map_err(|e| e.to_string())?;
+                let amount = U256::from_dec_str(amount).map_err(|_| format!("invalid native token amount {amount}"))?;
+
+                NativeToken::new(token_id, amount).map_err(|e| e.to_string())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            id: row.id.unwrap_or_default(),
+            address: Bech32Address::try_from_str(row.address).map_err(|e| e.to_string())?,
+            amount: row
+                .amount
+                .parse()
+                .map_err(|_| format!("invalid amount {}", row.amount))?,
+            native_tokens,
+            tag: row.tag,
+            metadata: row.metadata,
+        })
+    }
+
+    fn with_default_id(mut self, index: usize) -> Self {
+        if self.id.is_empty() {
+            self.id = (index + 1).to_string();
+        }
+        self
+    }
+}
+
+/// The status of a recipient of a batch payout.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", content = "transactionId")]
+pub enum PayoutStatus {
+    /// The recipient wasn't paid yet.
+    Pending,
+    /// The transaction paying the recipient was signed and submitted, but isn't confirmed yet.
+    Submitted(TransactionId),
+    /// The transaction paying the recipient is confirmed.
+    Confirmed(TransactionId),
+}
+
+/// A recipient of a batch payout with its status.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutRecipientState {
+    /// The recipient.
+    pub recipient: PayoutRecipient,
+    /// The status of the payment to the recipient.
+    pub status: PayoutStatus,
+}
+
+/// A batch payout, persisted in the wallet storage.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchPayout {
+    /// The identifier of the payout.
+    pub id: String,
+    /// The recipients of the payout.
+    pub recipients: Vec<PayoutRecipientState>,
+}
+
+impl BatchPayout {
+    /// Returns `true` if all recipients are paid.
+    pub fn is_finished(&self) -> bool {
+        self.recipients
+            .iter()
+            .all(|state| matches!(state.status, PayoutStatus::Confirmed(_)))
+    }
+}
+
+impl Account {
+    /// Pays a list of recipients, packing them into as few transactions as possible. The status of every recipient is
+    /// persisted in the wallet storage under the payout id, so running the same payout again only pays the recipients
+    /// that weren't paid yet. Recipients whose transaction was submitted are only paid again once the transaction can't
+    /// be confirmed anymore, if that's unknown they are left as [`PayoutStatus::Submitted`]. Recipients that were
+    /// submitted or paid in a previous run can't be removed from the payout.
+    /// ```ignore
+    /// let recipients = PayoutRecipient::from_csv(&std::fs::read_to_string("payroll.csv")?)?;
+    /// let payout = account.batch_payout("payroll-2023-06", recipients, None).await?;
+    /// println!("Payout finished: {}", payout.is_finished());
+    /// ```
+    pub async fn batch_payout(
+        &self,
+        payout_id: impl Into<String> + Send,
+        recipients: Vec<PayoutRecipient>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<BatchPayout> {
+        let payout_id = payout_id.into();
+        let options = options.into();
+        log::debug!("[TRANSACTION] batch_payout {payout_id}");
+
+        let outputs = self.validate_batch_payout(&recipients).await?;
+        let mut payout = self.merge_batch_payout(&payout_id, recipients).await?;
+        self.update_submitted_payout_recipients(&mut payout).await?;
+
+        let pending = payout
+            .recipients
+            .iter()
+            .zip(outputs)
+            .enumerate()
+            .filter(|(_, (state, _))| state.status == PayoutStatus::Pending)
+            .map(|(index, (_, output))| (index, output))
+            .collect::<Vec<_>>();
+
+        for batch in pending.chunks(STEP_OUTPUT_COUNT_MAX) {
+            let (indexes, outputs): (Vec<_>, Vec<_>) = batch.iter().cloned().unzip();
+            let transaction = self
+                .send_batch_payout_outputs(&mut payout, &indexes, outputs, &options)
+                .await?;
+
+            self.retry_transaction_until_included(&transaction.transaction_id, None, None)
+                .await?;
+            // Sync so the remainder is available for the next batch
+            self.sync(None).await?;
+
+            for index in indexes {
+                payout.recipients[index].status = PayoutStatus::Confirmed(transaction.transaction_id);
+            }
+            self.save_batch_payout(&payout).await?;
+        }
+
+        Ok(payout)
+    }
+
+    /// Validates all recipients of a batch payout and returns the outputs paying them. The errors of every invalid row
+    /// are returned.
+    pub async fn validate_batch_payout(&self, recipients: &[PayoutRecipient]) -> crate::wallet::Result<Vec<Output>> {
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+
+        let mut ids = HashSet::new();
+        let mut outputs = Vec::with_capacity(recipients.len());
+        let mut errors = Vec::new();
+
+        for (index, recipient) in recipients.iter().enumerate() {
+            if !ids.insert(recipient.id.as_str()) {
+                errors.push(format!("row {}: duplicated id {}", index + 1, recipient.id));
+                continue;
+            }
+
+            match build_payout_output(recipient, bech32_hrp, token_supply) {
+                Ok(output) => {
+                    // Same as `minimum_storage_deposit_basic_output()`, but also takes the features into account
+                    let minimum_storage_deposit = output.rent_cost(&rent_structure);
+                    if recipient.amount < minimum_storage_deposit {
+                        errors.push(format!(
+                            "row {}: amount {} is below the minimum storage deposit {minimum_storage_deposit}",
+                            index + 1,
+                            recipient.amount
+                        ));
+                    } else {
+                        outputs.push(output);
+                    }
+                }
+                Err(e) => errors.push(format!("row {}: {e}", index + 1)),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::InvalidBatchPayout(errors));
+        }
+
+        Ok(outputs)
+    }
+
+    /// Returns the stored state of a batch payout.
+    pub async fn get_batch_payout(&self, payout_id: &str) -> crate::wallet::Result<Option<BatchPayout>> {
+        let account_index = self.details().await.index;
+
+        self.wallet
+            .storage_manager
+            .read()
+            .await
+            .get_batch_payout(account_index, payout_id)
+            .await
+    }
+
+    /// Merges the recipients with the stored state of the payout.
+    async fn merge_batch_payout(
+        &self,
+        payout_id: &str,
+        recipients: Vec<PayoutRecipient>,
+    ) -> crate::wallet::Result<BatchPayout> {
+        let stored_recipients = self
+            .get_batch_payout(payout_id)
+            .await?
+            .map(|payout| payout.recipients)
+            .unwrap_or_default();
+
+        Ok(BatchPayout {
+            id: payout_id.to_string(),
+            recipients: merge_payout_recipients(&stored_recipients, recipients)?,
+        })
+    }
+
+    /// Updates the status of the recipients whose transaction was submitted in a previous run.
+    async fn update_submitted_payout_recipients(&self, payout: &mut BatchPayout) -> crate::wallet::Result<()> {
+        let transaction_ids = payout
+            .recipients
+            .iter()
+            .filter_map(|state| match state.status {
+                PayoutStatus::Submitted(transaction_id) => Some(transaction_id),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+
+        for transaction_id in transaction_ids {
+            let transaction = self.details().await.transactions().get(&transaction_id).cloned();
+
+            let state = match transaction {
+                Some(transaction) => match transaction.inclusion_state {
+                    InclusionState::Conflicting => NodeTransactionState::Conflicting,
+                    // The transaction may have been confirmed before it was pruned, so its inputs decide
+                    InclusionState::UnknownPruned => {
+                        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                        let input_ids = essence
+                            .inputs()
+                            .iter()
+                            .filter_map(|input| match input {
+                                Input::Utxo(input) => Some(*input.output_id()),
+                                Input::Treasury(_) => None,
+                            })
+                            .collect::<Vec<_>>();
+                        let inputs_metadata = self.client().get_outputs_metadata_ignore_errors(&input_ids).await?;
+
+                        unincluded_transaction_state(&transaction_id, input_ids.len(), &inputs_metadata)
+                    }
+                    _ => {
+                        self.retry_transaction_until_included(&transaction_id, None, None)
+                            .await?;
+                        NodeTransactionState::Included
+                    }
+                },
+                // The transaction was signed, but is unknown to the account, so it may or may not have been sent.
+                None => {
+                    match node_transaction_state(self.client().get_included_block_metadata(&transaction_id).await) {
+                        Ok(state) => state,
+                        Err(err) => {
+                            log::warn!(
+                                "[TRANSACTION] unknown state of batch payout transaction {transaction_id}: {err}"
+                            );
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            let status = match state {
+                NodeTransactionState::Included => PayoutStatus::Confirmed(transaction_id),
+                // The transaction will never be confirmed, the recipients can be paid again
+                NodeTransactionState::Conflicting => PayoutStatus::Pending,
+                // The inputs of a pruned transaction are unspent, so it wasn't confirmed
+                NodeTransactionState::NotFound if transaction.is_some() => PayoutStatus::Pending,
+                NodeTransactionState::NotFound | NodeTransactionState::Unknown => {
+                    log::warn!("[TRANSACTION] unknown state of batch payout transaction {transaction_id}");
+                    continue;
+                }
+            };
+
+            for state in payout
+                .recipients
+                .iter_mut()
+                .filter(|state| state.status == PayoutStatus::Submitted(transaction_id))
+            {
+                state.status = status;
+            }
+        }
+
+        self.save_batch_payout(payout).await
+    }
+
+    /// Signs a transaction for a batch of recipients, marks them as submitted and sends it.
+    async fn send_batch_payout_outputs(
+        &self,
+        payout: &mut BatchPayout,
+        indexes: &[usize],
+        outputs: Vec<Output>,
+        options: &Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction_data = match self.prepare_transaction(outputs.clone(), options.clone()).await {
+            Err(Error::Client(err))
+                if matches!(
+                    *err,
+                    crate::client::Error::InputSelection(InputSelectionError::InvalidInputCount(_))
+                ) =>
+            {
+                log::debug!("[TRANSACTION] too many inputs needed for the batch payout, consolidating first");
+                let consolidation = self.consolidate_outputs(true, None).await?;
+                self.retry_transaction_until_included(&consolidation.transaction_id, None, None)
+                    .await?;
+                self.sync(None).await?;
+
+                self.prepare_transaction(outputs, options.clone()).await?
+            }
+            res => res?,
+        };
+
+        let signed_transaction_data = match self.sign_transaction_essence(&prepared_transaction_data).await {
+            Ok(res) => res,
+            Err(err) => {
+                // unlock outputs so they are available for a new transaction
+                self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+                return Err(err);
+            }
+        };
+        let transaction_id = signed_transaction_data.transaction_payload.id();
+
+        // Persist the status before submitting the transaction, so the recipients are never paid twice
+        for index in indexes {
+            payout.recipients[*index].status = PayoutStatus::Submitted(transaction_id);
+        }
+        self.save_batch_payout(payout).await?;
+
+        self.submit_and_store_transaction(signed_transaction_data).await
+    }
+
+    async fn save_batch_payout(&self, payout: &BatchPayout) -> crate::wallet::Result<()> {
+        let account_index = self.details().await.index;
+
+        self.wallet
+            .storage_manager
+            .read()
+            .await
+            .set_batch_payout(account_index, payout)
+            .await
+    }
+}
+
+// Takes the status of the recipients from the stored state of the payout. Recipients that were already submitted must
+// not have changed or be missing, so they are never paid twice.
+fn merge_payout_recipients(
+    stored_recipients: &[PayoutRecipientState],
+    recipients: Vec<PayoutRecipient>,
+) -> crate::wallet::Result<Vec<PayoutRecipientState>> {
+    let mut errors = stored_recipients
+        .iter()
+        .filter(|state| {
+            state.status != PayoutStatus::Pending
+                && !recipients.iter().any(|recipient| recipient.id == state.recipient.id)
+        })
+        .map(|state| format!("recipient {} was already paid, but is missing", state.recipient.id))
+        .collect::<Vec<_>>();
+
+    let recipients = recipients
+        .into_iter()
+        .map(|recipient| {
+            let status = match stored_recipients
+                .iter()
+                .find(|state| state.recipient.id == recipient.id)
+            {
+                Some(state) if state.status != PayoutStatus::Pending && state.recipient != recipient => {
+                    errors.push(format!(
+                        "recipient {} was already paid with different values",
+                        recipient.id
+                    ));
+                    state.status
+                }
+                Some(state) => state.status,
+                None => PayoutStatus::Pending,
+            };
+
+            PayoutRecipientState { recipient, status }
+        })
+        .collect();
+
+    if !errors.is_empty() {
+        return Err(Error::InvalidBatchPayout(errors));
+    }
+
+    Ok(recipients)
+}
+
+fn build_payout_output(
+    recipient: &PayoutRecipient,
+    bech32_hrp: crate::types::block::address::Hrp,
+    token_supply: u64,
+) -> crate::wallet::Result<Output> {
+    if recipient.address.hrp() != &bech32_hrp {
+        return Err(crate::client::Error::Bech32HrpMismatch {
+            provided: recipient.address.hrp().to_string(),
+            expected: bech32_hrp.to_string(),
+        })?;
+    }
+
+    let mut output_builder = BasicOutputBuilder::new_with_amount(recipient.amount)
+        .add_unlock_condition(AddressUnlockCondition::new(recipient.address))
+        .with_native_tokens(recipient.native_tokens.clone());
+
+    if let Some(tag) = &recipient.tag {
+        output_builder = output_builder.add_feature(TagFeature::new(
+            prefix_hex::decode::<Vec<u8>>(tag).map_err(|_| crate::types::block::Error::InvalidField("tag"))?,
+        )?);
+    }
+
+    if let Some(metadata) = &recipient.metadata {
+        output_builder = output_builder.add_feature(MetadataFeature::new(
+            prefix_hex::decode::<Vec<u8>>(metadata)
+                .map_err(|_| crate::types::block::Error::InvalidField("metadata"))?,
+        )?);
+    }
+
+    Ok(output_builder.finish_output(token_supply)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recipient(id: &str, amount: u64) -> PayoutRecipient {
+        PayoutRecipient {
+            id: id.to_string(),
+            address: Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")
+                .unwrap(),
+            amount,
+            native_tokens: Vec::new(),
+            tag: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn merge_stored_payout_recipients() {
+        let transaction_id = TransactionId::new([1; TransactionId::LENGTH]);
+        let stored_recipients = vec![
+            PayoutRecipientState {
+                recipient: recipient("1", 1_000_000),
+                status: PayoutStatus::Confirmed(transaction_id),
+            },
+            PayoutRecipientState {
+                recipient: recipient("2", 1_000_000),
+                status: PayoutStatus::Submitted(transaction_id),
+            },
+            PayoutRecipientState {
+                recipient: recipient("3", 1_000_000),
+                status: PayoutStatus::Pending,
+            },
+        ];
+
+        // Pending recipients can be changed or removed, new ones are pending
+        let merged = merge_payout_recipients(
+            &stored_recipients,
+            vec![
+                recipient("4", 1_000_000),
+                recipient("2", 1_000_000),
+                recipient("1", 1_000_000),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            merged.iter().map(|state| state.status).collect::<Vec<_>>(),
+            [
+                PayoutStatus::Pending,
+                PayoutStatus::Submitted(transaction_id),
+                PayoutStatus::Confirmed(transaction_id)
+            ]
+        );
+
+        // Recipients that were already paid can't be missing
+        assert!(matches!(
+            merge_payout_recipients(&stored_recipients, vec![recipient("1", 1_000_000), recipient("3", 2_000_000)]),
+            Err(Error::InvalidBatchPayout(errors)) if errors == ["recipient 2 was already paid, but is missing"]
+        ));
+        // Or changed
+        assert!(matches!(
+            merge_payout_recipients(&stored_recipients, vec![recipient("1", 2_000_000), recipient("2", 1_000_000)]),
+            Err(Error::InvalidBatchPayout(errors)) if errors == ["recipient 1 was already paid with different values"]
+        ));
+    }
+}
@@ -4,3 +4,4 @@
 pub(crate) mod increase_native_token_supply;
 pub(crate) mod mint_native_token;
 pub(crate) mod mint_nfts;
+pub(crate) mod nft_collection;
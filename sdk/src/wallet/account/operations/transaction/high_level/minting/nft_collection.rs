@@ -0,0 +1,444 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::node_api::indexer::query_parameters::QueryParameter,
+    types::block::{
+        address::{Bech32Address, NftAddress},
+        output::{feature::MetadataFeature, NftId, Output, OutputId, OUTPUT_COUNT_MAX},
+        payload::transaction::TransactionEssence,
+        Block,
+    },
+    wallet::{
+        account::{operations::transaction::Transaction, Account, MintNftParams, TransactionOptions},
+        Error,
+    },
+};
+
+// The issuer NFT and the remainder need an output in every minting transaction
+const MINT_OUTPUT_COUNT_MAX: usize = OUTPUT_COUNT_MAX as usize - 2;
+// Leaves space in the block for the inputs, their unlocks and the outputs without their metadata
+const MINT_METADATA_LENGTH_MAX: usize = Block::LENGTH_MAX / 2;
+
+/// NFT metadata following the IRC-27 standard.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Irc27Metadata {
+    /// The standard, always `IRC27`.
+    pub standard: String,
+    /// The version of the standard, always `v1.0`.
+    pub version: String,
+    /// The MIME type of the NFT, e.g. `image/png`.
+    #[serde(rename = "type")]
+    pub media_type: String,
+    /// The URI of the NFT content.
+    pub uri: String,
+    /// The name of the NFT.
+    pub name: String,
+    /// The name of the collection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collection_name: Option<String>,
+    /// Shares of the sales for Bech32 encoded addresses, between 0 and 1 and at most 1 in total.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub royalties: BTreeMap<String, f64>,
+    /// The name of the issuer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_name: Option<String>,
+    /// A description of the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Attributes of the NFT.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<Irc27Attribute>,
+}
+
+/// An attribute of an NFT with [`Irc27Metadata`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Irc27Attribute {
+    /// The name of the attribute.
+    pub trait_type: String,
+    /// The value of the attribute, a string or number.
+    pub value: serde_json::Value,
+}
+
+impl Irc27Metadata {
+    /// The standard of the metadata.
+    pub const STANDARD: &'static str = "IRC27";
+    /// The supported version of the standard.
+    pub const VERSION: &'static str = "v1.0";
+
+    /// Creates IRC-27 metadata with the mandatory fields.
+    pub fn new(media_type: impl Into<String>, uri: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            standard: Self::STANDARD.to_string(),
+            version: Self::VERSION.to_string(),
+            media_type: media_type.into(),
+            uri: uri.into(),
+            name: name.into(),
+            collection_name: None,
+            royalties: BTreeMap::new(),
+            issuer_name: None,
+            description: None,
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Set the collection name
+    pub fn with_collection_name(mut self, collection_name: impl Into<Option<String>>) -> Self {
+        self.collection_name = collection_name.into();
+        self
+    }
+
+    /// Add a royalty share for an address
+    pub fn with_royalty(mut self, address: Bech32Address, share: f64) -> Self {
+        self.royalties.insert(address.to_string(), share);
+        self
+    }
+
+    /// Set the issuer name
+    pub fn with_issuer_name(mut self, issuer_name: impl Into<Option<String>>) -> Self {
+        self.issuer_name = issuer_name.into();
+        self
+    }
+
+    /// Set the description
+    pub fn with_description(mut self, description: impl Into<Option<String>>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Add an attribute
+    pub fn with_attribute(mut self, trait_type: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.attributes.push(Irc27Attribute {
+            trait_type: trait_type.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Parses and validates the JSON metadata of an NFT.
+    pub fn from_bytes(bytes: &[u8]) -> crate::wallet::Result<Self> {
+        let metadata =
+            serde_json::from_slice::<Self>(bytes).map_err(|e| Error::Irc27Metadata(format!("invalid JSON: {e}")))?;
+        metadata.validate()?;
+
+        Ok(metadata)
+    }
+
+    /// Encodes the metadata as JSON, as stored in the metadata feature of an NFT.
+    pub fn to_bytes(&self) -> crate::wallet::Result<Vec<u8>> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// Validates the metadata against the IRC-27 schema.
+    pub fn validate(&self) -> crate::wallet::Result<()> {
+        self.check().map_err(Error::Irc27Metadata)
+    }
+
+    fn check(&self) -> Result<(), String> {
+        if self.standard != Self::STANDARD {
+            return Err(format!("unsupported standard {}", self.standard));
+        }
+        if self.version != Self::VERSION {
+            return Err(format!("unsupported version {}", self.version));
+        }
+        match self.media_type.split_once('/') {
+            Some((kind, subtype)) if !kind.is_empty() && !subtype.is_empty() => {}
+            _ => return Err(format!("invalid type {}, expected a MIME type", self.media_type)),
+        }
+        if self.uri.is_empty() {
+            return Err("empty uri".to_string());
+        }
+        if self.name.is_empty() {
+            return Err("empty name".to_string());
+        }
+
+        let mut royalties_total = 0.0;
+        for (address, share) in &self.royalties {
+            Bech32Address::try_from_str(address).map_err(|_| format!("invalid royalty address {address}"))?;
+            if share.is_nan() || *share <= 0.0 || *share > 1.0 {
+                return Err(format!("royalty share {share} of {address} isn't between 0 and 1"));
+            }
+            royalties_total += share;
+        }
+        // Allows for rounding errors of the shares
+        if royalties_total > 1.0 + f64::EPSILON * self.royalties.len() as f64 {
+            return Err(format!("royalty shares add up to {royalties_total}, more than 1"));
+        }
+
+        for attribute in &self.attributes {
+            if attribute.trait_type.is_empty() {
+                return Err("empty attribute trait_type".to_string());
+            }
+            if !(attribute.value.is_string() || attribute.value.is_number()) {
+                return Err(format!(
+                    "value of attribute {} must be a string or number",
+                    attribute.trait_type
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An NFT of a collection to mint with [`Account::mint_collection_nfts()`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionNftParams {
+    /// Bech32 encoded address to which the NFT will be minted. Default will use the first address of the account.
+    pub address: Option<Bech32Address>,
+    /// The immutable metadata of the NFT.
+    pub metadata: Irc27Metadata,
+}
+
+/// An NFT of a collection, as listed by [`Account::collection_nfts()`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionNft {
+    /// The id of the NFT.
+    pub nft_id: NftId,
+    /// The id of the output holding the NFT.
+    pub output_id: OutputId,
+    /// Bech32 encoded address of the current owner.
+    pub address: Bech32Address,
+    /// The immutable metadata, `None` if it isn't valid IRC-27 metadata.
+    pub metadata: Option<Irc27Metadata>,
+}
+
+impl Account {
+    /// Mints the issuer NFT of a collection, whose address is set as issuer of the NFTs of the collection. Waits until
+    /// the transaction is included and syncs the account, so NFTs of the collection can be minted right away.
+    /// ```ignore
+    /// let issuer_nft_id = account
+    ///     .mint_collection_issuer(
+    ///         Irc27Metadata::new("image/png", "https://example.com/collection.png", "Shimmer OG")
+    ///             .with_royalty(artist_address, 0.05),
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn mint_collection_issuer(
+        &self,
+        metadata: Irc27Metadata,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<NftId> {
+        log::debug!("[TRANSACTION] mint_collection_issuer");
+        self.validate_collection_metadata(&metadata)
+            .await
+            .map_err(|e| Error::Irc27Metadata(format!("issuer: {e}")))?;
+
+        let transaction = self
+            .mint_nfts(
+                [MintNftParams::new().with_immutable_metadata(metadata.to_bytes()?)],
+                options,
+            )
+            .await?;
+        self.retry_transaction_until_included(&transaction.transaction_id, None, None)
+            .await?;
+        self.sync(None).await?;
+
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        for (index, output) in essence.outputs().iter().enumerate() {
+            if let Output::Nft(nft_output) = output {
+                // The id of a new NFT is only known after the transaction was created
+                if nft_output.nft_id().is_null() {
+                    return Ok(NftId::from(&OutputId::new(transaction.transaction_id, index as u16)?));
+                }
+            }
+        }
+
+        Err(Error::MintingFailed(format!(
+            "no NFT minted in transaction {}",
+            transaction.transaction_id
+        )))
+    }
+
+    /// Mints NFTs of a collection with the issuer NFT set as issuer, which has to be owned by the account. The
+    /// metadata of all NFTs is validated first, then they are split into as many transactions as needed to stay within
+    /// the output and block size limits. Every transaction is awaited and the account synced before the next one. If a
+    /// batch fails after others were minted, [`Error::CollectionMintingFailed`] contains the transactions and the
+    /// number of minted NFTs, so the remaining ones can be minted again.
+    pub async fn mint_collection_nfts(
+        &self,
+        issuer_nft_id: NftId,
+        nfts: Vec<CollectionNftParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] mint_collection_nfts {issuer_nft_id}");
+        let options = options.into();
+
+        if self.unspent_nft_output(&issuer_nft_id).await?.is_none() {
+            return Err(Error::NftNotFoundInUnspentOutputs);
+        }
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let issuer = Bech32Address::new(bech32_hrp, NftAddress::new(issuer_nft_id));
+
+        let mut mint_params = Vec::with_capacity(nfts.len());
+        for (index, nft) in nfts.into_iter().enumerate() {
+            self.validate_collection_metadata(&nft.metadata)
+                .await
+                .map_err(|e| Error::Irc27Metadata(format!("nft {}: {e}", index + 1)))?;
+            let metadata = nft.metadata.to_bytes()?;
+            if metadata.len() > *MetadataFeature::LENGTH_RANGE.end() as usize {
+                return Err(Error::Irc27Metadata(format!(
+                    "nft {}: {} bytes exceed the maximum metadata length",
+                    index + 1,
+                    metadata.len()
+                )));
+            }
+
+            mint_params.push(
+                MintNftParams::new()
+                    .with_address(nft.address)
+                    .with_issuer(issuer)
+                    .with_immutable_metadata(metadata),
+            );
+        }
+        let batches = mint_batches(mint_params);
+
+        let mut transactions = Vec::with_capacity(batches.len());
+        let mut minted_count = 0;
+        for (index, batch) in batches.into_iter().enumerate() {
+            log::debug!("[TRANSACTION] minting {} collection NFTs in batch {index}", batch.len());
+            let batch_len = batch.len();
+            let result = async {
+                let transaction = self.mint_nfts(batch, options.clone()).await?;
+                let transaction_id = transaction.transaction_id;
+                transactions.push(transaction);
+                minted_count += batch_len;
+                self.retry_transaction_until_included(&transaction_id, None, None)
+                    .await?;
+                // Sync so the issuer NFT and the remainder are available for the next batch
+                self.sync(None).await?;
+                Ok::<_, Error>(())
+            }
+            .await;
+
+            if let Err(error) = result {
+                // Nothing was minted, so there is nothing to report besides the error
+                if transactions.is_empty() {
+                    return Err(error);
+                }
+                return Err(Error::CollectionMintingFailed {
+                    minted_count,
+                    transactions,
+                    error: Box::new(error),
+                });
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    /// Lists the unspent NFTs of a collection, found with the `issuer` query of the indexer.
+    pub async fn collection_nfts(&self, issuer_nft_id: NftId) -> crate::wallet::Result<Vec<CollectionNft>> {
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let issuer = Bech32Address::new(bech32_hrp, NftAddress::new(issuer_nft_id));
+
+        let output_ids = self
+            .client()
+            .nft_output_ids([QueryParameter::Issuer(issuer)])
+            .await?
+            .items;
+        // Outputs spent after the query are skipped
+        let outputs = self.client().get_outputs_ignore_errors(&output_ids).await?;
+
+        Ok(outputs
+            .iter()
+            .filter_map(|output_with_metadata| {
+                let Output::Nft(nft_output) = output_with_metadata.output() else {
+                    return None;
+                };
+                let output_id = *output_with_metadata.metadata().output_id();
+
+                Some(CollectionNft {
+                    nft_id: nft_output.nft_id_non_null(&output_id),
+                    output_id,
+                    address: Bech32Address::new(bech32_hrp, *nft_output.address()),
+                    metadata: nft_output
+                        .immutable_features()
+                        .metadata()
+                        .and_then(|metadata| Irc27Metadata::from_bytes(metadata.data()).ok()),
+                })
+            })
+            .collect())
+    }
+
+    // Validates the metadata and that the royalty addresses are for the network of the account
+    async fn validate_collection_metadata(&self, metadata: &Irc27Metadata) -> Result<(), String> {
+        metadata.check()?;
+        for address in metadata.royalties.keys() {
+            // PANIC: the addresses were validated above.
+            let address = Bech32Address::try_from_str(address).unwrap();
+            self.client()
+                .bech32_hrp_matches(address.hrp())
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+}
+
+// Splits the NFTs into batches which stay within the output count and metadata length limits of a transaction
+fn mint_batches(nfts: Vec<MintNftParams>) -> Vec<Vec<MintNftParams>> {
+    let mut batches: Vec<Vec<MintNftParams>> = Vec::new();
+    let mut batch_metadata_length = 0;
+    for nft in nfts {
+        let metadata_length = nft.immutable_metadata().as_ref().map_or(0, Vec::len);
+        let batch_full = batches.last().map_or(true, |batch| {
+            batch.len() == MINT_OUTPUT_COUNT_MAX || batch_metadata_length + metadata_length > MINT_METADATA_LENGTH_MAX
+        });
+        if batch_full {
+            batches.push(Vec::new());
+            batch_metadata_length = 0;
+        }
+        batch_metadata_length += metadata_length;
+        // PANIC: a batch was pushed above if there was none.
+        batches.last_mut().unwrap().push(nft);
+    }
+
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch_lengths(metadata_lengths: impl IntoIterator<Item = usize>) -> Vec<usize> {
+        let nfts = metadata_lengths
+            .into_iter()
+            .map(|length| MintNftParams::new().with_immutable_metadata(vec![0; length]))
+            .collect();
+
+        mint_batches(nfts).iter().map(Vec::len).collect()
+    }
+
+    #[test]
+    fn mint_batches_within_limits() {
+        assert!(batch_lengths([]).is_empty());
+
+        // Limited by the output count
+        assert_eq!(
+            batch_lengths(vec![10; 2 * MINT_OUTPUT_COUNT_MAX + 1]),
+            [MINT_OUTPUT_COUNT_MAX, MINT_OUTPUT_COUNT_MAX, 1]
+        );
+
+        // Limited by the metadata length, a batch can be filled exactly
+        let metadata_length_max = *MetadataFeature::LENGTH_RANGE.end() as usize;
+        let nfts_per_batch = MINT_METADATA_LENGTH_MAX / metadata_length_max;
+        assert_eq!(
+            batch_lengths(vec![metadata_length_max; nfts_per_batch + 1]),
+            [nfts_per_batch, 1]
+        );
+        assert_eq!(
+            batch_lengths([metadata_length_max, MINT_METADATA_LENGTH_MAX - metadata_length_max, 1]),
+            [2, 1]
+        );
+    }
+}
@@ -1,10 +1,14 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "storage")]
+pub(crate) mod batch_payout;
 pub(crate) mod burning_melting;
 pub(crate) mod create_alias;
 pub(crate) mod minimum_storage_deposit;
 pub(crate) mod minting;
+pub(crate) mod replace_transaction;
 pub(crate) mod send_amount;
 pub(crate) mod send_native_tokens;
 pub(crate) mod send_nft;
+pub(crate) mod transaction_plan;
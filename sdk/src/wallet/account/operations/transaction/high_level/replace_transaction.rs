@@ -0,0 +1,324 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{TransactionInclusionEvent, WalletEvent};
+use crate::{
+    types::block::{
+        input::Input,
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Output, OutputId},
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
+    wallet::{
+        account::{
+            types::{InclusionState, Transaction},
+            Account, AccountDetails, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+impl Account {
+    /// Cancels a pending transaction by replacing it with a conflicting transaction that spends the same inputs and
+    /// sends their funds back to the account. The cancelled transaction is marked as
+    /// [`InclusionState::Conflicting`] once the replacement got confirmed.
+    /// ```ignore
+    /// let transaction = account.cancel_transaction(&stuck_transaction_id, None).await?;
+    /// println!("Replacement transaction created: {}", transaction.transaction_id);
+    /// ```
+    pub async fn cancel_transaction(
+        &self,
+        transaction_id: &TransactionId,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] cancel_transaction");
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+
+        let address = {
+            let account_details = self.details().await;
+            let replaceable_inputs = replaceable_inputs(&account_details, transaction_id)?;
+            // PANIC: there is at least one replaceable input and all of them are unspent outputs of the account.
+            account_details.unspent_outputs[&replaceable_inputs[0]].address
+        };
+
+        // The remainder takes the rest of the replaced inputs, so the output only needs the minimum amount.
+        let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)?;
+
+        self.replace_transaction(transaction_id, [output], options).await
+    }
+
+    /// Replaces a pending transaction with a conflicting transaction that spends all of its inputs which are still
+    /// unspent and creates the provided outputs instead. The replaced transaction is not reattached anymore and is
+    /// marked as [`InclusionState::Conflicting`] once the replacement got confirmed.
+    pub async fn replace_transaction(
+        &self,
+        transaction_id: &TransactionId,
+        outputs: impl Into<Vec<Output>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] replace_transaction {transaction_id}");
+        let mut options = options.into().unwrap_or_default();
+
+        // The inputs stay locked by the transaction that gets replaced, so no other transaction can select them in the
+        // meantime, only the replacement is allowed to spend them.
+        let replaced_inputs = replaceable_inputs(&*self.details().await, transaction_id)?;
+        let allowed_locked_inputs = replaced_inputs.iter().copied().collect::<HashSet<_>>();
+
+        let mut mandatory_inputs = options.mandatory_inputs.take().unwrap_or_default();
+        for output_id in &replaced_inputs {
+            if !mandatory_inputs.contains(output_id) {
+                mandatory_inputs.push(*output_id);
+            }
+        }
+        options.mandatory_inputs = Some(mandatory_inputs);
+
+        let transaction = match self
+            .prepare_transaction_internal(outputs, options, true, &allowed_locked_inputs)
+            .await
+        {
+            Ok(prepared_transaction_data) => self.sign_and_submit_transaction(prepared_transaction_data).await,
+            Err(err) => Err(err),
+        };
+        let transaction = match transaction {
+            Ok(transaction) => transaction,
+            Err(err) => {
+                // Failed replacements can unlock the inputs, but the replaced transaction is still pending and needs
+                // them
+                let mut account_details = self.details_mut().await;
+                for output_id in replaced_inputs {
+                    if account_details.unspent_outputs.contains_key(&output_id) {
+                        account_details.locked_outputs.insert(output_id);
+                    }
+                }
+                return Err(err);
+            }
+        };
+
+        let mut account_details = self.details_mut().await;
+        account_details
+            .replaced_transactions
+            .insert(*transaction_id, transaction.transaction_id);
+        #[cfg(feature = "storage")]
+        {
+            log::debug!("[TRANSACTION] storing account {}", account_details.index());
+            self.save(Some(&account_details)).await?;
+        }
+
+        Ok(transaction)
+    }
+
+    /// Resolves replaced transactions after their replacements got a final inclusion state.
+    /// Transactions replaced by a confirmed transaction are conflicting and their remaining inputs are released.
+    /// Transactions whose replacement failed are pending again and get their inputs locked again.
+    pub(crate) async fn resolve_replaced_transactions(
+        &self,
+        account_details: &mut AccountDetails,
+        replacements: &[(TransactionId, InclusionState)],
+    ) {
+        #[cfg_attr(not(feature = "events"), allow(unused_variables))]
+        let conflicting_transactions = resolve_replacements(account_details, replacements);
+
+        #[cfg(feature = "events")]
+        for transaction_id in conflicting_transactions {
+            self.emit(
+                account_details.index,
+                WalletEvent::TransactionInclusion(TransactionInclusionEvent {
+                    transaction_id,
+                    inclusion_state: InclusionState::Conflicting,
+                }),
+            )
+            .await;
+        }
+    }
+}
+
+// Marks the transactions replaced by confirmed replacements as conflicting and releases their remaining inputs, and
+// makes the transactions whose replacement failed pending again, returns the conflicting transactions
+fn resolve_replacements(
+    account_details: &mut AccountDetails,
+    replacements: &[(TransactionId, InclusionState)],
+) -> Vec<TransactionId> {
+    let mut conflicting_transactions = Vec::new();
+    // Replacements always spend all inputs of the replaced transaction, so a transaction that replaced a now
+    // conflicting transaction can't get confirmed anymore either.
+    let mut superseding_transactions = replacements
+        .iter()
+        .filter(|(_, inclusion_state)| *inclusion_state == InclusionState::Confirmed)
+        .map(|(transaction_id, _)| *transaction_id)
+        .collect::<Vec<_>>();
+
+    while let Some(superseding_transaction_id) = superseding_transactions.pop() {
+        for transaction_id in replaced_by(account_details, &superseding_transaction_id) {
+            account_details.replaced_transactions.remove(&transaction_id);
+            account_details.pending_transactions.remove(&transaction_id);
+
+            if let Some(transaction) = account_details.transactions.get_mut(&transaction_id) {
+                transaction.inclusion_state = InclusionState::Conflicting;
+                let inputs = transaction_inputs(transaction).collect::<Vec<_>>();
+                // Inputs which weren't spent by the replacement are available again
+                for output_id in inputs {
+                    if account_details.unspent_outputs.contains_key(&output_id) {
+                        account_details.locked_outputs.remove(&output_id);
+                    }
+                }
+            }
+            log::debug!("[SYNC] replaced transaction {transaction_id} is conflicting");
+
+            conflicting_transactions.push(transaction_id);
+            superseding_transactions.push(transaction_id);
+        }
+    }
+
+    for (replacement_id, _) in replacements
+        .iter()
+        .filter(|(_, inclusion_state)| *inclusion_state != InclusionState::Confirmed)
+    {
+        for transaction_id in replaced_by(account_details, replacement_id) {
+            // Not replaced anymore, so it gets synced and reattached like any other pending transaction
+            account_details.replaced_transactions.remove(&transaction_id);
+
+            if let Some(transaction) = account_details.transactions.get(&transaction_id) {
+                let inputs = transaction_inputs(transaction).collect::<Vec<_>>();
+                for output_id in inputs {
+                    if account_details.unspent_outputs.contains_key(&output_id) {
+                        account_details.locked_outputs.insert(output_id);
+                    }
+                }
+            }
+            log::debug!("[SYNC] replacement {replacement_id} of transaction {transaction_id} failed");
+        }
+    }
+
+    conflicting_transactions
+}
+
+// Returns the inputs of a transaction that can be spent by a replacement transaction
+fn replaceable_inputs(
+    account_details: &AccountDetails,
+    transaction_id: &TransactionId,
+) -> crate::wallet::Result<Vec<OutputId>> {
+    let transaction = account_details
+        .transactions
+        .get(transaction_id)
+        .ok_or(Error::TransactionNotFound(*transaction_id))?;
+
+    if transaction.incoming {
+        return Err(Error::TransactionNotReplaceable(
+            *transaction_id,
+            "it was not sent from this account",
+        ));
+    }
+    if transaction.inclusion_state != InclusionState::Pending {
+        return Err(Error::TransactionNotReplaceable(*transaction_id, "it is not pending"));
+    }
+    if account_details.replaced_transactions.contains_key(transaction_id) {
+        return Err(Error::TransactionNotReplaceable(
+            *transaction_id,
+            "it was already replaced",
+        ));
+    }
+
+    let inputs = transaction_inputs(transaction)
+        .filter(|output_id| account_details.unspent_outputs.contains_key(output_id))
+        .collect::<Vec<_>>();
+
+    if inputs.is_empty() {
+        return Err(Error::TransactionNotReplaceable(
+            *transaction_id,
+            "none of its inputs is unspent",
+        ));
+    }
+
+    Ok(inputs)
+}
+
+// Returns the transactions that got replaced by the provided transaction
+fn replaced_by(account_details: &AccountDetails, replacement_id: &TransactionId) -> Vec<TransactionId> {
+    account_details
+        .replaced_transactions
+        .iter()
+        .filter(|(_, replacement)| *replacement == replacement_id)
+        .map(|(transaction_id, _)| *transaction_id)
+        .collect()
+}
+
+fn transaction_inputs(transaction: &Transaction) -> impl Iterator<Item = OutputId> + '_ {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    essence.inputs().iter().filter_map(|input| match input {
+        Input::Utxo(input) => Some(*input.output_id()),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{types::block::protocol::protocol_parameters, wallet::account::types::OutputData};
+
+    #[test]
+    fn resolve_replaced_transactions() {
+        let mut account_details = AccountDetails::mock();
+        let address = *account_details.public_addresses[0].address.as_ref();
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap();
+        let [spent_input, unspent_input, other_input] =
+            [0, 1, 2].map(|index| OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), index).unwrap());
+        for output_id in [unspent_input, other_input] {
+            account_details
+                .unspent_outputs
+                .insert(output_id, OutputData::mock(output_id, output.clone(), address));
+        }
+
+        // Replaced by a confirmed transaction, which only spent one of its inputs
+        let replaced = Transaction::mock(&[spent_input, unspent_input], vec![output.clone()]);
+        let replaced_id = replaced.transaction_id;
+        let confirmed_replacement_id = TransactionId::new([2; TransactionId::LENGTH]);
+        // Replaced by a transaction that didn't get confirmed, which released the input already
+        let not_replaced = Transaction::mock(&[other_input], vec![output]);
+        let not_replaced_id = not_replaced.transaction_id;
+        let failed_replacement_id = TransactionId::new([3; TransactionId::LENGTH]);
+
+        for (transaction, replacement_id) in [
+            (replaced, confirmed_replacement_id),
+            (not_replaced, failed_replacement_id),
+        ] {
+            account_details.pending_transactions.insert(transaction.transaction_id);
+            account_details
+                .replaced_transactions
+                .insert(transaction.transaction_id, replacement_id);
+            account_details
+                .transactions
+                .insert(transaction.transaction_id, transaction);
+        }
+        account_details.locked_outputs.insert(unspent_input);
+
+        let conflicting_transactions = resolve_replacements(
+            &mut account_details,
+            &[
+                (confirmed_replacement_id, InclusionState::Confirmed),
+                (failed_replacement_id, InclusionState::Conflicting),
+            ],
+        );
+
+        assert_eq!(conflicting_transactions, [replaced_id]);
+        assert_eq!(
+            account_details.transactions[&replaced_id].inclusion_state,
+            InclusionState::Conflicting
+        );
+        assert_eq!(
+            account_details.transactions[&not_replaced_id].inclusion_state,
+            InclusionState::Pending
+        );
+        assert_eq!(account_details.pending_transactions, HashSet::from([not_replaced_id]));
+        assert!(account_details.replaced_transactions.is_empty());
+        // The input that wasn't spent by the replacement is available again, the pending transaction needs its input
+        assert_eq!(account_details.locked_outputs, HashSet::from([other_input]));
+    }
+}
@@ -12,7 +12,7 @@ use crate::{
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
             },
-            BasicOutputBuilder,
+            BasicOutputBuilder, Output,
         },
         ConvertTo,
     },
@@ -21,6 +21,7 @@ use crate::{
             constants::DEFAULT_EXPIRATION_TIME,
             operations::transaction::{
                 high_level::minimum_storage_deposit::minimum_storage_deposit_basic_native_tokens, Transaction,
+                TransactionDryRun,
             },
             Account, TransactionOptions,
         },
@@ -120,6 +121,37 @@ impl Account {
     {
         log::debug!("[TRANSACTION] prepare_send_amount");
         let options = options.into();
+        let outputs = self.send_amount_outputs(params, options.as_ref()).await?;
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    /// Reports what [Account.send_amount()](crate::account::Account.send_amount) would do, without signing the
+    /// transaction or locking its inputs.
+    pub async fn dry_run_send_amount<I: IntoIterator<Item = SendAmountParams> + Send>(
+        &self,
+        params: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<TransactionDryRun>
+    where
+        I::IntoIter: Send,
+    {
+        log::debug!("[TRANSACTION] dry_run_send_amount");
+        let options = options.into();
+        let outputs = self.send_amount_outputs(params, options.as_ref()).await?;
+
+        self.dry_run_transaction(outputs, options).await
+    }
+
+    // Create the basic outputs for the send amount params
+    async fn send_amount_outputs<I: IntoIterator<Item = SendAmountParams> + Send>(
+        &self,
+        params: I,
+        options: Option<&TransactionOptions>,
+    ) -> crate::wallet::Result<Vec<Output>>
+    where
+        I::IntoIter: Send,
+    {
         let rent_structure = self.client().get_rent_structure().await?;
         let token_supply = self.client().get_token_supply().await?;
 
@@ -175,7 +207,7 @@ impl Account {
                     token_supply,
                 )?;
 
-                if !options.as_ref().map(|o| o.allow_micro_amount).unwrap_or_default() {
+                if !options.map(|o| o.allow_micro_amount).unwrap_or_default() {
                     return Err(Error::InsufficientFunds {
                         available: amount,
                         required: amount + storage_deposit_amount,
@@ -202,6 +234,6 @@ impl Account {
             }
         }
 
-        self.prepare_transaction(outputs, options).await
+        Ok(outputs)
     }
 }
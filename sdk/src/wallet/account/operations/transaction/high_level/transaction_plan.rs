@@ -0,0 +1,585 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{TransactionPlanStepProgress, TransactionProgressEvent, WalletEvent};
+use crate::{
+    client::{api::input_selection::Error as InputSelectionError, node_api::error::Error as NodeApiError},
+    types::{
+        api::core::{dto::LedgerInclusionStateDto, response::BlockMetadataResponse},
+        block::{
+            input::INPUT_COUNT_MAX,
+            output::{dto::OutputMetadataDto, Output, OutputId, OUTPUT_COUNT_MAX},
+            payload::transaction::{TransactionId, TransactionPayload},
+        },
+    },
+    wallet::{
+        account::{
+            types::{InclusionState, Transaction},
+            Account, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+/// The maximum number of outputs sent in a single step, one output is kept free for the remainder.
+pub(crate) const STEP_OUTPUT_COUNT_MAX: usize = OUTPUT_COUNT_MAX as usize - 1;
+
+/// A sequence of transactions to send outputs that don't fit into a single transaction, either because there are more
+/// outputs than allowed or because more inputs than allowed would be needed to fund them.
+/// The plan starts with consolidation steps, followed by the batched sends. Each step spends the remainder of the
+/// previous one, so a step is only executed once the previous one is confirmed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionPlan {
+    /// The steps of the plan, executed in order.
+    pub steps: Vec<TransactionPlanStep>,
+    /// The options used for the send steps.
+    pub options: Option<TransactionOptions>,
+}
+
+/// A step of a [`TransactionPlan`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionPlanStep {
+    /// What the step does.
+    pub kind: TransactionPlanStepKind,
+    /// The id of the transaction of the step, set as soon as the transaction is signed.
+    pub transaction_id: Option<TransactionId>,
+    /// The signed transaction of the step, stored before it's submitted so the same transaction can be submitted again
+    /// if it gets lost, instead of sending the outputs with other inputs.
+    #[serde(default)]
+    pub payload: Option<TransactionPayload>,
+    /// The inputs of the transaction of the step.
+    pub inputs: Vec<OutputId>,
+    /// Whether the step is done.
+    pub confirmed: bool,
+}
+
+/// The kind of a [`TransactionPlanStep`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "outputs")]
+pub enum TransactionPlanStepKind {
+    /// Consolidates outputs of the account, to reduce the number of inputs needed by the following steps.
+    Consolidation,
+    /// Sends a batch of outputs.
+    Send(Vec<Output>),
+}
+
+impl TransactionPlanStep {
+    fn new(kind: TransactionPlanStepKind) -> Self {
+        Self {
+            kind,
+            transaction_id: None,
+            payload: None,
+            inputs: Vec::new(),
+            confirmed: false,
+        }
+    }
+}
+
+impl TransactionPlan {
+    /// Plans the steps needed to send the outputs, given the amounts of the outputs available as inputs.
+    pub fn new(
+        outputs: Vec<Output>,
+        options: impl Into<Option<TransactionOptions>>,
+        available_amounts: impl IntoIterator<Item = u64>,
+    ) -> Self {
+        let required_amount = outputs.iter().map(Output::amount).sum::<u64>();
+        let mut available_amounts = available_amounts.into_iter().collect::<Vec<_>>();
+        // Sort high to low, to get the lowest number of inputs that can fund the outputs.
+        available_amounts.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut input_count = 0;
+        let mut input_amount = 0;
+        for amount in available_amounts {
+            if input_amount >= required_amount {
+                break;
+            }
+            input_amount += amount;
+            input_count += 1;
+        }
+
+        // Every consolidation replaces up to INPUT_COUNT_MAX outputs by a single one.
+        let input_count_max = INPUT_COUNT_MAX as usize;
+        let consolidation_count =
+            (input_count.saturating_sub(input_count_max) + input_count_max - 2) / (input_count_max - 1);
+
+        let mut steps = vec![TransactionPlanStep::new(TransactionPlanStepKind::Consolidation); consolidation_count];
+        steps.extend(
+            outputs
+                .chunks(STEP_OUTPUT_COUNT_MAX)
+                .map(|batch| TransactionPlanStep::new(TransactionPlanStepKind::Send(batch.to_vec()))),
+        );
+
+        Self {
+            steps,
+            options: options.into(),
+        }
+    }
+
+    /// Returns `true` if all steps of the plan are confirmed.
+    pub fn is_finished(&self) -> bool {
+        self.steps.iter().all(|step| step.confirmed)
+    }
+}
+
+impl Account {
+    /// Sends outputs that may not fit into a single transaction, by planning and executing a sequence of
+    /// consolidation and send transactions. The plan is stored in the account and can be resumed with
+    /// [`Account::resume_transaction_plan()`] if it gets interrupted.
+    /// Returns the transactions of all steps.
+    pub async fn send_with_plan(
+        &self,
+        outputs: impl Into<Vec<Output>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        let transaction_plan = self.prepare_transaction_plan(outputs, options).await?;
+
+        let mut account_details = self.details_mut().await;
+        if account_details.transaction_plan.is_some() {
+            return Err(Error::TransactionPlanPending);
+        }
+        account_details.transaction_plan = Some(transaction_plan);
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        drop(account_details);
+
+        self.resume_transaction_plan().await
+    }
+
+    /// Function to prepare the plan for [`Account::send_with_plan()`]
+    pub async fn prepare_transaction_plan(
+        &self,
+        outputs: impl Into<Vec<Output>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<TransactionPlan> {
+        log::debug!("[TRANSACTION] prepare_transaction_plan");
+        let outputs = outputs.into();
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
+
+        // Check if the outputs have enough amount to cover the storage deposit
+        for output in &outputs {
+            output.verify_storage_deposit(
+                *protocol_parameters.rent_structure(),
+                protocol_parameters.token_supply(),
+            )?;
+        }
+
+        let account_details = self.details().await;
+        let available_amounts = account_details
+            .unspent_outputs
+            .values()
+            .filter(|output_data| {
+                !account_details.locked_outputs.contains(&output_data.output_id)
+                    // Basic outputs always have an address unlock condition, only use the ones without other unlock
+                    // conditions
+                    && output_data.output.is_basic()
+                    && output_data.output.as_basic().unlock_conditions().len() == 1
+            })
+            .map(|output_data| output_data.output.amount());
+
+        Ok(TransactionPlan::new(outputs, options, available_amounts))
+    }
+
+    /// Executes the remaining steps of the transaction plan stored in the account, if there is one. Steps whose
+    /// transaction was already submitted are awaited instead of being sent again. If the transaction of a step is
+    /// unknown to the account or pruned, and the node has no included block with it, the same transaction is submitted
+    /// again as long as its inputs are unspent. A step is only executed again with other inputs once its transaction
+    /// conflicts, so it can't be confirmed anymore.
+    /// Returns the transactions of the steps executed or awaited by this call.
+    pub async fn resume_transaction_plan(&self) -> crate::wallet::Result<Vec<Transaction>> {
+        let mut transaction_plan = match self.details().await.transaction_plan.clone() {
+            Some(transaction_plan) => transaction_plan,
+            None => return Ok(Vec::new()),
+        };
+        let mut transactions = Vec::new();
+
+        while let Some(step_index) = transaction_plan.steps.iter().position(|step| !step.confirmed) {
+            let transaction_id = match transaction_plan.steps[step_index].transaction_id {
+                Some(transaction_id) => transaction_id,
+                None => {
+                    match self
+                        .execute_transaction_plan_step(&mut transaction_plan, step_index)
+                        .await?
+                    {
+                        Some(transaction_id) => transaction_id,
+                        None => continue,
+                    }
+                }
+            };
+
+            let transaction = self.details().await.transactions().get(&transaction_id).cloned();
+
+            match transaction {
+                // The transaction was signed, but is unknown to the account, so it may or may not have been sent, or it
+                // was pruned. The outputs must not be sent twice, so the step is only executed again with other inputs
+                // once the transaction can't be confirmed anymore.
+                transaction
+                    if transaction.as_ref().map_or(true, |transaction| {
+                        matches!(
+                            transaction.inclusion_state,
+                            InclusionState::Conflicting | InclusionState::UnknownPruned
+                        )
+                    }) =>
+                {
+                    let state = match &transaction {
+                        Some(transaction) if transaction.inclusion_state == InclusionState::Conflicting => {
+                            NodeTransactionState::Conflicting
+                        }
+                        _ => {
+                            self.transaction_plan_step_state(&transaction_plan.steps[step_index])
+                                .await?
+                        }
+                    };
+
+                    match state {
+                        NodeTransactionState::Included => {
+                            // Sync so the remainder of the step is available as input for the next one
+                            self.sync(None).await?;
+                            transaction_plan.steps[step_index].confirmed = true;
+                        }
+                        NodeTransactionState::Conflicting => {
+                            log::debug!(
+                                "[TRANSACTION] transaction plan step {step_index} {transaction_id} conflicts, \
+                                 executing it again"
+                            );
+                            self.reset_transaction_plan_step(&mut transaction_plan, step_index)
+                                .await;
+                        }
+                        NodeTransactionState::NotFound => {
+                            let payload = transaction
+                                .map(|transaction| transaction.payload)
+                                .or_else(|| transaction_plan.steps[step_index].payload.clone())
+                                .ok_or(Error::TransactionPlanStepUnknown(transaction_id))?;
+                            log::debug!(
+                                "[TRANSACTION] transaction plan step {step_index} {transaction_id} isn't included, \
+                                 submitting it again"
+                            );
+                            let block_id = self.submit_transaction_payload(payload).await?;
+                            // The state is checked again in the next iteration
+                            self.client().retry_until_included(&block_id, None, None).await?;
+                        }
+                        NodeTransactionState::Unknown => {
+                            return Err(Error::TransactionPlanStepUnknown(transaction_id));
+                        }
+                    }
+                }
+                Some(_) => {
+                    self.retry_transaction_until_included(&transaction_id, None, None)
+                        .await?;
+                    // Sync so the remainder of the step is available as input for the next one
+                    self.sync(None).await?;
+
+                    if let Some(transaction) = self.details().await.transactions().get(&transaction_id) {
+                        transactions.push(transaction.clone());
+                    }
+                    transaction_plan.steps[step_index].confirmed = true;
+                }
+            }
+
+            self.update_transaction_plan(Some(transaction_plan.clone())).await?;
+        }
+
+        self.update_transaction_plan(None).await?;
+
+        Ok(transactions)
+    }
+
+    /// Signs and submits the transaction of a step, returns its id or `None` if the step had nothing to do.
+    async fn execute_transaction_plan_step(
+        &self,
+        transaction_plan: &mut TransactionPlan,
+        step_index: usize,
+    ) -> crate::wallet::Result<Option<TransactionId>> {
+        log::debug!(
+            "[TRANSACTION] executing transaction plan step {}/{}",
+            step_index + 1,
+            transaction_plan.steps.len()
+        );
+        #[cfg(feature = "events")]
+        {
+            let account_index = self.details().await.index;
+            self.emit(
+                account_index,
+                WalletEvent::TransactionProgress(TransactionProgressEvent::TransactionPlanStep(
+                    TransactionPlanStepProgress {
+                        step: step_index,
+                        step_count: transaction_plan.steps.len(),
+                        consolidation: transaction_plan.steps[step_index].kind
+                            == TransactionPlanStepKind::Consolidation,
+                    },
+                )),
+            )
+            .await;
+        }
+
+        let prepared_transaction_data = match &transaction_plan.steps[step_index].kind {
+            TransactionPlanStepKind::Consolidation => match self.prepare_consolidate_outputs(true, None).await {
+                Ok(prepared_transaction_data) => prepared_transaction_data,
+                Err(Error::NoOutputsToConsolidate { .. }) => {
+                    transaction_plan.steps[step_index].confirmed = true;
+                    self.update_transaction_plan(Some(transaction_plan.clone())).await?;
+                    return Ok(None);
+                }
+                Err(err) => return Err(err),
+            },
+            TransactionPlanStepKind::Send(outputs) => {
+                match self
+                    .prepare_transaction(outputs.clone(), transaction_plan.options.clone())
+                    .await
+                {
+                    Ok(prepared_transaction_data) => prepared_transaction_data,
+                    Err(Error::Client(err))
+                        if matches!(
+                            *err,
+                            crate::client::Error::InputSelection(InputSelectionError::InvalidInputCount(_))
+                        ) =>
+                    {
+                        // Don't plan another consolidation if the previous one had nothing to consolidate
+                        let nothing_consolidated = step_index
+                            .checked_sub(1)
+                            .map(|previous_index| &transaction_plan.steps[previous_index])
+                            .map_or(false, |previous_step| {
+                                previous_step.kind == TransactionPlanStepKind::Consolidation
+                                    && previous_step.transaction_id.is_none()
+                            });
+                        if nothing_consolidated {
+                            return Err(Error::Client(err));
+                        }
+                        log::debug!("[TRANSACTION] too many inputs needed, adding a consolidation step to the plan");
+                        transaction_plan.steps.insert(
+                            step_index,
+                            TransactionPlanStep::new(TransactionPlanStepKind::Consolidation),
+                        );
+                        self.update_transaction_plan(Some(transaction_plan.clone())).await?;
+                        return Ok(None);
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+        };
+
+        let signed_transaction_data = match self.sign_transaction_essence(&prepared_transaction_data).await {
+            Ok(res) => res,
+            Err(err) => {
+                // unlock outputs so they are available for a new transaction
+                self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+                return Err(err);
+            }
+        };
+        let transaction_id = signed_transaction_data.transaction_payload.id();
+
+        // Store the transaction id before submitting the transaction, so the step isn't sent twice if the wallet
+        // stops in between
+        transaction_plan.steps[step_index].transaction_id = Some(transaction_id);
+        transaction_plan.steps[step_index].payload = Some(signed_transaction_data.transaction_payload.clone());
+        transaction_plan.steps[step_index].inputs = signed_transaction_data
+            .inputs_data
+            .iter()
+            .map(|input| *input.output_id())
+            .collect();
+        self.update_transaction_plan(Some(transaction_plan.clone())).await?;
+
+        self.submit_and_store_transaction(signed_transaction_data).await?;
+
+        Ok(Some(transaction_id))
+    }
+
+    /// Clears the transaction of a step and unlocks its inputs, so the step can be executed again.
+    async fn reset_transaction_plan_step(&self, transaction_plan: &mut TransactionPlan, step_index: usize) {
+        let step = &mut transaction_plan.steps[step_index];
+        let mut account_details = self.details_mut().await;
+
+        for output_id in step.inputs.drain(..) {
+            account_details.locked_outputs.remove(&output_id);
+        }
+        step.transaction_id = None;
+        step.payload = None;
+    }
+
+    // Returns the state of the transaction of a step that is unknown to the account or pruned, according to the node.
+    async fn transaction_plan_step_state(
+        &self,
+        step: &TransactionPlanStep,
+    ) -> crate::wallet::Result<NodeTransactionState> {
+        // PANIC: only called for steps with a transaction.
+        let transaction_id = step.transaction_id.unwrap();
+        let included_block_metadata = self.client().get_included_block_metadata(&transaction_id).await;
+
+        Ok(match node_transaction_state(included_block_metadata)? {
+            NodeTransactionState::NotFound => {
+                // Also not found if the transaction isn't included yet or its block was pruned, so the inputs decide
+                let inputs_metadata = self.client().get_outputs_metadata_ignore_errors(&step.inputs).await?;
+                unincluded_transaction_state(&transaction_id, step.inputs.len(), &inputs_metadata)
+            }
+            state => state,
+        })
+    }
+
+    async fn update_transaction_plan(&self, transaction_plan: Option<TransactionPlan>) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        account_details.transaction_plan = transaction_plan;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+}
+
+/// The state of a transaction according to the node.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum NodeTransactionState {
+    /// The transaction is included in the ledger.
+    Included,
+    /// The transaction is referenced by a milestone, but conflicts with the ledger, or its inputs were spent by
+    /// another transaction.
+    Conflicting,
+    /// The node doesn't know an included block with the transaction, but its inputs are unspent, so it can still be
+    /// confirmed.
+    NotFound,
+    /// Inputs of the transaction were pruned, so it's unknown whether they were spent by it.
+    Unknown,
+}
+
+// Maps the response of the included block metadata route to the state of the transaction. Other errors than a not
+// found are returned, since the transaction could still be included.
+pub(crate) fn node_transaction_state(
+    included_block_metadata: crate::client::Result<BlockMetadataResponse>,
+) -> crate::client::Result<NodeTransactionState> {
+    match included_block_metadata {
+        Ok(metadata) => Ok(match metadata.ledger_inclusion_state {
+            Some(LedgerInclusionStateDto::Conflicting) => NodeTransactionState::Conflicting,
+            _ => NodeTransactionState::Included,
+        }),
+        Err(crate::client::Error::Node(NodeApiError::NotFound(_))) => Ok(NodeTransactionState::NotFound),
+        Err(err) => Err(err),
+    }
+}
+
+// The state of a transaction without an included block on the node: it can only be confirmed as long as none of its
+// inputs is spent by another transaction.
+pub(crate) fn unincluded_transaction_state(
+    transaction_id: &TransactionId,
+    input_count: usize,
+    inputs_metadata: &[OutputMetadataDto],
+) -> NodeTransactionState {
+    let transaction_id = transaction_id.to_string();
+    let spent_by_transaction =
+        |metadata: &OutputMetadataDto| metadata.transaction_id_spent.as_ref() == Some(&transaction_id);
+
+    if inputs_metadata
+        .iter()
+        .any(|metadata| metadata.is_spent && !spent_by_transaction(metadata))
+    {
+        NodeTransactionState::Conflicting
+    } else if inputs_metadata.len() < input_count {
+        NodeTransactionState::Unknown
+    } else if inputs_metadata.iter().all(spent_by_transaction) {
+        NodeTransactionState::Included
+    } else {
+        NodeTransactionState::NotFound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn included_block_metadata(ledger_inclusion_state: &str) -> BlockMetadataResponse {
+        serde_json::from_value(serde_json::json!({
+            "blockId": "0x3b2d3e1cd0e0bc9d1e1b3b7dc4dfc4fd0a5ab8ea0ad2ec3ca9a5d1c4c2c4e9e5",
+            "parents": [],
+            "isSolid": true,
+            "referencedByMilestoneIndex": 10,
+            "ledgerInclusionState": ledger_inclusion_state,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn node_transaction_state_of_unknown_step_transaction() {
+        assert_eq!(
+            node_transaction_state(Ok(included_block_metadata("included"))).unwrap(),
+            NodeTransactionState::Included
+        );
+        assert_eq!(
+            node_transaction_state(Ok(included_block_metadata("conflicting"))).unwrap(),
+            NodeTransactionState::Conflicting
+        );
+        assert_eq!(
+            node_transaction_state(Err(crate::client::Error::Node(NodeApiError::NotFound(
+                "transaction".to_string()
+            ))))
+            .unwrap(),
+            NodeTransactionState::NotFound
+        );
+
+        // The node couldn't answer, so the step must not be executed again
+        assert!(
+            node_transaction_state(Err(crate::client::Error::Node(NodeApiError::ResponseError {
+                code: 503,
+                text: "service unavailable".to_string(),
+                url: "http://localhost:14265".to_string(),
+            })))
+            .is_err()
+        );
+        assert!(node_transaction_state(Err(crate::client::Error::HealthyNodePoolEmpty)).is_err());
+    }
+
+    #[test]
+    fn unincluded_transaction_state_from_inputs() {
+        let transaction_id = TransactionId::new([1; TransactionId::LENGTH]);
+        let other_transaction_id = TransactionId::new([2; TransactionId::LENGTH]);
+        let input_metadata = |spent_by: Option<&TransactionId>| OutputMetadataDto {
+            block_id: "0x3b2d3e1cd0e0bc9d1e1b3b7dc4dfc4fd0a5ab8ea0ad2ec3ca9a5d1c4c2c4e9e5".to_string(),
+            transaction_id: TransactionId::new([3; TransactionId::LENGTH]).to_string(),
+            output_index: 0,
+            is_spent: spent_by.is_some(),
+            milestone_index_spent: spent_by.map(|_| 11),
+            milestone_timestamp_spent: spent_by.map(|_| 1_000),
+            transaction_id_spent: spent_by.map(ToString::to_string),
+            milestone_index_booked: 10,
+            milestone_timestamp_booked: 900,
+            ledger_index: 11,
+        };
+
+        // Unspent inputs, the same transaction is submitted again
+        assert_eq!(
+            unincluded_transaction_state(&transaction_id, 2, &[input_metadata(None), input_metadata(None)]),
+            NodeTransactionState::NotFound
+        );
+        // Spent by the transaction, but its block was pruned
+        assert_eq!(
+            unincluded_transaction_state(
+                &transaction_id,
+                2,
+                &[
+                    input_metadata(Some(&transaction_id)),
+                    input_metadata(Some(&transaction_id))
+                ]
+            ),
+            NodeTransactionState::Included
+        );
+        // An input spent by another transaction, it can't be confirmed anymore
+        assert_eq!(
+            unincluded_transaction_state(
+                &transaction_id,
+                2,
+                &[input_metadata(None), input_metadata(Some(&other_transaction_id))]
+            ),
+            NodeTransactionState::Conflicting
+        );
+        assert_eq!(
+            unincluded_transaction_state(&transaction_id, 2, &[input_metadata(Some(&other_transaction_id))]),
+            NodeTransactionState::Conflicting
+        );
+        // A pruned input could have been spent by the transaction
+        assert_eq!(
+            unincluded_transaction_state(&transaction_id, 2, &[input_metadata(None)]),
+            NodeTransactionState::Unknown
+        );
+    }
+}
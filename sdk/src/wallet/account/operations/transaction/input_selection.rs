@@ -7,7 +7,10 @@ use std::collections::{hash_map::Values, HashSet};
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
 use crate::{
     client::{
-        api::input_selection::{is_alias_transition, Burn, InputSelection, Selected},
+        api::input_selection::{
+            is_alias_transition, Burn, Error as InputSelectionError, InputRejectionReason, InputSelection,
+            RejectedInput, Selected,
+        },
         secret::types::InputSigningData,
     },
     types::block::{
@@ -15,21 +18,37 @@ use crate::{
         output::{Output, OutputId},
     },
     wallet::account::{
-        operations::helpers::time::can_output_be_unlocked_forever_from_now_on, Account, AccountDetails, OutputData,
+        operations::{
+            helpers::time::can_output_be_unlocked_forever_from_now_on,
+            transaction::dependencies::unconfirmed_outputs_signing_data,
+        },
+        Account, AccountDetails, OutputData, TransactionOptions,
     },
 };
 
 impl Account {
-    /// Selects inputs for a transaction and locks them in the account, so they don't get used again
+    /// Selects inputs for a transaction with the input related transaction options and, if `lock_inputs` is set, locks
+    /// them in the account, so they don't get used again. Locked outputs are only selected if they're in
+    /// `allowed_locked_inputs`, which is used to spend the inputs of a transaction that gets replaced.
     pub(crate) async fn select_inputs(
         &self,
         outputs: Vec<Output>,
-        custom_inputs: Option<HashSet<OutputId>>,
-        mandatory_inputs: Option<HashSet<OutputId>>,
         remainder_address: Option<Address>,
-        burn: Option<&Burn>,
+        options: &TransactionOptions,
+        lock_inputs: bool,
+        allowed_locked_inputs: &HashSet<OutputId>,
     ) -> crate::wallet::Result<Selected> {
         log::debug!("[TRANSACTION] select_inputs");
+        let custom_inputs = options
+            .custom_inputs
+            .as_ref()
+            .map(|inputs| inputs.iter().copied().collect::<HashSet<_>>());
+        let mandatory_inputs = options
+            .mandatory_inputs
+            .as_ref()
+            .map(|inputs| inputs.iter().copied().collect::<HashSet<_>>());
+        let burn = options.burn.as_ref();
+        let diagnostics = options.input_selection_diagnostics;
         // Voting output needs to be requested before to prevent a deadlock
         #[cfg(feature = "participation")]
         let voting_output = self.get_voting_output().await?;
@@ -45,8 +64,13 @@ impl Account {
         .await;
 
         let current_time = self.client().get_time_checked().await?;
+        let locked_outputs = account_details
+            .locked_outputs
+            .difference(allowed_locked_inputs)
+            .copied()
+            .collect::<HashSet<_>>();
         #[allow(unused_mut)]
-        let mut forbidden_inputs = account_details.locked_outputs.clone();
+        let mut forbidden_inputs = locked_outputs.clone();
 
         let addresses = account_details
             .public_addresses()
@@ -68,7 +92,7 @@ impl Account {
 
         // Filter inputs to not include inputs that require additional outputs for storage deposit return or could be
         // still locked.
-        let available_outputs_signing_data = filter_inputs(
+        let (mut available_outputs_signing_data, filtered_inputs) = filter_inputs(
             &account_details,
             account_details.unspent_outputs.values(),
             current_time,
@@ -77,13 +101,23 @@ impl Account {
             custom_inputs.as_ref(),
             mandatory_inputs.as_ref(),
         )?;
+        if options.allow_unconfirmed_inputs {
+            available_outputs_signing_data
+                .extend(unconfirmed_outputs_signing_data(&account_details, &forbidden_inputs)?);
+        }
+        // Locked outputs come first, as that's the reason for them not being available in the first place
+        let rejected_inputs = locked_outputs
+            .iter()
+            .map(|output_id| RejectedInput::new(*output_id, InputRejectionReason::Locked))
+            .chain(filtered_inputs)
+            .collect::<Vec<_>>();
 
         // if custom inputs are provided we should only use them (validate if we have the outputs in this account and
         // that the amount is enough)
         if let Some(custom_inputs) = custom_inputs {
             // Check that no input got already locked
             for input in custom_inputs.iter() {
-                if account_details.locked_outputs.contains(input) {
+                if locked_outputs.contains(input) {
                     return Err(crate::wallet::Error::CustomInput(format!(
                         "provided custom input {input} is already used in another transaction",
                     )));
@@ -97,7 +131,10 @@ impl Account {
                 protocol_parameters.clone(),
             )
             .required_inputs(custom_inputs)
-            .forbidden_inputs(forbidden_inputs);
+            .forbidden_inputs(forbidden_inputs)
+            .coin_selection_strategy(options.coin_selection_strategy)
+            .remainder_split_strategy(options.remainder_split_strategy.clone())
+            .rejected_inputs(rejected_inputs);
 
             if let Some(address) = remainder_address {
                 input_selection = input_selection.remainder_address(address);
@@ -107,18 +144,20 @@ impl Account {
                 input_selection = input_selection.burn(burn.clone());
             }
 
-            let selected_transaction_data = input_selection.select()?;
+            let selected_transaction_data = select(input_selection, diagnostics)?;
 
             // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
+            if lock_inputs {
+                for output in &selected_transaction_data.inputs {
+                    account_details.locked_outputs.insert(*output.output_id());
+                }
             }
 
             return Ok(selected_transaction_data);
         } else if let Some(mandatory_inputs) = mandatory_inputs {
             // Check that no input got already locked
             for input in mandatory_inputs.iter() {
-                if account_details.locked_outputs.contains(input) {
+                if locked_outputs.contains(input) {
                     return Err(crate::wallet::Error::CustomInput(format!(
                         "provided custom input {input} is already used in another transaction",
                     )));
@@ -132,7 +171,10 @@ impl Account {
                 protocol_parameters.clone(),
             )
             .required_inputs(mandatory_inputs)
-            .forbidden_inputs(forbidden_inputs);
+            .forbidden_inputs(forbidden_inputs)
+            .coin_selection_strategy(options.coin_selection_strategy)
+            .remainder_split_strategy(options.remainder_split_strategy.clone())
+            .rejected_inputs(rejected_inputs);
 
             if let Some(address) = remainder_address {
                 input_selection = input_selection.remainder_address(address);
@@ -142,16 +184,20 @@ impl Account {
                 input_selection = input_selection.burn(burn.clone());
             }
 
-            let selected_transaction_data = input_selection.select()?;
+            let selected_transaction_data = select(input_selection, diagnostics)?;
 
             // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
+            if lock_inputs {
+                for output in &selected_transaction_data.inputs {
+                    account_details.locked_outputs.insert(*output.output_id());
+                }
             }
 
             // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
+            if lock_inputs {
+                for output in &selected_transaction_data.inputs {
+                    account_details.locked_outputs.insert(*output.output_id());
+                }
             }
 
             return Ok(selected_transaction_data);
@@ -163,7 +209,10 @@ impl Account {
             addresses,
             protocol_parameters.clone(),
         )
-        .forbidden_inputs(forbidden_inputs);
+        .forbidden_inputs(forbidden_inputs)
+        .coin_selection_strategy(options.coin_selection_strategy)
+        .remainder_split_strategy(options.remainder_split_strategy.clone())
+        .rejected_inputs(rejected_inputs);
 
         if let Some(address) = remainder_address {
             input_selection = input_selection.remainder_address(address);
@@ -173,7 +222,7 @@ impl Account {
             input_selection = input_selection.burn(burn.clone());
         }
 
-        let selected_transaction_data = match input_selection.select() {
+        let selected_transaction_data = match select(input_selection, diagnostics) {
             Ok(r) => r,
             // TODO this error doesn't exist with the new ISA
             // Err(crate::client::Error::ConsolidationRequired(output_count)) => {
@@ -191,15 +240,29 @@ impl Account {
         };
 
         // lock outputs so they don't get used by another transaction
-        for output in &selected_transaction_data.inputs {
-            log::debug!("[TRANSACTION] locking: {}", output.output_id());
-            account_details.locked_outputs.insert(*output.output_id());
+        if lock_inputs {
+            for output in &selected_transaction_data.inputs {
+                log::debug!("[TRANSACTION] locking: {}", output.output_id());
+                account_details.locked_outputs.insert(*output.output_id());
+            }
         }
 
         Ok(selected_transaction_data)
     }
 }
 
+// Runs the input selection and logs the trace of its requirement fulfilment if it fails and diagnostics are enabled
+fn select(input_selection: InputSelection, diagnostics: bool) -> Result<Selected, InputSelectionError> {
+    if !diagnostics {
+        return input_selection.select();
+    }
+
+    input_selection.select_with_diagnostics().map_err(|error| {
+        log::warn!("[TRANSACTION] input selection failed: {error}");
+        error.into()
+    })
+}
+
 /// Filter available outputs to only include outputs that don't have unlock conditions, that could create
 /// conflicting transactions or need a new output for the storage deposit return
 /// Also only include Alias, Nft and Foundry outputs, if a corresponding output with the same id exists in the output,
@@ -218,6 +281,8 @@ impl Account {
 /// | [Address, not expired Expiration, ...]              | no                |
 /// | [Address, StorageDepositReturn, ...]                | no                |
 /// | [Address, StorageDepositReturn, expired Expiration] | yes               |
+///
+/// Outputs that are not included are returned as rejected inputs.
 #[allow(clippy::too_many_arguments)]
 fn filter_inputs(
     account: &AccountDetails,
@@ -227,8 +292,9 @@ fn filter_inputs(
     burn: Option<&Burn>,
     custom_inputs: Option<&HashSet<OutputId>>,
     mandatory_inputs: Option<&HashSet<OutputId>>,
-) -> crate::wallet::Result<Vec<InputSigningData>> {
+) -> crate::wallet::Result<(Vec<InputSigningData>, Vec<RejectedInput>)> {
     let mut available_outputs_signing_data = Vec::new();
+    let mut rejected_inputs = Vec::new();
 
     for output_data in available_outputs {
         if !custom_inputs
@@ -248,6 +314,14 @@ fn filter_inputs(
 
             // Outputs that could get unlocked in the future will not be included
             if !output_can_be_unlocked_now_and_in_future {
+                let reason = match output_data.output.unlock_conditions() {
+                    None => InputRejectionReason::UnsupportedOutputType,
+                    Some(unlock_conditions) if unlock_conditions.is_time_locked(current_time) => {
+                        InputRejectionReason::Timelocked
+                    }
+                    Some(_) => InputRejectionReason::Expiration,
+                };
+                rejected_inputs.push(RejectedInput::new(output_data.output_id, reason));
                 continue;
             }
         }
@@ -257,8 +331,13 @@ fn filter_inputs(
 
         if let Some(available_input) = output_data.input_signing_data(account, current_time, alias_state_transition)? {
             available_outputs_signing_data.push(available_input);
+        } else {
+            rejected_inputs.push(RejectedInput::new(
+                output_data.output_id,
+                InputRejectionReason::NotOwned,
+            ));
         }
     }
 
-    Ok(available_outputs_signing_data)
+    Ok((available_outputs_signing_data, rejected_inputs))
 }
@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod build_transaction;
+pub(crate) mod dependencies;
+pub(crate) mod dry_run;
 pub(crate) mod high_level;
 mod input_selection;
 mod options;
@@ -10,7 +12,11 @@ mod prepare_transaction;
 mod sign_transaction;
 pub(crate) mod submit_transaction;
 
-pub use self::options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto};
+pub use self::{
+    dependencies::{TransactionDependency, TransactionReplan},
+    dry_run::{RemainderPlacement, TokenBalanceDelta, TransactionDryRun, TransactionDryRunDto},
+    options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto},
+};
 use crate::{
     client::{
         api::{verify_semantic, PreparedTransactionData, SignedTransactionData},
@@ -106,6 +112,10 @@ impl Account {
             Err(err) => {
                 // unlock outputs so they are available for a new transaction
                 self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+                self.unconfirmed_input_requests
+                    .lock()
+                    .await
+                    .remove(&prepared_transaction_data.essence.hash());
                 return Err(err);
             }
         };
@@ -155,6 +165,11 @@ impl Account {
         };
 
         let transaction_id = signed_transaction_data.transaction_payload.id();
+        let unconfirmed_input_request = self
+            .unconfirmed_input_requests
+            .lock()
+            .await
+            .remove(&signed_transaction_data.transaction_payload.essence().hash());
 
         // store transaction payload to account (with db feature also store the account to the db)
         let network_id = self.client().get_network_id().await?;
@@ -180,14 +195,22 @@ impl Account {
             inputs,
         };
 
-        let mut account_details = self.details_mut().await;
-
-        account_details.transactions.insert(transaction_id, transaction.clone());
-        account_details.pending_transactions.insert(transaction_id);
-        #[cfg(feature = "storage")]
         {
-            log::debug!("[TRANSACTION] storing account {}", account_details.index());
-            self.save(Some(&account_details)).await?;
+            let mut account_details = self.details_mut().await;
+
+            account_details.transactions.insert(transaction_id, transaction.clone());
+            account_details.pending_transactions.insert(transaction_id);
+            #[cfg(feature = "storage")]
+            {
+                log::debug!("[TRANSACTION] storing account {}", account_details.index());
+                self.save(Some(&account_details)).await?;
+            }
+        }
+
+        // Transactions which spend outputs of pending transactions need to be sent again if one of them conflicts
+        if let Some((outputs, options)) = unconfirmed_input_request {
+            self.track_transaction_dependency(&transaction, outputs, options)
+                .await?;
         }
 
         Ok(transaction)
@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::api::input_selection::{Burn, BurnDto},
+    client::api::input_selection::{Burn, BurnDto, CoinSelectionStrategy, RemainderSplitStrategy},
     types::block::{
         output::OutputId,
         payload::{dto::TaggedDataPayloadDto, tagged_data::TaggedDataPayload},
@@ -14,11 +14,14 @@ use crate::{
 };
 
 /// Options for transactions
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionOptions {
     #[serde(default)]
     pub remainder_value_strategy: RemainderValueStrategy,
+    // Split the remainder into multiple outputs, so they can be spent independently.
+    #[serde(default)]
+    pub remainder_split_strategy: RemainderSplitStrategy,
     #[serde(default)]
     pub tagged_data_payload: Option<TaggedDataPayload>,
     // If custom inputs are provided only they are used. If also other additional inputs should be used,
@@ -31,6 +34,15 @@ pub struct TransactionOptions {
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    #[serde(default)]
+    pub coin_selection_strategy: CoinSelectionStrategy,
+    // If input selection fails, log the trace of its requirement fulfilment. The error itself is returned unchanged.
+    #[serde(default)]
+    pub input_selection_diagnostics: bool,
+    // Also use outputs created by pending transactions of the account as inputs. If one of these transactions
+    // conflicts, the transaction is sent again with other inputs.
+    #[serde(default)]
+    pub allow_unconfirmed_inputs: bool,
 }
 
 impl TransactionOptions {
@@ -38,6 +50,7 @@ impl TransactionOptions {
     pub fn try_from_dto(value: &TransactionOptionsDto) -> Result<Self, Error> {
         Ok(Self {
             remainder_value_strategy: value.remainder_value_strategy.clone(),
+            remainder_split_strategy: value.remainder_split_strategy.clone(),
             tagged_data_payload: value
                 .tagged_data_payload
                 .as_ref()
@@ -48,6 +61,9 @@ impl TransactionOptions {
             burn: value.burn.as_ref().map(Burn::try_from).transpose()?,
             note: value.note.clone(),
             allow_micro_amount: value.allow_micro_amount,
+            coin_selection_strategy: value.coin_selection_strategy,
+            input_selection_diagnostics: value.input_selection_diagnostics,
+            allow_unconfirmed_inputs: value.allow_unconfirmed_inputs,
         })
     }
 }
@@ -58,6 +74,9 @@ impl TransactionOptions {
 pub struct TransactionOptionsDto {
     #[serde(default)]
     pub remainder_value_strategy: RemainderValueStrategy,
+    // Split the remainder into multiple outputs, so they can be spent independently.
+    #[serde(default)]
+    pub remainder_split_strategy: RemainderSplitStrategy,
     #[serde(default)]
     pub tagged_data_payload: Option<TaggedDataPayloadDto>,
     // If custom inputs are provided only they are used. If also other additional inputs should be used,
@@ -70,11 +89,20 @@ pub struct TransactionOptionsDto {
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    #[serde(default)]
+    pub coin_selection_strategy: CoinSelectionStrategy,
+    // If input selection fails, log the trace of its requirement fulfilment. The error itself is returned unchanged.
+    #[serde(default)]
+    pub input_selection_diagnostics: bool,
+    // Also use outputs created by pending transactions of the account as inputs. If one of these transactions
+    // conflicts, the transaction is sent again with other inputs.
+    #[serde(default)]
+    pub allow_unconfirmed_inputs: bool,
 }
 
 #[allow(clippy::enum_variant_names)]
 /// The strategy to use for the remainder value management when sending funds.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "strategy", content = "value")]
 pub enum RemainderValueStrategy {
     /// Keep the remainder value on the source address.
@@ -12,7 +12,7 @@ use crate::{
     client::api::PreparedTransactionData,
     types::block::{
         input::INPUT_COUNT_RANGE,
-        output::{Output, OUTPUT_COUNT_RANGE},
+        output::{Output, OutputId, OUTPUT_COUNT_RANGE},
     },
     wallet::account::{
         operations::transaction::{RemainderValueStrategy, TransactionOptions},
@@ -26,6 +26,19 @@ impl Account {
         &self,
         outputs: impl Into<Vec<Output>> + Send,
         options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        self.prepare_transaction_internal(outputs, options, true, &HashSet::new())
+            .await
+    }
+
+    /// Get inputs and build the transaction essence, the selected inputs are only locked if `lock_inputs` is set.
+    /// Locked outputs can't be selected, unless they're in `allowed_locked_inputs`.
+    pub(crate) async fn prepare_transaction_internal(
+        &self,
+        outputs: impl Into<Vec<Output>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+        lock_inputs: bool,
+        allowed_locked_inputs: &HashSet<OutputId>,
     ) -> crate::wallet::Result<PreparedTransactionData> {
         log::debug!("[TRANSACTION] prepare_transaction");
         let options = options.into();
@@ -97,19 +110,19 @@ impl Account {
             None => None,
         };
 
+        // Transactions which can spend outputs of pending transactions need to be sent again if one of them conflicts
+        let unconfirmed_input_request = options
+            .as_ref()
+            .filter(|options| lock_inputs && options.allow_unconfirmed_inputs)
+            .map(|options| (outputs.clone(), options.clone()));
+
         let selected_transaction_data = self
             .select_inputs(
                 outputs,
-                options
-                    .as_ref()
-                    .and_then(|options| options.custom_inputs.as_ref())
-                    .map(|inputs| HashSet::from_iter(inputs.clone())),
-                options
-                    .as_ref()
-                    .and_then(|options| options.mandatory_inputs.as_ref())
-                    .map(|inputs| HashSet::from_iter(inputs.clone())),
                 remainder_address,
-                options.as_ref().and_then(|options| options.burn.as_ref()),
+                options.as_ref().unwrap_or(&TransactionOptions::default()),
+                lock_inputs,
+                allowed_locked_inputs,
             )
             .await?;
 
@@ -120,11 +133,20 @@ impl Account {
             Ok(res) => res,
             Err(err) => {
                 // unlock outputs so they are available for a new transaction
-                self.unlock_inputs(&selected_transaction_data.inputs).await?;
+                if lock_inputs {
+                    self.unlock_inputs(&selected_transaction_data.inputs).await?;
+                }
                 return Err(err);
             }
         };
 
+        if let Some(request) = unconfirmed_input_request {
+            self.unconfirmed_input_requests
+                .lock()
+                .await
+                .insert(prepared_transaction_data.essence.hash(), request);
+        }
+
         log::debug!(
             "[TRANSACTION] finished prepare_transaction in {:.2?}",
             prepare_transaction_start_time.elapsed()
@@ -4,6 +4,7 @@
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
 use crate::{
+    client::api::block_builder::submission::BlockSubmissionProgress,
     types::block::{payload::Payload, BlockId},
     wallet::account::{operations::transaction::TransactionPayload, Account},
 };
@@ -18,29 +19,32 @@ impl Account {
         #[cfg(feature = "events")]
         let account_index = self.details().await.index;
 
-        let local_pow = self.client().get_local_pow().await;
-        if local_pow {
-            log::debug!("[TRANSACTION] doing local pow");
-            #[cfg(feature = "events")]
-            self.emit(
-                account_index,
-                WalletEvent::TransactionProgress(TransactionProgressEvent::PerformingPow),
-            )
-            .await;
-        }
-        let block = self
+        let parents = self.unconfirmed_input_parents(&transaction_payload).await?;
+
+        // The block is built and posted together, so it can be built again if the node rejects its parents. The events
+        // are emitted by the submission, which knows whether the proof of work is done locally.
+        let (_, metrics) = self
             .client()
-            .finish_block_builder(None, Some(Payload::from(transaction_payload)))
+            .submit_block_with_progress(
+                parents,
+                Some(Payload::from(transaction_payload)),
+                |progress| async move {
+                    if progress == BlockSubmissionProgress::PerformingPow {
+                        log::debug!("[TRANSACTION] doing local pow");
+                    }
+                    #[cfg(feature = "events")]
+                    self.emit(
+                        account_index,
+                        WalletEvent::TransactionProgress(match progress {
+                            BlockSubmissionProgress::PerformingPow => TransactionProgressEvent::PerformingPow,
+                            BlockSubmissionProgress::Broadcasting => TransactionProgressEvent::Broadcasting,
+                        }),
+                    )
+                    .await;
+                },
+            )
             .await?;
-
-        #[cfg(feature = "events")]
-        self.emit(
-            account_index,
-            WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting),
-        )
-        .await;
-        let block_id = self.client().post_block(&block).await?;
-        log::debug!("[TRANSACTION] submitted block {}", block_id);
-        Ok(block_id)
+        log::debug!("[TRANSACTION] submitted block {}", metrics.block_id);
+        Ok(metrics.block_id)
     }
 }
@@ -213,6 +213,78 @@ impl From<&NativeTokensBalance> for NativeTokensBalanceDto {
     }
 }
 
+/// A past point of the ledger, for [`crate::wallet::account::Account::historical_balance()`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum BalancePoint {
+    /// Right after the milestone with this index was confirmed.
+    Milestone { index: u32 },
+    /// At this time, in seconds since the unix epoch like milestone timestamps, unlike the milliseconds of
+    /// transaction timestamps.
+    Timestamp {
+        #[serde(rename = "timestampSeconds")]
+        timestamp_seconds: u32,
+    },
+}
+
+/// A confirmed milestone with its timestamp.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestonePoint {
+    /// The index of the milestone.
+    pub index: u32,
+    /// Seconds since the unix epoch when the milestone was issued.
+    pub timestamp: u32,
+}
+
+/// The balance of an account at a past point, returned from
+/// [`crate::wallet::account::Account::historical_balance()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalBalance {
+    /// The point of the balance.
+    pub point: BalancePoint,
+    /// The balance, reconstructed from the outputs of the account booked before and spent after the point.
+    pub balance: Balance,
+    /// Spent outputs booked before the point, whose spending milestone is unknown because their metadata was pruned
+    /// before the account synced them. They're not part of the balance.
+    pub unknown_spent_outputs: Vec<OutputId>,
+    /// The point is before the first sync of the account, outputs which were spent and pruned before aren't known.
+    pub before_first_sync: bool,
+}
+
+impl HistoricalBalance {
+    /// Returns whether the balance was reconstructed from all outputs the account had at the point.
+    pub fn is_complete(&self) -> bool {
+        self.unknown_spent_outputs.is_empty() && !self.before_first_sync
+    }
+}
+
+/// Dto for the balance of an account at a past point.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalBalanceDto {
+    /// The point of the balance.
+    pub point: BalancePoint,
+    /// The balance, reconstructed from the outputs of the account booked before and spent after the point.
+    pub balance: BalanceDto,
+    /// Spent outputs booked before the point, whose spending milestone is unknown.
+    pub unknown_spent_outputs: Vec<OutputId>,
+    /// The point is before the first sync of the account.
+    pub before_first_sync: bool,
+}
+
+impl From<&HistoricalBalance> for HistoricalBalanceDto {
+    fn from(value: &HistoricalBalance) -> Self {
+        Self {
+            point: value.point,
+            balance: BalanceDto::from(&value.balance),
+            unknown_spent_outputs: value.unknown_spent_outputs.clone(),
+            before_first_sync: value.before_first_sync,
+        }
+    }
+}
+
 #[cfg(feature = "rand")]
 impl Balance {
     pub fn rand_mock() -> Self {
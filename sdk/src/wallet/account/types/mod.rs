@@ -15,7 +15,8 @@ use serde::{Deserialize, Deserializer, Serialize};
 pub use self::{
     address::{AccountAddress, AddressWithUnspentOutputs},
     balance::{
-        Balance, BalanceDto, BaseCoinBalance, NativeTokensBalance, NativeTokensBalanceDto, RequiredStorageDeposit,
+        Balance, BalanceDto, BalancePoint, BaseCoinBalance, HistoricalBalance, HistoricalBalanceDto, MilestonePoint,
+        NativeTokensBalance, NativeTokensBalanceDto, RequiredStorageDeposit,
     },
 };
 use crate::{
@@ -55,6 +56,24 @@ pub struct OutputData {
     pub chain: Option<Chain>,
 }
 
+#[cfg(test)]
+impl OutputData {
+    /// Returns a mock of an unspent output of the given address, booked at milestone 0, all other fields are set to
+    /// their Rust defaults.
+    pub(crate) fn mock(output_id: OutputId, output: Output, address: Address) -> Self {
+        Self {
+            output_id,
+            metadata: OutputMetadata::new(BlockId::null(), output_id, false, None, None, None, 0, 0, 0),
+            output,
+            is_spent: false,
+            address,
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        }
+    }
+}
+
 impl OutputData {
     pub fn input_signing_data(
         &self,
@@ -156,6 +175,62 @@ pub struct Transaction {
     pub inputs: Vec<OutputWithMetadataResponse>,
 }
 
+#[cfg(test)]
+impl Transaction {
+    /// Returns a mock of a pending transaction sent by the account on the network of
+    /// [`protocol_parameters()`](crate::types::block::protocol::protocol_parameters), spending the inputs and creating
+    /// the outputs. The inputs are unlocked by a zeroed signature and aren't stored with the transaction.
+    pub(crate) fn mock(inputs: &[OutputId], outputs: Vec<Output>) -> Self {
+        use crate::types::block::{
+            input::{Input, UtxoInput},
+            output::InputsCommitment,
+            payload::transaction::{RegularTransactionEssence, TransactionEssence},
+            protocol::protocol_parameters,
+            signature::{Ed25519Signature, Signature},
+            unlock::{ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+        };
+
+        let protocol_parameters = protocol_parameters();
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), InputsCommitment::from([0; 32]))
+                .with_inputs(
+                    inputs
+                        .iter()
+                        .map(|output_id| Input::Utxo(UtxoInput::from(*output_id)))
+                        .collect::<Vec<_>>(),
+                )
+                .with_outputs(outputs)
+                .finish(&protocol_parameters)
+                .unwrap(),
+        );
+        // The first input is unlocked by the signature, all others reference it
+        let unlocks = (0..inputs.len())
+            .map(|index| {
+                if index == 0 {
+                    Unlock::Signature(SignatureUnlock::from(Signature::Ed25519(Ed25519Signature::new(
+                        [0; 32], [0; 64],
+                    ))))
+                } else {
+                    Unlock::Reference(ReferenceUnlock::new(0).unwrap())
+                }
+            })
+            .collect::<Vec<_>>();
+        let payload = TransactionPayload::new(essence, Unlocks::new(unlocks).unwrap()).unwrap();
+
+        Self {
+            transaction_id: payload.id(),
+            payload,
+            block_id: None,
+            network_id: protocol_parameters.network_id(),
+            timestamp: 0,
+            inclusion_state: InclusionState::Pending,
+            incoming: false,
+            note: None,
+            inputs: Vec::new(),
+        }
+    }
+}
+
 /// Dto for a transaction with metadata
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
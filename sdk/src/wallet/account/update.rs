@@ -6,7 +6,10 @@ use std::collections::HashMap;
 use crate::{
     types::block::output::{dto::OutputMetadataDto, OutputId},
     wallet::account::{
-        operations::syncing::options::SyncOptions,
+        operations::{
+            invoices::{expire_invoices, match_invoice},
+            syncing::options::SyncOptions,
+        },
         types::{address::AddressWithUnspentOutputs, InclusionState, OutputData, Transaction},
         Account, AccountAddress,
     },
@@ -112,6 +115,8 @@ impl Account {
                 // Could also be outputs from other networks after we switched the node, so we check that first
                 if output.network_id == network_id {
                     log::debug!("[SYNC] Spent output {}", output_id);
+                    #[cfg(feature = "events")]
+                    let (address_label, tags) = account_details.labels.output_labels(&output_id, &output.address);
                     account_details.locked_outputs.remove(&output_id);
                     account_details.unspent_outputs.remove(&output_id);
                     // Update spent data fields
@@ -124,6 +129,8 @@ impl Account {
                                 account_index,
                                 WalletEvent::SpentOutput(Box::new(SpentOutputEvent {
                                     output: OutputDataDto::from(&*output_data),
+                                    address_label,
+                                    tags,
                                 })),
                             )
                             .await;
@@ -141,8 +148,21 @@ impl Account {
                 .insert(output_data.output_id, output_data.clone())
                 .is_none()
             {
+                #[cfg_attr(not(feature = "events"), allow(unused_variables))]
+                let invoice_id = {
+                    // Borrow the fields separately through the guard
+                    let account_details = &mut *account_details;
+                    match_invoice(
+                        &mut account_details.invoices,
+                        &account_details.transactions,
+                        &output_data,
+                    )
+                };
                 #[cfg(feature = "events")]
                 {
+                    let (address_label, tags) = account_details
+                        .labels
+                        .output_labels(&output_data.output_id, &output_data.address);
                     let transaction = account_details
                         .incoming_transactions
                         .get(output_data.output_id.transaction_id());
@@ -158,6 +178,9 @@ impl Account {
                                     .map(OutputWithMetadataResponse::from)
                                     .collect()
                             }),
+                            address_label,
+                            tags,
+                            invoice_id,
                         })),
                     )
                     .await;
@@ -169,6 +192,7 @@ impl Account {
                     .insert(output_data.output_id, output_data);
             }
         }
+        expire_invoices(&mut account_details.invoices);
 
         #[cfg(feature = "storage")]
         {
@@ -191,12 +215,14 @@ impl Account {
         log::debug!("[SYNC] Update account with new synced transactions");
 
         let mut account_details = self.details_mut().await;
+        let mut finished_transactions = Vec::new();
 
         for transaction in updated_transactions {
             match transaction.inclusion_state {
                 InclusionState::Confirmed | InclusionState::Conflicting | InclusionState::UnknownPruned => {
                     let transaction_id = transaction.payload.id();
                     account_details.pending_transactions.remove(&transaction_id);
+                    finished_transactions.push((transaction_id, transaction.inclusion_state));
                     log::debug!(
                         "[SYNC] inclusion_state of {transaction_id} changed to {:?}",
                         transaction.inclusion_state
@@ -237,6 +263,11 @@ impl Account {
             );
         }
 
+        self.resolve_replaced_transactions(&mut account_details, &finished_transactions)
+            .await;
+        self.resolve_transaction_dependencies(&mut account_details, &finished_transactions)
+            .await;
+
         #[cfg(feature = "storage")]
         {
             log::debug!(
@@ -274,7 +305,8 @@ impl Account {
     }
 
     // Should only be called from the Wallet so all accounts are on the same state
-    // Will update the addresses with a possible new Bech32 HRP and clear the inaccessible_incoming_transactions.
+    // Will update the addresses with a possible new Bech32 HRP and clear the inaccessible_incoming_transactions and the
+    // synced milestone, because the node could be on another network.
     pub(crate) async fn update_account_bech32_hrp(&mut self) -> crate::wallet::Result<()> {
         let bech32_hrp = self.client().get_bech32_hrp().await?;
         log::debug!("[UPDATE ACCOUNT WITH BECH32 HRP] new bech32_hrp: {}", bech32_hrp);
@@ -288,8 +320,15 @@ impl Account {
         for address in &mut account_details.internal_addresses {
             address.address.hrp = bech32_hrp;
         }
+        for address_label in &mut account_details.labels.address_labels {
+            address_label.address.hrp = bech32_hrp;
+        }
+        for invoice in &mut account_details.invoices {
+            invoice.address.hrp = bech32_hrp;
+        }
 
         account_details.inaccessible_incoming_transactions.clear();
+        account_details.synced_milestone = None;
 
         #[cfg(feature = "storage")]
         {
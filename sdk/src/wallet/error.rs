@@ -8,7 +8,7 @@ use serde::{
     Serialize,
 };
 
-use crate::types::block::{address::Bech32Address, payload::transaction::TransactionId};
+use crate::types::block::{address::Bech32Address, output::OutputId, payload::transaction::TransactionId};
 
 /// The wallet error type.
 #[derive(Debug, thiserror::Error)]
@@ -34,6 +34,19 @@ pub enum Error {
     /// Client error.
     #[error("`{0}`")]
     Client(Box<crate::client::Error>),
+    /// Minting a collection failed after some of its NFTs were minted
+    #[error(
+        "collection minting failed after {minted_count} NFTs were minted in the transactions {}: {error}",
+        .transactions.iter().map(|transaction| transaction.transaction_id.to_string()).collect::<Vec<_>>().join(", ")
+    )]
+    CollectionMintingFailed {
+        /// The number of NFTs that were minted, in the order they were provided.
+        minted_count: usize,
+        /// The submitted transactions, the last one may still be pending.
+        transactions: Vec<crate::wallet::account::types::Transaction>,
+        /// The error of the failed batch.
+        error: Box<Error>,
+    },
     /// Funds are spread over too many outputs
     #[error("funds are spread over too many outputs {output_count}/{output_count_max}, consolidation required")]
     ConsolidationRequired { output_count: usize, output_count_max: u16 },
@@ -43,12 +56,18 @@ pub enum Error {
     /// Custom input error
     #[error("custom input error {0}")]
     CustomInput(String),
+    /// Export error
+    #[error("export error: {0}")]
+    Export(String),
     /// Failed to get remainder
     #[error("failed to get remainder address")]
     FailedToGetRemainder,
     /// Insufficient funds to send transaction.
     #[error("insufficient funds {available}/{required} available")]
     InsufficientFunds { available: u64, required: u64 },
+    /// Invalid batch payout, with the errors of every invalid row
+    #[error("invalid batch payout: {}", .0.join("; "))]
+    InvalidBatchPayout(Vec<String>),
     /// Invalid coin type, all accounts need to have the same coin type
     #[error("invalid coin type for new account: {new_coin_type}, existing coin type is: {existing_coin_type}")]
     InvalidCoinType {
@@ -61,9 +80,15 @@ pub enum Error {
     /// Invalid output kind.
     #[error("invalid output kind: {0}")]
     InvalidOutputKind(String),
+    /// Invoice error
+    #[error("invoice error: {0}")]
+    Invoice(String),
     /// IO error. (storage, backup, restore)
     #[error("`{0}`")]
     Io(#[from] std::io::Error),
+    /// Invalid IRC-27 NFT metadata
+    #[error("invalid IRC-27 metadata: {0}")]
+    Irc27Metadata(String),
     /// serde_json error.
     #[error("`{0}`")]
     Json(#[from] serde_json::error::Error),
@@ -100,18 +125,39 @@ pub enum Error {
         /// The consolidation threshold.
         consolidation_threshold: usize,
     },
+    /// Output not found in the account
+    #[error("output {0} not found in account")]
+    OutputNotFound(OutputId),
+    /// Payment URI error
+    #[error("payment URI error: {0}")]
+    PaymentUri(String),
+    /// Scheduled intent error
+    #[error("scheduled intent error: {0}")]
+    ScheduledIntent(String),
     /// Storage access error.
     #[error("error accessing storage: {0}")]
     Storage(String),
     /// Can't use Wallet API because the storage is encrypted
     #[error("can't perform operation while storage is encrypted; use Wallet::set_storage_password to decrypt storage")]
     StorageIsEncrypted,
+    /// A sync or an account recovery was cancelled
+    #[error("sync was cancelled")]
+    SyncCancelled,
     /// Tokio task join error
     #[error("{0}")]
     TaskJoin(#[from] tokio::task::JoinError),
     /// Transaction not found
     #[error("transaction {0} not found")]
     TransactionNotFound(TransactionId),
+    /// Transaction can't be replaced
+    #[error("transaction {0} can't be replaced: {1}")]
+    TransactionNotReplaceable(TransactionId, &'static str),
+    /// A transaction plan is already pending
+    #[error("a transaction plan is already pending; resume it with Account::resume_transaction_plan")]
+    TransactionPlanPending,
+    /// The transaction of a plan step isn't included and its inputs were pruned, so it could have been confirmed
+    #[error("transaction {0} of the transaction plan is unknown to the node and its inputs were pruned")]
+    TransactionPlanStepUnknown(TransactionId),
 }
 
 // Serialize type with Display error
@@ -40,6 +40,8 @@ impl EventEmitter {
                 WalletEventType::TransactionInclusion,
                 WalletEventType::TransactionProgress,
                 WalletEventType::ConsolidationRequired,
+                WalletEventType::ScheduledIntent,
+                WalletEventType::SyncProgress,
                 #[cfg(feature = "ledger_nano")]
                 WalletEventType::LedgerAddressGeneration,
             ] {
@@ -76,6 +78,8 @@ impl EventEmitter {
             WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
             WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
             WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
+            WalletEvent::ScheduledIntent(_) => WalletEventType::ScheduledIntent,
+            WalletEvent::SyncProgress(_) => WalletEventType::SyncProgress,
             #[cfg(feature = "ledger_nano")]
             WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
         };
@@ -13,7 +13,10 @@ use crate::{
             payload::transaction::{dto::TransactionPayloadDto, TransactionId},
         },
     },
-    wallet::account::types::{InclusionState, OutputDataDto},
+    wallet::account::{
+        types::{InclusionState, OutputDataDto},
+        ScheduledIntent, ScheduledIntentAction,
+    },
 };
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -35,6 +38,8 @@ pub enum WalletEvent {
     SpentOutput(Box<SpentOutputEvent>),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
+    ScheduledIntent(Box<ScheduledIntentEvent>),
+    SyncProgress(SyncProgressEvent),
 }
 
 impl Serialize for WalletEvent {
@@ -57,6 +62,8 @@ impl Serialize for WalletEvent {
             T3(&'a SpentOutputEvent),
             T4(&'a TransactionInclusionEvent),
             T5(TransactionProgressEvent_<'a>),
+            T6(&'a ScheduledIntentEvent),
+            T7(&'a SyncProgressEvent),
         }
         #[derive(Serialize)]
         struct TypedWalletEvent_<'a> {
@@ -91,6 +98,14 @@ impl Serialize for WalletEvent {
                 kind: WalletEventType::TransactionProgress as u8,
                 event: WalletEvent_::T5(TransactionProgressEvent_ { progress: e }),
             },
+            Self::ScheduledIntent(e) => TypedWalletEvent_ {
+                kind: WalletEventType::ScheduledIntent as u8,
+                event: WalletEvent_::T6(e),
+            },
+            Self::SyncProgress(e) => TypedWalletEvent_ {
+                kind: WalletEventType::SyncProgress as u8,
+                event: WalletEvent_::T7(e),
+            },
         };
         event.serialize(serializer)
     }
@@ -142,6 +157,15 @@ impl<'de> Deserialize<'de> for WalletEvent {
                         })?
                         .progress,
                 ),
+                WalletEventType::ScheduledIntent => {
+                    Self::ScheduledIntent(Box::new(ScheduledIntentEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize ScheduledIntent: {e}"))
+                    })?))
+                }
+                WalletEventType::SyncProgress => Self::SyncProgress(
+                    SyncProgressEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize SyncProgress: {e}")))?,
+                ),
             },
         )
     }
@@ -158,6 +182,8 @@ pub enum WalletEventType {
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    ScheduledIntent = 6,
+    SyncProgress = 7,
 }
 
 impl TryFrom<u8> for WalletEventType {
@@ -172,6 +198,8 @@ impl TryFrom<u8> for WalletEventType {
             3 => Self::SpentOutput,
             4 => Self::TransactionInclusion,
             5 => Self::TransactionProgress,
+            6 => Self::ScheduledIntent,
+            7 => Self::SyncProgress,
             _ => return Err(format!("invalid event type {value}")),
         };
         Ok(event_type)
@@ -189,12 +217,28 @@ pub struct NewOutputEvent {
     /// The inputs for the transaction that created the output. Might be pruned and not available.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_inputs: Option<Vec<OutputWithMetadataResponse>>,
+    /// The label of the address of the account that owns the output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address_label: Option<String>,
+    /// The tags of the output.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// The invoice paid by the output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invoice_id: Option<u32>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SpentOutputEvent {
     /// The spent output.
     pub output: OutputDataDto,
+    /// The label of the address of the account that owned the output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address_label: Option<String>,
+    /// The tags of the output.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
@@ -204,6 +248,42 @@ pub struct TransactionInclusionEvent {
     pub inclusion_state: InclusionState,
 }
 
+/// A scheduled intent was evaluated.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledIntentEvent {
+    /// The evaluated intent.
+    pub intent: ScheduledIntent,
+    /// What happened.
+    pub action: ScheduledIntentAction,
+}
+
+/// Progress of a sync of an account or of an account recovery.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncProgressEvent {
+    /// The stage of the sync.
+    pub stage: SyncStage,
+    /// How many items of the stage were processed so far.
+    pub processed: usize,
+    /// How many items the stage has in total, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<usize>,
+}
+
+/// The stages of a sync, an account recovery runs them for every searched account.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SyncStage {
+    /// The output ids of addresses were requested.
+    AddressesScanned,
+    /// Output ids were found on the synced addresses.
+    OutputIdsFound,
+    /// Outputs were requested.
+    OutputsFetched,
+    /// The transactions which created received outputs were requested.
+    TransactionsResolved,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TransactionProgressEvent {
     /// Performing input selection.
@@ -220,6 +300,8 @@ pub enum TransactionProgressEvent {
     PerformingPow,
     /// Broadcasting.
     Broadcasting,
+    /// Executing a step of a transaction plan.
+    TransactionPlanStep(TransactionPlanStepProgress),
 }
 
 impl Serialize for TransactionProgressEvent {
@@ -242,6 +324,7 @@ impl Serialize for TransactionProgressEvent {
             T4,
             T5,
             T6,
+            T7(&'a TransactionPlanStepProgress),
         }
         #[derive(Serialize)]
         struct TypedTransactionProgressEvent_<'a> {
@@ -279,6 +362,10 @@ impl Serialize for TransactionProgressEvent {
                 kind: 6,
                 event: TransactionProgressEvent_::T6,
             },
+            Self::TransactionPlanStep(e) => TypedTransactionProgressEvent_ {
+                kind: 7,
+                event: TransactionProgressEvent_::T7(e),
+            },
         };
         event.serialize(serializer)
     }
@@ -316,12 +403,27 @@ impl<'de> Deserialize<'de> for TransactionProgressEvent {
                 4 => Self::SigningTransaction,
                 5 => Self::PerformingPow,
                 6 => Self::Broadcasting,
+                7 => Self::TransactionPlanStep(TransactionPlanStepProgress::deserialize(value).map_err(|e| {
+                    serde::de::Error::custom(format!("cannot deserialize TransactionPlanStepProgress: {e}"))
+                })?),
                 _ => return Err(serde::de::Error::custom("invalid transaction progress event type")),
             },
         )
     }
 }
 
+/// Progress of a transaction plan, emitted when one of its steps is executed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionPlanStepProgress {
+    /// The index of the executed step.
+    pub step: usize,
+    /// The number of steps of the plan.
+    pub step_count: usize,
+    /// Whether the step consolidates outputs or sends a batch of outputs.
+    pub consolidation: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct AddressConsolidationNeeded {
     /// The associated address.
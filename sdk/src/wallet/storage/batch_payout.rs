@@ -0,0 +1,33 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use super::manager::StorageManager;
+use crate::{
+    client::storage::StorageAdapter,
+    wallet::{account::BatchPayout, storage::constants::BATCH_PAYOUT_INDEXATION_KEY},
+};
+
+impl StorageManager {
+    pub(crate) async fn set_batch_payout(&self, account_index: u32, payout: &BatchPayout) -> crate::wallet::Result<()> {
+        log::debug!("set_batch_payout {}", payout.id);
+
+        self.storage
+            .set(
+                &format!("{BATCH_PAYOUT_INDEXATION_KEY}{account_index}-{}", payout.id),
+                payout,
+            )
+            .await
+    }
+
+    pub(crate) async fn get_batch_payout(
+        &self,
+        account_index: u32,
+        payout_id: &str,
+    ) -> crate::wallet::Result<Option<BatchPayout>> {
+        log::debug!("get_batch_payout {payout_id}");
+
+        self.storage
+            .get(&format!("{BATCH_PAYOUT_INDEXATION_KEY}{account_index}-{payout_id}"))
+            .await
+    }
+}
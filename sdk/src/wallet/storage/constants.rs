@@ -24,6 +24,8 @@ pub(crate) const ACCOUNT_INDEXATION_KEY: &str = "iota-wallet-account-";
 
 pub(crate) const ACCOUNT_SYNC_OPTIONS: &str = "sync-options";
 
+pub(crate) const BATCH_PAYOUT_INDEXATION_KEY: &str = "batch-payout-";
+
 pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 1;
 pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
 
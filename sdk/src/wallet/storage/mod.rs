@@ -3,6 +3,8 @@
 
 /// Storage adapter.
 pub mod adapter;
+/// Storage functions related to batch payouts.
+mod batch_payout;
 /// Storage constants.
 pub mod constants;
 /// Storage manager.
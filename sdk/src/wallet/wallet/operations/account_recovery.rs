@@ -4,6 +4,7 @@
 use instant::Instant;
 
 use crate::wallet::{
+    account::CancellationToken,
     task,
     wallet::{SyncOptions, Wallet},
     Account,
@@ -30,6 +31,28 @@ impl Wallet {
         account_gap_limit: u32,
         address_gap_limit: u32,
         sync_options: Option<SyncOptions>,
+    ) -> crate::wallet::Result<Vec<Account>> {
+        self.recover_accounts_with_cancellation(
+            account_start_index,
+            account_gap_limit,
+            address_gap_limit,
+            sync_options,
+            &CancellationToken::new(),
+        )
+        .await
+    }
+
+    /// Find accounts with unspent outputs like [`Wallet::recover_accounts()`], but stop with
+    /// [`Error::SyncCancelled`](crate::wallet::Error::SyncCancelled) when the token gets cancelled. Addresses and
+    /// accounts without outputs are removed again, accounts of the round of new accounts that was searched when
+    /// cancelling are removed too.
+    pub async fn recover_accounts_with_cancellation(
+        &self,
+        account_start_index: u32,
+        account_gap_limit: u32,
+        address_gap_limit: u32,
+        sync_options: Option<SyncOptions>,
+        cancellation: &CancellationToken,
     ) -> crate::wallet::Result<Vec<Account>> {
         log::debug!("[recover_accounts]");
         let start_time = Instant::now();
@@ -40,7 +63,7 @@ impl Wallet {
             // If the gap limit is 0, there is no need to search for funds
             if address_gap_limit > 0 {
                 account
-                    .search_addresses_with_outputs(address_gap_limit, sync_options.clone())
+                    .search_addresses_with_outputs(address_gap_limit, sync_options.clone(), cancellation)
                     .await?;
             }
             let account_index = *account.details().await.index();
@@ -68,6 +91,7 @@ impl Wallet {
                 address_gap_limit,
                 &mut max_account_index_to_keep,
                 sync_options.clone(),
+                cancellation,
             )
             .await;
 
@@ -113,20 +137,23 @@ impl Wallet {
         address_gap_limit: u32,
         max_account_index_to_keep: &mut Option<u32>,
         sync_options: Option<SyncOptions>,
+        cancellation: &CancellationToken,
     ) -> crate::wallet::Result<()> {
         let mut updated_account_gap_limit = account_gap_limit;
         loop {
+            cancellation.check()?;
             log::debug!("[recover_accounts] generating {updated_account_gap_limit} new accounts");
 
             // Generate account with addresses and get their outputs in parallel
             let results = futures::future::try_join_all((0..updated_account_gap_limit).map(|_| {
                 let mut new_account = self.create_account();
                 let sync_options_ = sync_options.clone();
+                let cancellation = cancellation.clone();
                 async move {
                     task::spawn(async move {
                         let new_account = new_account.finish().await?;
                         let account_outputs_count = new_account
-                            .search_addresses_with_outputs(address_gap_limit, sync_options_)
+                            .search_addresses_with_outputs(address_gap_limit, sync_options_, &cancellation)
                             .await?;
                         let account_index = *new_account.details().await.index();
                         crate::wallet::Result::Ok((account_index, account_outputs_count))
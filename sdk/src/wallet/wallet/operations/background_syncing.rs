@@ -11,7 +11,9 @@ use crate::wallet::{account::operations::syncing::SyncOptions, Wallet};
 pub(crate) const DEFAULT_BACKGROUNDSYNCING_INTERVAL: Duration = Duration::from_secs(7);
 
 impl Wallet {
-    /// Start the background syncing process for all accounts, default interval is 7 seconds
+    /// Start the background syncing process for all accounts, default interval is 7 seconds. After each sync of an
+    /// account its scheduled intents are executed and, if [`SyncOptions::replan_transactions`] is set, transactions
+    /// whose parents conflicted are sent again.
     pub async fn start_background_syncing(
         &self,
         options: Option<SyncOptions>,
@@ -49,8 +51,23 @@ impl Wallet {
                             log::debug!("[background_syncing]: stopping");
                             break 'outer;
                         }
+                        let replan_transactions = match &options {
+                            Some(options) => options.replan_transactions,
+                            None => account.default_sync_options().await.replan_transactions,
+                        };
                         match account.sync(options.clone()).await {
-                            Ok(_) => {}
+                            Ok(_) => {
+                                // Scheduled intents are evaluated on the freshly synced state
+                                if let Err(err) = account.execute_scheduled_intents().await {
+                                    log::debug!("[background_syncing] scheduled intents error: {}", err);
+                                }
+                                // Transactions whose parents conflicted are sent again
+                                if replan_transactions {
+                                    if let Err(err) = account.replan_transactions().await {
+                                        log::debug!("[background_syncing] replan transactions error: {}", err);
+                                    }
+                                }
+                            }
                             Err(err) => log::debug!("[background_syncing] error: {}", err),
                         };
                     }
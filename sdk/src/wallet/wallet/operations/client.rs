@@ -1,7 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
 #[cfg(feature = "storage")]
 use crate::wallet::WalletBuilder;
@@ -34,15 +34,17 @@ impl Wallet {
             network_info,
             api_timeout,
             remote_pow_timeout,
+            block_submission_options,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count,
         } = client_options;
         self.client
-            .update_node_manager(node_manager_builder.build(HashMap::new()))
+            .update_node_manager(node_manager_builder.build(HashMap::new())?)
             .await?;
         *self.client.network_info.write().await = network_info;
         *self.client.api_timeout.write().await = api_timeout;
         *self.client.remote_pow_timeout.write().await = remote_pow_timeout;
+        self.client.set_block_submission_options(block_submission_options).await;
         #[cfg(not(target_family = "wasm"))]
         {
             *self.client.pow_worker_count.write().await = pow_worker_count;
@@ -74,74 +76,26 @@ impl Wallet {
         log::debug!("[update_node_auth]");
         let mut node_manager_builder = NodeManagerBuilder::from(&*self.client.node_manager.read().await);
 
-        if let Some(primary_node) = &node_manager_builder.primary_node {
-            let (node_url, disabled) = match &primary_node {
-                NodeDto::Url(node_url) => (node_url, false),
-                NodeDto::Node(node) => (&node.url, node.disabled),
-            };
-
-            if node_url == &url {
-                node_manager_builder.primary_node = Some(NodeDto::Node(Node {
-                    url: url.clone(),
-                    auth: auth.clone(),
-                    disabled,
-                }));
-            }
-        }
-
-        if let Some(primary_pow_node) = &node_manager_builder.primary_pow_node {
-            let (node_url, disabled) = match &primary_pow_node {
-                NodeDto::Url(node_url) => (node_url, false),
-                NodeDto::Node(node) => (&node.url, node.disabled),
-            };
-
-            if node_url == &url {
-                node_manager_builder.primary_pow_node = Some(NodeDto::Node(Node {
-                    url: url.clone(),
-                    auth: auth.clone(),
-                    disabled,
-                }));
-            }
-        }
-
-        if let Some(permanodes) = &node_manager_builder.permanodes {
-            let mut new_permanodes = HashSet::new();
-            for node in permanodes.iter() {
-                let (node_url, disabled) = match &node {
-                    NodeDto::Url(node_url) => (node_url, false),
-                    NodeDto::Node(node) => (&node.url, node.disabled),
-                };
-
-                if node_url == &url {
-                    new_permanodes.insert(NodeDto::Node(Node {
-                        url: url.clone(),
-                        auth: auth.clone(),
-                        disabled,
-                    }));
-                } else {
-                    new_permanodes.insert(node.clone());
-                }
-            }
-            node_manager_builder.permanodes = Some(new_permanodes);
-        }
-
-        let mut new_nodes = HashSet::new();
-        for node in node_manager_builder.nodes.iter() {
-            let (node_url, disabled) = match &node {
-                NodeDto::Url(node_url) => (node_url, false),
-                NodeDto::Node(node) => (&node.url, node.disabled),
-            };
-
-            if node_url == &url {
-                new_nodes.insert(NodeDto::Node(Node {
-                    url: url.clone(),
+        // Replace the auth of the node with the matching url and keep its other options
+        let update_auth = |node: &NodeDto| -> NodeDto {
+            let node = Node::from(node);
+            if node.url == url {
+                NodeDto::Node(Node {
                     auth: auth.clone(),
-                    disabled,
-                }));
+                    ..node
+                })
             } else {
-                new_nodes.insert(node.clone());
+                NodeDto::Node(node)
             }
-        }
+        };
+
+        node_manager_builder.primary_node = node_manager_builder.primary_node.as_ref().map(update_auth);
+        node_manager_builder.primary_pow_node = node_manager_builder.primary_pow_node.as_ref().map(update_auth);
+        node_manager_builder.permanodes = node_manager_builder
+            .permanodes
+            .as_ref()
+            .map(|permanodes| permanodes.iter().map(update_auth).collect());
+        let new_nodes = node_manager_builder.nodes.iter().map(update_auth).collect();
         node_manager_builder.nodes = new_nodes;
 
         #[cfg(feature = "storage")]
@@ -154,7 +108,7 @@ impl Wallet {
         }
 
         self.client
-            .update_node_manager(node_manager_builder.build(HashMap::new()))
+            .update_node_manager(node_manager_builder.build(HashMap::new())?)
             .await?;
 
         for account in self.accounts.write().await.iter_mut() {
@@ -1,7 +1,11 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::{Client, ClientBuilder};
+use iota_sdk::client::{
+    api::{BlockSubmissionOptions, ParentsStrategy, PowStrategy},
+    node_manager::node::{Node, TlsOptions},
+    Client, ClientBuilder,
+};
 
 #[tokio::test]
 async fn invalid_url() {
@@ -126,3 +130,80 @@ async fn client_builder() {
 
     let _client_builder = serde_json::from_str::<ClientBuilder>(client_builder_json).unwrap();
 }
+
+#[tokio::test]
+async fn client_builder_proxy_and_tls() {
+    let client_builder_json = r#"{
+        "nodes":[
+            {
+                "url":"http://localhost:14265/",
+                "auth":null,
+                "disabled":false,
+                "tls":{
+                    "rootCertificates":[],
+                    "disableBuiltInRootCertificates":true
+                }
+            }
+        ],
+        "proxy":{
+            "url":"http://localhost:8080/",
+            "basicAuthNamePwd":["name","password"]
+        }
+    }"#;
+
+    let client_builder = ClientBuilder::new().from_json(client_builder_json).unwrap();
+
+    let proxy = client_builder.node_manager_builder.proxy.as_ref().unwrap();
+    assert_eq!(proxy.url.as_str(), "http://localhost:8080/");
+    assert!(client_builder.node_manager_builder.tls.is_none());
+
+    let node: Node = client_builder.node_manager_builder.nodes.iter().next().unwrap().into();
+    assert!(node.proxy.is_none());
+    assert_eq!(
+        node.tls,
+        Some(TlsOptions {
+            root_certificates: Vec::new(),
+            disable_built_in_root_certificates: true,
+            client_identity_path: None,
+        })
+    );
+
+    // Options survive a roundtrip
+    let json = client_builder.to_json().unwrap();
+    assert_eq!(ClientBuilder::new().from_json(&json).unwrap(), client_builder);
+}
+
+#[tokio::test]
+async fn client_builder_block_submission_options() {
+    // Defaults without options
+    let client_builder = ClientBuilder::new().from_json(r#"{"nodes":[]}"#).unwrap();
+    assert_eq!(
+        client_builder.block_submission_options,
+        BlockSubmissionOptions::default()
+    );
+    assert_eq!(client_builder.block_submission_options.max_retips, 3);
+
+    let client_builder_json = r#"{
+        "nodes":[],
+        "blockSubmissionOptions":{
+            "powStrategy":"Fastest",
+            "parentsStrategy":{
+                "strategy":"OwnBlocks"
+            }
+        }
+    }"#;
+    let client_builder = ClientBuilder::new().from_json(client_builder_json).unwrap();
+
+    assert_eq!(
+        client_builder.block_submission_options,
+        BlockSubmissionOptions {
+            pow_strategy: PowStrategy::Fastest,
+            parents_strategy: ParentsStrategy::OwnBlocks,
+            max_retips: 3,
+        }
+    );
+
+    // Options survive a roundtrip
+    let json = client_builder.to_json().unwrap();
+    assert_eq!(ClientBuilder::new().from_json(&json).unwrap(), client_builder);
+}
@@ -0,0 +1,176 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::api::input_selection::{CoinSelectionStrategy, InputSelection},
+    types::block::{
+        address::Address,
+        output::{Output, RentStructure},
+        protocol::protocol_parameters,
+    },
+};
+use proptest::prelude::*;
+
+use crate::client::{
+    addresses, build_inputs, build_outputs, Build::Basic, BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1,
+    BECH32_ADDRESS_ED25519_2,
+};
+
+const STRATEGIES: [CoinSelectionStrategy; 5] = [
+    CoinSelectionStrategy::Default,
+    CoinSelectionStrategy::MinimizeInputCount,
+    CoinSelectionStrategy::ExactMatch,
+    CoinSelectionStrategy::OldestFirst,
+    CoinSelectionStrategy::AvoidAddressMixing,
+];
+const ADDRESSES: [&str; 3] = [
+    BECH32_ADDRESS_ED25519_0,
+    BECH32_ADDRESS_ED25519_1,
+    BECH32_ADDRESS_ED25519_2,
+];
+
+fn basic(amount: u64, address: &str) -> crate::client::Build<'_> {
+    Basic(amount, address, None, None, None, None, None, None)
+}
+
+fn required_address(output: &Output) -> Address {
+    *output.unlock_conditions().unwrap().address().unwrap().address()
+}
+
+#[test]
+fn exact_match_avoids_remainder() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        basic(1_000_000, BECH32_ADDRESS_ED25519_0),
+        basic(2_500_000, BECH32_ADDRESS_ED25519_0),
+        basic(3_000_000, BECH32_ADDRESS_ED25519_0),
+        basic(4_000_000, BECH32_ADDRESS_ED25519_0),
+    ]);
+    let outputs = build_outputs([basic(5_500_000, BECH32_ADDRESS_ED25519_1)]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters.clone(),
+    )
+    .select()
+    .unwrap();
+
+    assert_eq!(selected.remainders.len(), 1);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .coin_selection_strategy(CoinSelectionStrategy::ExactMatch)
+    .select()
+    .unwrap();
+
+    assert!(selected.remainders.is_empty());
+    assert_eq!(selected.inputs.len(), 2);
+    assert_eq!(selected.outputs, outputs);
+}
+
+#[test]
+fn minimize_input_count() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        basic(1_000_000, BECH32_ADDRESS_ED25519_0),
+        basic(1_000_000, BECH32_ADDRESS_ED25519_0),
+        basic(1_000_000, BECH32_ADDRESS_ED25519_0),
+        basic(5_000_000, BECH32_ADDRESS_ED25519_0),
+    ]);
+    let outputs = build_outputs([basic(2_000_000, BECH32_ADDRESS_ED25519_1)]);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .coin_selection_strategy(CoinSelectionStrategy::MinimizeInputCount)
+    .select()
+    .unwrap();
+
+    assert_eq!(selected.inputs.len(), 1);
+    assert_eq!(selected.inputs[0].output.amount(), 5_000_000);
+}
+
+#[test]
+fn avoid_address_mixing() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        basic(1_000_000, BECH32_ADDRESS_ED25519_0),
+        basic(1_000_000, BECH32_ADDRESS_ED25519_0),
+        basic(3_000_000, BECH32_ADDRESS_ED25519_1),
+    ]);
+    let outputs = build_outputs([basic(2_500_000, BECH32_ADDRESS_ED25519_2)]);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1]),
+        protocol_parameters,
+    )
+    .coin_selection_strategy(CoinSelectionStrategy::AvoidAddressMixing)
+    .select()
+    .unwrap();
+
+    assert_eq!(selected.inputs.len(), 1);
+    assert_eq!(
+        required_address(&selected.inputs[0].output),
+        Address::try_from_bech32(BECH32_ADDRESS_ED25519_1).unwrap()
+    );
+}
+
+proptest! {
+    #[test]
+    fn every_strategy_yields_valid_transactions(
+        inputs in prop::collection::vec((1_000_000u64..10_000_000, 0..ADDRESSES.len()), 1..30),
+        output_ratio in 0.0f64..1.0,
+    ) {
+        let protocol_parameters = protocol_parameters();
+        let rent_structure: RentStructure = *protocol_parameters.rent_structure();
+        let token_supply = protocol_parameters.token_supply();
+
+        let total = inputs.iter().map(|(amount, _)| amount).sum::<u64>();
+        // Keep enough funds for a remainder so that the selection can always succeed.
+        let output_amount = 1_000_000 + ((total - 1_000_000) as f64 * output_ratio) as u64;
+        let output_amount = output_amount.min(total - 1_000_000).max(1_000_000);
+
+        let inputs = build_inputs(inputs.iter().map(|(amount, address)| basic(*amount, ADDRESSES[*address])));
+        let outputs = build_outputs([basic(output_amount, BECH32_ADDRESS_ED25519_0)]);
+
+        for strategy in STRATEGIES {
+            let selected = InputSelection::new(
+                inputs.clone(),
+                outputs.clone(),
+                addresses(ADDRESSES),
+                protocol_parameters.clone(),
+            )
+            .coin_selection_strategy(strategy)
+            .select()
+            .unwrap_or_else(|e| panic!("{strategy:?} failed: {e:?}"));
+
+            let inputs_sum = selected.inputs.iter().map(|input| input.output.amount()).sum::<u64>();
+            let outputs_sum = selected.outputs.iter().map(Output::amount).sum::<u64>();
+
+            prop_assert_eq!(inputs_sum, outputs_sum, "{:?} doesn't balance", strategy);
+            prop_assert!(selected.inputs.iter().all(|input| inputs.contains(input)));
+            prop_assert!(outputs.iter().all(|output| selected.outputs.contains(output)));
+            prop_assert!(
+                selected
+                    .outputs
+                    .iter()
+                    .all(|output| output.verify_storage_deposit(rent_structure, token_supply).is_ok())
+            );
+            prop_assert_eq!(selected.outputs.len() - outputs.len(), selected.remainders.len());
+        }
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use iota_sdk::{
+    client::api::input_selection::{
+        DiagnosedError, Error, InputRejectionReason, InputSelection, RejectedInput, Requirement, Shortfall,
+    },
+    types::block::protocol::protocol_parameters,
+};
+
+use crate::client::{
+    addresses, build_inputs, build_outputs, Build::Basic, BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1,
+};
+
+#[test]
+fn insufficient_amount_without_diagnostics() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs([Basic(
+        2_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .select();
+
+    assert!(matches!(
+        selected,
+        Err(Error::InsufficientAmount {
+            found: 1_000_000,
+            required: 2_000_000,
+        })
+    ));
+}
+
+#[test]
+fn insufficient_amount_with_diagnostics() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(
+            2_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            Some(200),
+            None,
+            None,
+        ),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        3_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let timelocked = *inputs[1].output_id();
+    let forbidden = *inputs[2].output_id();
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .forbidden_inputs(HashSet::from([forbidden]))
+    .timestamp(100)
+    .select_with_diagnostics();
+
+    let Err(DiagnosedError { error, diagnostics }) = selected else {
+        panic!("expected a diagnosed error, got {selected:?}");
+    };
+
+    assert!(matches!(
+        error,
+        Error::InsufficientAmount {
+            found: 1_000_000,
+            required: 3_000_000,
+        }
+    ));
+    assert_eq!(diagnostics.failed_requirement, Some(Requirement::Amount));
+    assert_eq!(diagnostics.shortfall, Some(Shortfall::Amount { amount: 2_000_000 }));
+    assert!(diagnostics
+        .rejected_inputs
+        .contains(&RejectedInput::new(timelocked, InputRejectionReason::Timelocked)));
+    assert!(diagnostics
+        .rejected_inputs
+        .contains(&RejectedInput::new(forbidden, InputRejectionReason::Forbidden)));
+}
+
+#[test]
+fn rejected_inputs_are_kept() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let locked = build_inputs([Basic(
+        5_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let locked = *locked[0].output_id();
+    let outputs = build_outputs([Basic(
+        2_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .rejected_inputs([RejectedInput::new(locked, InputRejectionReason::Locked)])
+    .select_with_diagnostics();
+
+    let Err(DiagnosedError { diagnostics, .. }) = selected else {
+        panic!("expected a diagnosed error, got {selected:?}");
+    };
+
+    assert_eq!(
+        diagnostics.rejected_inputs,
+        [RejectedInput::new(locked, InputRejectionReason::Locked)]
+    );
+}
@@ -6,10 +6,13 @@
 mod alias_outputs;
 mod basic_outputs;
 mod burn;
+mod coin_selection;
+mod diagnostics;
 mod expiration;
 mod foundry_outputs;
 mod native_tokens;
 mod nft_outputs;
 mod outputs;
+mod remainder_split;
 mod storage_deposit_return;
 mod timelock;
@@ -0,0 +1,144 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::api::{
+        input_selection::{Error, InputSelection, RemainderSplitStrategy},
+        RemainderData,
+    },
+    types::block::{output::Output, protocol::protocol_parameters},
+};
+
+use crate::client::{
+    addresses, build_inputs, build_outputs, is_remainder_or_return, Build::Basic, BECH32_ADDRESS_ED25519_0,
+    BECH32_ADDRESS_ED25519_1,
+};
+
+fn basic(amount: u64, address: &str) -> crate::client::Build<'_> {
+    Basic(amount, address, None, None, None, None, None, None)
+}
+
+fn remainder_amounts(outputs: &[Output], provided_outputs: &[Output], remainders: &[RemainderData]) -> Vec<u64> {
+    let mut amounts = outputs
+        .iter()
+        .filter(|output| !provided_outputs.contains(output))
+        .inspect(|output| {
+            assert!(is_remainder_or_return(
+                output,
+                output.amount(),
+                BECH32_ADDRESS_ED25519_0,
+                None
+            ))
+        })
+        .map(|output| output.amount())
+        .collect::<Vec<_>>();
+    amounts.sort_unstable();
+
+    // All split outputs are returned as remainders
+    let mut remainder_amounts = remainders
+        .iter()
+        .map(|remainder| remainder.output.amount())
+        .collect::<Vec<_>>();
+    remainder_amounts.sort_unstable();
+    assert_eq!(amounts, remainder_amounts);
+
+    amounts
+}
+
+#[test]
+fn split_into_outputs() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([basic(10_000_000, BECH32_ADDRESS_ED25519_0)]);
+    let outputs = build_outputs([basic(1_000_000, BECH32_ADDRESS_ED25519_1)]);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .remainder_split_strategy(RemainderSplitStrategy::Outputs(3))
+    .select()
+    .unwrap();
+
+    assert_eq!(selected.outputs.len(), 4);
+    assert_eq!(
+        remainder_amounts(&selected.outputs, &outputs, &selected.remainders),
+        [3_000_000, 3_000_000, 3_000_000]
+    );
+    assert_eq!(selected.remainders[0].output.amount(), 3_000_000);
+}
+
+#[test]
+fn split_into_fewer_outputs_to_cover_storage_deposit() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([basic(1_500_000, BECH32_ADDRESS_ED25519_0)]);
+    let outputs = build_outputs([basic(1_000_000, BECH32_ADDRESS_ED25519_1)]);
+
+    // Only two outputs of 250_000 cover the storage deposit of 213_000 each.
+    let selected = InputSelection::new(
+        inputs,
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .remainder_split_strategy(RemainderSplitStrategy::Outputs(4))
+    .select()
+    .unwrap();
+
+    assert_eq!(selected.outputs.len(), 3);
+    assert_eq!(
+        remainder_amounts(&selected.outputs, &outputs, &selected.remainders),
+        [250_000, 250_000]
+    );
+}
+
+#[test]
+fn split_into_denominations() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([basic(10_000_000, BECH32_ADDRESS_ED25519_0)]);
+    let outputs = build_outputs([basic(1_000_000, BECH32_ADDRESS_ED25519_1)]);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .remainder_split_strategy(RemainderSplitStrategy::Denominations(vec![1_000_000, 5_000_000]))
+    .select()
+    .unwrap();
+
+    // The main remainder keeps at least its storage deposit, so the last million isn't denominated.
+    assert_eq!(selected.outputs.len(), 6);
+    assert_eq!(
+        remainder_amounts(&selected.outputs, &outputs, &selected.remainders),
+        [1_000_000, 1_000_000, 1_000_000, 1_000_000, 5_000_000]
+    );
+    assert_eq!(selected.remainders[0].output.amount(), 1_000_000);
+}
+
+#[test]
+fn denomination_below_storage_deposit() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([basic(10_000_000, BECH32_ADDRESS_ED25519_0)]);
+    let outputs = build_outputs([basic(1_000_000, BECH32_ADDRESS_ED25519_1)]);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .remainder_split_strategy(RemainderSplitStrategy::Denominations(vec![1_000_000, 100]))
+    .select();
+
+    assert!(matches!(
+        selected,
+        Err(Error::InvalidRemainderDenomination { denomination: 100, .. })
+    ));
+}
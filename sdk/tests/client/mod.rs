@@ -11,6 +11,7 @@ mod mnemonic;
 #[cfg(feature = "mqtt")]
 mod mqtt;
 mod node_api;
+mod plugin;
 mod secret_manager;
 mod signing;
 mod transactions;
@@ -0,0 +1,29 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use iota_sdk::client::mqtt::BrokerOptions;
+
+#[test]
+fn broker_options_defaults() {
+    let options: BrokerOptions = serde_json::from_str("{}").unwrap();
+
+    assert_eq!(options, BrokerOptions::new());
+}
+
+#[test]
+fn broker_options_backfill() {
+    let options: BrokerOptions = serde_json::from_str(
+        r#"{"backfill":true,"maxBackfillMilestones":10,"reconnectionBackoff":{"secs":5,"nanos":0}}"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        options,
+        BrokerOptions::new()
+            .backfill(true)
+            .max_backfill_milestones(10)
+            .reconnection_backoff(Duration::from_secs(5))
+    );
+}
@@ -3,10 +3,11 @@
 
 // These are E2E test samples, so they are ignored by default.
 
+use futures::TryStreamExt;
 use iota_sdk::{
     client::{
         api::GetAddressesOptions, bech32_to_hex, node_api::indexer::query_parameters::QueryParameter,
-        request_funds_from_faucet, secret::SecretManager, Client,
+        request_funds_from_faucet, secret::SecretManager, Client, Error,
     },
     types::block::{
         address::ToBech32Ext,
@@ -215,6 +216,42 @@ async fn test_get_address_outputs() {
     println!("{r:#?}");
 }
 
+#[ignore]
+#[tokio::test]
+async fn test_get_address_outputs_stream() {
+    let client = setup_client_with_node_health_ignored().await;
+    let secret_manager = setup_secret_manager();
+
+    let address = secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::from_client(&client)
+                .await
+                .unwrap()
+                .with_range(0..1),
+        )
+        .await
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+
+    let output_ids_response = client
+        .basic_output_ids([QueryParameter::Address(address)])
+        .await
+        .unwrap();
+
+    let pages = client
+        .basic_output_ids_stream([QueryParameter::Address(address), QueryParameter::PageSize(1)])
+        .unwrap()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    let streamed_output_ids = pages.into_iter().flat_map(|page| page.items).collect::<Vec<_>>();
+
+    assert_eq!(output_ids_response.items, streamed_output_ids);
+}
+
 #[ignore]
 #[tokio::test]
 async fn test_get_output() {
@@ -237,6 +274,29 @@ async fn test_get_peers() {
     println!("{r:#?}");
 }
 
+#[tokio::test]
+async fn protected_routes_require_primary_node() {
+    let client = Client::builder()
+        .with_nodes(&[NODE_LOCAL, "http://localhost:14266"])
+        .unwrap()
+        .with_ignore_node_health()
+        .finish()
+        .await
+        .unwrap();
+
+    // With multiple nodes it's unclear which one should be changed
+    assert!(matches!(
+        client
+            .remove_peer("12D3KooWRVt4Engu27jHnF2RjfX48EqiAqJbgLfFdHNt3Vn6BtJK")
+            .await,
+        Err(Error::ProtectedNodeMissing)
+    ));
+    assert!(matches!(
+        client.create_snapshots(1).await,
+        Err(Error::ProtectedNodeMissing)
+    ));
+}
+
 #[ignore]
 #[tokio::test]
 async fn test_get_milestone_by_id() {
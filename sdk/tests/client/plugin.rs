@@ -0,0 +1,106 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::client::{
+    node_api::plugin::{NodePlugin, PluginRoute},
+    Error,
+};
+use serde::{Deserialize, Serialize};
+
+struct Participation;
+
+impl NodePlugin for Participation {
+    const NAME: &'static str = "participation/v1";
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EventStatus {
+    status: String,
+}
+
+struct EventStatusRoute {
+    event_id: String,
+    milestone_index: Option<u32>,
+}
+
+impl PluginRoute for EventStatusRoute {
+    type Plugin = Participation;
+    type Response = EventStatus;
+
+    const PATH: &'static str = "events/{eventId}/status";
+
+    fn path_parameters(&self) -> Vec<(&'static str, String)> {
+        vec![("eventId", self.event_id.clone())]
+    }
+
+    fn query_parameters(&self) -> Vec<(&'static str, String)> {
+        self.milestone_index
+            .map(|index| vec![("milestoneIndex", index.to_string())])
+            .unwrap_or_default()
+    }
+}
+
+struct MissingParameterRoute;
+
+impl PluginRoute for MissingParameterRoute {
+    type Plugin = Participation;
+    type Response = EventStatus;
+
+    const PATH: &'static str = "events/{eventId}/status";
+}
+
+#[test]
+fn plugin_route_path_and_query() {
+    let route = EventStatusRoute {
+        event_id: "0x1234".to_string(),
+        milestone_index: Some(10),
+    };
+
+    assert_eq!(route.path().unwrap(), "api/participation/v1/events/0x1234/status");
+    assert_eq!(route.query().as_deref(), Some("milestoneIndex=10"));
+
+    let route = EventStatusRoute {
+        event_id: "0x1234".to_string(),
+        milestone_index: None,
+    };
+
+    assert_eq!(route.query(), None);
+}
+
+#[test]
+fn plugin_route_path_parameters_are_encoded() {
+    for (event_id, path) in [
+        (
+            "../../core/v2/info",
+            "api/participation/v1/events/..%2F..%2Fcore%2Fv2%2Finfo/status",
+        ),
+        ("a b?c#d", "api/participation/v1/events/a%20b%3Fc%23d/status"),
+        ("{eventId}", "api/participation/v1/events/%7BeventId%7D/status"),
+        ("ü", "api/participation/v1/events/%C3%BC/status"),
+    ] {
+        let route = EventStatusRoute {
+            event_id: event_id.to_string(),
+            milestone_index: None,
+        };
+        assert_eq!(route.path().unwrap(), path);
+    }
+
+    for event_id in ["", ".", ".."] {
+        let route = EventStatusRoute {
+            event_id: event_id.to_string(),
+            milestone_index: None,
+        };
+        assert!(matches!(
+            route.path(),
+            Err(Error::InvalidPathParameter { name: "eventId", .. })
+        ));
+    }
+}
+
+#[test]
+fn plugin_route_missing_path_parameter() {
+    assert!(matches!(
+        MissingParameterRoute.path(),
+        Err(Error::MissingPathParameter(name)) if name == "eventId"
+    ));
+}
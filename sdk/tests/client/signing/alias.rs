@@ -100,7 +100,7 @@ async fn sign_alias_state_transition() -> Result<()> {
     let prepared_transaction_data = PreparedTransactionData {
         essence,
         inputs_data: inputs,
-        remainder: None,
+        remainders: Vec::new(),
     };
 
     let unlocks = secret_manager
@@ -191,7 +191,7 @@ async fn sign_alias_governance_transition() -> Result<()> {
     let prepared_transaction_data = PreparedTransactionData {
         essence,
         inputs_data: inputs,
-        remainder: None,
+        remainders: Vec::new(),
     };
 
     let unlocks = secret_manager
@@ -317,7 +317,7 @@ async fn alias_reference_unlocks() -> Result<()> {
     let prepared_transaction_data = PreparedTransactionData {
         essence,
         inputs_data: inputs,
-        remainder: None,
+        remainders: Vec::new(),
     };
 
     let unlocks = secret_manager
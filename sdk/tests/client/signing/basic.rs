@@ -15,14 +15,15 @@ use iota_sdk::{
     types::block::{
         address::ToBech32Ext,
         input::{Input, UtxoInput},
-        output::InputsCommitment,
+        output::{InputsCommitment, Output, OutputId},
         payload::{
             transaction::{RegularTransactionEssence, TransactionEssence},
             TransactionPayload,
         },
         protocol::protocol_parameters,
-        semantic::ConflictReason,
-        unlock::{SignatureUnlock, Unlock},
+        semantic::{semantic_validation, ConflictReason, ValidationContext},
+        signature::{Ed25519Signature, Signature},
+        unlock::{SignatureUnlock, Unlock, Unlocks},
     },
 };
 
@@ -83,7 +84,7 @@ async fn single_ed25519_unlock() -> Result<()> {
     let prepared_transaction_data = PreparedTransactionData {
         essence,
         inputs_data: inputs,
-        remainder: None,
+        remainders: Vec::new(),
     };
 
     let unlocks = secret_manager
@@ -185,7 +186,7 @@ async fn ed25519_reference_unlocks() -> Result<()> {
     let prepared_transaction_data = PreparedTransactionData {
         essence,
         inputs_data: inputs,
-        remainder: None,
+        remainders: Vec::new(),
     };
 
     let unlocks = secret_manager
@@ -297,7 +298,7 @@ async fn two_signature_unlocks() -> Result<()> {
     let prepared_transaction_data = PreparedTransactionData {
         essence,
         inputs_data: inputs,
-        remainder: None,
+        remainders: Vec::new(),
     };
 
     let unlocks = secret_manager
@@ -322,3 +323,83 @@ async fn two_signature_unlocks() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn invalid_signature_conflict() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+
+    let bech32_address_0 = &secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..1),
+        )
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Chain::from_u32_hardened([HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, 0])),
+    )]);
+
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = RegularTransactionEssence::builder(
+        protocol_parameters.network_id(),
+        InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+    )
+    .with_inputs(
+        inputs
+            .iter()
+            .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+            .collect::<Vec<_>>(),
+    )
+    .with_outputs(outputs)
+    .finish(&protocol_parameters)?;
+
+    // A signature that doesn't match the essence
+    let unlocks = Unlocks::new([Unlock::Signature(SignatureUnlock::new(Signature::Ed25519(
+        Ed25519Signature::new(
+            [0; Ed25519Signature::PUBLIC_KEY_LENGTH],
+            [0; Ed25519Signature::SIGNATURE_LENGTH],
+        ),
+    )))])?;
+    let tx_payload = TransactionPayload::new(TransactionEssence::Regular(essence), unlocks)?;
+    let TransactionEssence::Regular(essence) = tx_payload.essence();
+
+    let inputs = inputs
+        .iter()
+        .map(|i| (*i.output_id(), &i.output))
+        .collect::<Vec<(OutputId, &Output)>>();
+
+    let context = ValidationContext::new(
+        &tx_payload.id(),
+        essence,
+        inputs.iter().map(|(id, input)| (id, *input)),
+        tx_payload.unlocks(),
+        100,
+    );
+    assert_eq!(
+        semantic_validation(context, &inputs, tx_payload.unlocks())?,
+        ConflictReason::InvalidSignature
+    );
+
+    Ok(())
+}
@@ -386,7 +386,7 @@ async fn all_combined() -> Result<()> {
     let prepared_transaction_data = PreparedTransactionData {
         essence,
         inputs_data: selected.inputs,
-        remainder: None,
+        remainders: Vec::new(),
     };
 
     let unlocks = secret_manager
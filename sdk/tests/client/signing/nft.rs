@@ -127,7 +127,7 @@ async fn nft_reference_unlocks() -> Result<()> {
     let prepared_transaction_data = PreparedTransactionData {
         essence,
         inputs_data: inputs,
-        remainder: None,
+        remainders: Vec::new(),
     };
 
     let current_time = 100;
@@ -0,0 +1,47 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::api::core::{
+    request::{AddPeerRequest, PruneDatabaseRequest},
+    response::{CreateSnapshotsResponse, PruneDatabaseResponse},
+};
+
+#[test]
+fn prune_database_request() {
+    assert_eq!(
+        serde_json::to_string(&PruneDatabaseRequest::Index(1000)).unwrap(),
+        r#"{"index":1000}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&PruneDatabaseRequest::Depth(10)).unwrap(),
+        r#"{"depth":10}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&PruneDatabaseRequest::TargetDatabaseSize("4GB".to_string())).unwrap(),
+        r#"{"targetDatabaseSize":"4GB"}"#
+    );
+}
+
+#[test]
+fn add_peer_request() {
+    let request = AddPeerRequest {
+        multi_address: "/dns/example.com/tcp/15600/p2p/12D3KooWAbc".to_string(),
+        alias: None,
+    };
+
+    assert_eq!(
+        serde_json::to_string(&request).unwrap(),
+        r#"{"multiAddress":"/dns/example.com/tcp/15600/p2p/12D3KooWAbc"}"#
+    );
+}
+
+#[test]
+fn control_responses() {
+    let response: PruneDatabaseResponse = serde_json::from_str(r#"{"index":1000}"#).unwrap();
+    assert_eq!(response.index, 1000);
+
+    let response: CreateSnapshotsResponse =
+        serde_json::from_str(r#"{"index":1000,"filePath":"snapshots/full_snapshot.bin"}"#).unwrap();
+    assert_eq!(response.index, 1000);
+    assert_eq!(response.file_path, "snapshots/full_snapshot.bin");
+}
@@ -8,8 +8,8 @@ use iota_sdk::{
         BasicOutputBuilder, UnlockCondition,
     },
     wallet::{
-        account::types::{Balance, BalanceDto},
-        Result,
+        account::types::{Balance, BalanceDto, BalancePoint, HistoricalBalance, HistoricalBalanceDto},
+        Result, SendAmountParams,
     },
 };
 
@@ -123,6 +123,106 @@ fn balance_add_assign() {
     }
 }
 
+#[test]
+fn historical_balance_to_dto() {
+    let mut historical_balance = HistoricalBalance {
+        point: BalancePoint::Milestone { index: 5 },
+        balance: Balance::rand_mock(),
+        unknown_spent_outputs: Vec::new(),
+        before_first_sync: false,
+    };
+    assert!(historical_balance.is_complete());
+
+    let dto = HistoricalBalanceDto::from(&historical_balance);
+    assert_eq!(dto.balance, BalanceDto::from(&historical_balance.balance));
+    assert_eq!(
+        serde_json::to_value(dto.point).unwrap(),
+        serde_json::json!({ "type": "milestone", "index": 5 })
+    );
+
+    historical_balance.before_first_sync = true;
+    assert!(!historical_balance.is_complete());
+}
+
+#[ignore]
+#[tokio::test]
+async fn historical_balance() -> Result<()> {
+    let storage_path = "test-storage/historical_balance";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let balance_before_tx = account_0.sync(None).await?;
+    let milestone_before_tx = account_0
+        .client()
+        .get_info()
+        .await?
+        .node_info
+        .status
+        .confirmed_milestone
+        .index;
+
+    let amount = 1_000_000;
+    let tx = account_0
+        .send_amount(
+            [SendAmountParams::new(
+                *account_1.addresses().await?[0].address(),
+                amount,
+            )?],
+            None,
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    let balance_after_tx = account_0.sync(None).await?;
+
+    let historical_balance = account_0
+        .historical_balance(BalancePoint::Milestone {
+            index: milestone_before_tx,
+        })
+        .await?;
+    assert!(historical_balance.is_complete());
+    assert_eq!(
+        historical_balance.balance.base_coin().total(),
+        balance_before_tx.base_coin().total()
+    );
+
+    let latest_milestone = account_0
+        .client()
+        .get_info()
+        .await?
+        .node_info
+        .status
+        .confirmed_milestone
+        .index;
+    let historical_balance = account_0
+        .historical_balance(BalancePoint::Milestone {
+            index: latest_milestone,
+        })
+        .await?;
+    assert_eq!(
+        historical_balance.balance.base_coin().total(),
+        balance_after_tx.base_coin().total()
+    );
+    assert_eq!(
+        balance_before_tx.base_coin().total() - amount,
+        balance_after_tx.base_coin().total()
+    );
+
+    // Before the first sync the account can't know all outputs
+    let historical_balance = account_0
+        .historical_balance(BalancePoint::Timestamp { timestamp_seconds: 0 })
+        .await?;
+    assert!(historical_balance.before_first_sync);
+    assert_eq!(historical_balance.balance.base_coin().total(), 0);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn balance_expiration() -> Result<()> {
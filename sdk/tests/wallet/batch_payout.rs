@@ -0,0 +1,110 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::wallet::{
+    account::{BatchPayout, PayoutRecipient, PayoutRecipientState, PayoutStatus},
+    Error,
+};
+#[cfg(feature = "csv")]
+use iota_sdk::U256;
+
+const ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+#[cfg(feature = "csv")]
+const TOKEN_ID: &str = "0x087d205988b733d97fb145ae340e27a8b19554d1ceee64574d7e5ff66c45f69e7a0100000000";
+
+#[test]
+fn payout_recipients_from_json() {
+    let recipients = PayoutRecipient::from_json(&format!(
+        r#"[
+            {{ "address": "{ADDRESS}", "amount": "1000000" }},
+            {{ "id": "alice", "address": "{ADDRESS}", "amount": "2000000", "tag": "0x73616c617279" }}
+        ]"#
+    ))
+    .unwrap();
+
+    assert_eq!(recipients.len(), 2);
+    assert_eq!(recipients[0].id, "1");
+    assert_eq!(recipients[0].amount, 1_000_000);
+    assert_eq!(recipients[1].id, "alice");
+    assert_eq!(recipients[1].tag.as_deref(), Some("0x73616c617279"));
+}
+
+#[test]
+fn payout_recipients_from_json_reports_every_invalid_row() {
+    let result = PayoutRecipient::from_json(&format!(
+        r#"[
+            {{ "address": "invalid", "amount": "1000000" }},
+            {{ "address": "{ADDRESS}", "amount": "1000000" }},
+            {{ "address": "{ADDRESS}" }}
+        ]"#
+    ));
+
+    match result {
+        Err(Error::InvalidBatchPayout(errors)) => {
+            assert_eq!(errors.len(), 2);
+            assert!(errors[0].starts_with("row 1:"));
+            assert!(errors[1].starts_with("row 3:"));
+        }
+        _ => panic!("expected InvalidBatchPayout error"),
+    }
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn payout_recipients_from_csv() {
+    let recipients = PayoutRecipient::from_csv(&format!(
+        "id,address,amount,native_tokens,tag,metadata\n\
+        ,{ADDRESS},1000000,,,\n\
+        bob,{ADDRESS},2000000,{TOKEN_ID}:100,0x73616c617279,\n"
+    ))
+    .unwrap();
+
+    assert_eq!(recipients.len(), 2);
+    assert_eq!(recipients[0].id, "1");
+    assert!(recipients[0].native_tokens.is_empty());
+    assert_eq!(recipients[0].tag, None);
+    assert_eq!(recipients[1].id, "bob");
+    assert_eq!(recipients[1].native_tokens.len(), 1);
+    assert_eq!(recipients[1].native_tokens[0].amount(), U256::from(100));
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn payout_recipients_from_csv_reports_every_invalid_row() {
+    let result = PayoutRecipient::from_csv(&format!(
+        "address,amount,native_tokens\n\
+        {ADDRESS},abc,\n\
+        {ADDRESS},1000000,\n\
+        {ADDRESS},1000000,{TOKEN_ID}\n"
+    ));
+
+    match result {
+        Err(Error::InvalidBatchPayout(errors)) => {
+            assert_eq!(errors.len(), 2);
+            assert!(errors[0].starts_with("row 1:"));
+            assert!(errors[1].starts_with("row 3:"));
+        }
+        _ => panic!("expected InvalidBatchPayout error"),
+    }
+}
+
+#[test]
+fn batch_payout_serde() {
+    let recipients =
+        PayoutRecipient::from_json(&format!(r#"[{{ "address": "{ADDRESS}", "amount": "1000000" }}]"#)).unwrap();
+    let payout = BatchPayout {
+        id: "payroll".to_string(),
+        recipients: recipients
+            .into_iter()
+            .map(|recipient| PayoutRecipientState {
+                recipient,
+                status: PayoutStatus::Pending,
+            })
+            .collect(),
+    };
+
+    let json = serde_json::to_string(&payout).unwrap();
+
+    assert_eq!(serde_json::from_str::<BatchPayout>(&json).unwrap(), payout);
+    assert!(!payout.is_finished());
+}
@@ -12,10 +12,13 @@ use iota_sdk::{
         rand::output::{rand_basic_output, rand_inputs_commitment, rand_output_metadata},
     },
     wallet::{
-        account::types::{InclusionState, OutputData, OutputDataDto},
+        account::{
+            types::{InclusionState, OutputData, OutputDataDto},
+            ScheduledIntent, ScheduledIntentAction, ScheduledIntentKind,
+        },
         events::types::{
-            AddressData, NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, TransactionProgressEvent,
-            WalletEvent,
+            AddressData, NewOutputEvent, ScheduledIntentEvent, SpentOutputEvent, SyncProgressEvent, SyncStage,
+            TransactionInclusionEvent, TransactionPlanStepProgress, TransactionProgressEvent, WalletEvent,
         },
     },
 };
@@ -55,10 +58,15 @@ fn wallet_events_serde() {
         output: output_data_dto.clone(),
         transaction: None,
         transaction_inputs: None,
+        address_label: None,
+        tags: Vec::new(),
+        invoice_id: Some(3),
     })));
 
     assert_serde_eq(WalletEvent::SpentOutput(Box::new(SpentOutputEvent {
         output: output_data_dto,
+        address_label: Some("invoice #123".to_string()),
+        tags: vec!["rent".to_string()],
     })));
 
     assert_serde_eq(WalletEvent::TransactionInclusion(TransactionInclusionEvent {
@@ -70,6 +78,40 @@ fn wallet_events_serde() {
         TransactionProgressEvent::SelectingInputs,
     ));
 
+    assert_serde_eq(WalletEvent::ScheduledIntent(Box::new(ScheduledIntentEvent {
+        intent: ScheduledIntent {
+            id: 0,
+            kind: ScheduledIntentKind::ClaimOutput {
+                output_id: OutputId::null(),
+            },
+        },
+        action: ScheduledIntentAction::Executed {
+            transaction_id: TransactionId::null(),
+        },
+    })));
+
+    assert_serde_eq(WalletEvent::ScheduledIntent(Box::new(ScheduledIntentEvent {
+        intent: ScheduledIntent {
+            id: 1,
+            kind: ScheduledIntentKind::ReclaimExpiredOutputs,
+        },
+        action: ScheduledIntentAction::Failed {
+            error: "insufficient funds".to_string(),
+        },
+    })));
+
+    assert_serde_eq(WalletEvent::SyncProgress(SyncProgressEvent {
+        stage: SyncStage::AddressesScanned,
+        processed: 10,
+        total: Some(20),
+    }));
+
+    assert_serde_eq(WalletEvent::SyncProgress(SyncProgressEvent {
+        stage: SyncStage::OutputIdsFound,
+        processed: 5,
+        total: None,
+    }));
+
     assert_serde_eq(WalletEvent::TransactionProgress(
         TransactionProgressEvent::GeneratingRemainderDepositAddress(AddressData {
             address: Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")
@@ -103,7 +145,7 @@ fn wallet_events_serde() {
             TransactionProgressEvent::PreparedTransaction(Box::new(PreparedTransactionDataDto {
                 essence: (&essence).into(),
                 inputs_data: Vec::new(),
-                remainder: None,
+                remainders: Vec::new(),
             })),
         ));
     }
@@ -121,4 +163,12 @@ fn wallet_events_serde() {
     ));
 
     assert_serde_eq(WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting));
+
+    assert_serde_eq(WalletEvent::TransactionProgress(
+        TransactionProgressEvent::TransactionPlanStep(TransactionPlanStepProgress {
+            step: 1,
+            step_count: 3,
+            consolidation: false,
+        }),
+    ));
 }
@@ -0,0 +1,128 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use iota_sdk::{
+    types::block::{output::TokenId, payload::transaction::TransactionId},
+    wallet::{
+        account::{ExportDirection, ExportOptions, ExportRow, HistoryEntryKind, SyncOptions},
+        Result, SendAmountParams,
+    },
+};
+
+use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
+
+const ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+const TOKEN_ID: &str = "0x087d205988b733d97fb145ae340e27a8b19554d1ceee64574d7e5ff66c45f69e7a0100000000";
+const TRANSACTION_ID: &str = "0x84fe6b1796bddc022c9bc40206f0a692f4536b02aa8c13140264e2e01a3b7e4b";
+
+fn rows() -> Vec<ExportRow> {
+    let transaction_id = TransactionId::from_str(TRANSACTION_ID).unwrap();
+
+    vec![
+        ExportRow {
+            timestamp: 1_688_000_000_000,
+            milestone_index: Some(5),
+            transaction_id,
+            direction: ExportDirection::Outgoing,
+            kind: HistoryEntryKind::Outgoing,
+            counterparty: Some(ADDRESS.to_string()),
+            counterparty_name: Some("Alice".to_string()),
+            token_id: None,
+            amount: "1000000".to_string(),
+            storage_deposit: "47600".to_string(),
+            note: Some("salary".to_string()),
+            tags: Some("payroll;june".to_string()),
+        },
+        ExportRow {
+            timestamp: 1_688_000_000_000,
+            milestone_index: Some(5),
+            transaction_id,
+            direction: ExportDirection::Outgoing,
+            kind: HistoryEntryKind::Outgoing,
+            counterparty: Some(ADDRESS.to_string()),
+            counterparty_name: Some("Alice".to_string()),
+            token_id: Some(TokenId::from_str(TOKEN_ID).unwrap()),
+            amount: "100".to_string(),
+            storage_deposit: "0".to_string(),
+            note: Some("salary".to_string()),
+            tags: None,
+        },
+    ]
+}
+
+#[test]
+fn export_rows_to_json() {
+    let rows = rows();
+    let json = ExportRow::to_json(&rows).unwrap();
+
+    assert_eq!(serde_json::from_str::<Vec<ExportRow>>(&json).unwrap(), rows);
+    assert!(json.contains(r#""timestamp": "1688000000000""#));
+    assert!(json.contains(r#""direction": "outgoing""#));
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn export_rows_to_csv() {
+    let csv = ExportRow::to_csv(&rows()).unwrap();
+
+    assert_eq!(
+        csv,
+        format!(
+            "timestamp,milestoneIndex,transactionId,direction,kind,counterparty,counterpartyName,tokenId,amount,\
+            storageDeposit,note,tags\n\
+            1688000000000,5,{TRANSACTION_ID},outgoing,outgoing,{ADDRESS},Alice,,1000000,47600,salary,payroll;june\n\
+            1688000000000,5,{TRANSACTION_ID},outgoing,outgoing,{ADDRESS},Alice,{TOKEN_ID},100,0,salary,\n"
+        )
+    );
+}
+
+#[ignore]
+#[tokio::test]
+async fn export_history() -> Result<()> {
+    let storage_path = "test-storage/export_history";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let recipient_address = *account_1.addresses().await?[0].address();
+
+    let amount = 1_000_000;
+    let tx = account_0
+        .send_amount([SendAmountParams::new(recipient_address, amount)?], None)
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_0
+        .sync(Some(SyncOptions {
+            sync_incoming_transactions: true,
+            ..Default::default()
+        }))
+        .await?;
+
+    let rows = account_0.export_history(ExportOptions::default()).await?;
+    let row = rows.iter().find(|row| row.transaction_id == tx.transaction_id).unwrap();
+    assert_eq!(row.direction, ExportDirection::Outgoing);
+    assert_eq!(row.token_id, None);
+    assert_eq!(row.amount, amount.to_string());
+    assert_eq!(row.counterparty, Some(recipient_address.to_string()));
+    assert!(row.milestone_index.is_some());
+
+    // Exports of the same account state are identical
+    assert_eq!(rows, account_0.export_history(ExportOptions::default()).await?);
+
+    // Date ranges
+    let rows = account_0
+        .export_history(ExportOptions {
+            lower_bound_timestamp: Some(row.timestamp + 1),
+            ..Default::default()
+        })
+        .await?;
+    assert!(rows.iter().all(|row| row.transaction_id != tx.transaction_id));
+
+    tear_down(storage_path)
+}
@@ -0,0 +1,150 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use iota_sdk::{
+    types::block::{
+        address::Bech32Address,
+        output::{NativeToken, TokenId},
+    },
+    wallet::{
+        account::{Invoice, InvoiceParams, InvoiceStatus, SyncOptions},
+        Error, Result, SendAmountParams,
+    },
+    U256,
+};
+
+use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
+
+const ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+const TOKEN_ID: &str = "0x087d205988b733d97fb145ae340e27a8b19554d1ceee64574d7e5ff66c45f69e7a0100000000";
+
+fn invoice() -> Invoice {
+    Invoice {
+        id: 0,
+        address: Bech32Address::try_from_str(ADDRESS).unwrap(),
+        amount: 1_000_000,
+        native_tokens: vec![NativeToken::new(TokenId::from_str(TOKEN_ID).unwrap(), U256::from(100)).unwrap()],
+        reference: Some("invoice #123".to_string()),
+        expiration: Some(1_700_000_000),
+        description: None,
+        timestamp: 1_688_000_000_000,
+        status: InvoiceStatus::Pending,
+        outputs: Vec::new(),
+        received_amount: 0,
+        received_native_tokens: Vec::new(),
+    }
+}
+
+#[test]
+fn invoice_serde() {
+    let invoice = invoice();
+    let json = serde_json::to_value(&invoice).unwrap();
+
+    assert_eq!(json["amount"], "1000000");
+    assert_eq!(json["status"], "pending");
+    assert_eq!(serde_json::from_value::<Invoice>(json).unwrap(), invoice);
+}
+
+#[test]
+fn invoice_to_uri() {
+    assert_eq!(
+        invoice().to_uri().unwrap(),
+        format!("smr:{ADDRESS}?amount=1000000&token={TOKEN_ID}%3A100&tag=invoice+%23123&expiration=1700000000")
+    );
+}
+
+#[tokio::test]
+async fn account_invoices() -> Result<()> {
+    let storage_path = "test-storage/account_invoices";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+    let first_address = *account.addresses().await?[0].address();
+
+    assert!(matches!(
+        account.create_invoice(InvoiceParams::default()).await,
+        Err(Error::Invoice(_))
+    ));
+
+    // Invoices without a reference get a fresh address
+    let invoice = account
+        .create_invoice(InvoiceParams {
+            amount: 1_000_000,
+            ..Default::default()
+        })
+        .await?;
+    assert_ne!(invoice.address, first_address);
+    assert_eq!(invoice.status, InvoiceStatus::Pending);
+    assert_eq!(account.addresses().await?.len(), 2);
+
+    let params = InvoiceParams {
+        amount: 2_000_000,
+        reference: Some("invoice #123".to_string()),
+        ..Default::default()
+    };
+    let referenced_invoice = account.create_invoice(params.clone()).await?;
+    assert_eq!(referenced_invoice.address, first_address);
+    assert_eq!(referenced_invoice.id, invoice.id + 1);
+    // References of unpaid invoices are unique
+    assert!(matches!(account.create_invoice(params).await, Err(Error::Invoice(_))));
+
+    assert_eq!(
+        account.invoices().await,
+        vec![invoice.clone(), referenced_invoice.clone()]
+    );
+    assert_eq!(account.get_invoice(invoice.id).await, Some(invoice.clone()));
+
+    account.remove_invoice(invoice.id).await?;
+    assert_eq!(account.get_invoice(invoice.id).await, None);
+    assert!(account.remove_invoice(invoice.id).await.is_err());
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn invoice_matching() -> Result<()> {
+    let storage_path = "test-storage/invoice_matching";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let amount = 1_000_000;
+    let invoice = account_1
+        .create_invoice(InvoiceParams {
+            amount,
+            ..Default::default()
+        })
+        .await?;
+
+    // Underpay first
+    let tx = account_0
+        .send_amount([SendAmountParams::new(invoice.address, amount / 2)?], None)
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_1.sync(Some(SyncOptions::default())).await?;
+    let invoice = account_1.get_invoice(invoice.id).await.unwrap();
+    assert_eq!(invoice.status, InvoiceStatus::Underpaid);
+    assert_eq!(invoice.received_amount, amount / 2);
+
+    let tx = account_0
+        .send_amount([SendAmountParams::new(invoice.address, amount / 2)?], None)
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_1.sync(Some(SyncOptions::default())).await?;
+    let invoice = account_1.get_invoice(invoice.id).await.unwrap();
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.outputs.len(), 2);
+
+    tear_down(storage_path)
+}
@@ -0,0 +1,122 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use iota_sdk::{
+    types::block::{
+        address::{Address, Bech32Address},
+        output::OutputId,
+    },
+    wallet::{
+        account::{AddressLabel, Contact, LabelMatch, Labels},
+        Error, Result,
+    },
+};
+
+use crate::wallet::common::{make_wallet, setup, tear_down};
+
+const ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+const OUTPUT_ID: &str = "0xbce525324af12eda02bf7927e92cea3a8e8322d0f41966271443e6c3b245a4400000";
+
+fn labels() -> Labels {
+    let address = Bech32Address::try_from_str(ADDRESS).unwrap();
+    let output_id = OutputId::from_str(OUTPUT_ID).unwrap();
+
+    Labels {
+        contacts: vec![Contact {
+            address,
+            name: "Alice".to_string(),
+        }],
+        address_labels: vec![AddressLabel {
+            address,
+            label: "Invoice #123".to_string(),
+        }],
+        output_tags: [(output_id, vec!["rent".to_string(), "invoice".to_string()])].into(),
+    }
+}
+
+#[test]
+fn labels_serde() {
+    let labels = labels();
+    let json = serde_json::to_string(&labels).unwrap();
+
+    assert_eq!(serde_json::from_str::<Labels>(&json).unwrap(), labels);
+    // Accounts stored before labels existed have none
+    assert_eq!(serde_json::from_str::<Labels>("{}").unwrap(), Labels::default());
+}
+
+#[test]
+fn labels_search() {
+    let labels = labels();
+    let address = Bech32Address::try_from_str(ADDRESS).unwrap();
+
+    assert_eq!(labels.name(address.inner()), Some("Alice"));
+    assert_eq!(labels.address_label(address.inner()), Some("Invoice #123"));
+
+    let matches = labels.search("INVOICE");
+    assert_eq!(
+        matches,
+        vec![
+            LabelMatch::AddressLabel(labels.address_labels[0].clone()),
+            LabelMatch::OutputTag {
+                output_id: OutputId::from_str(OUTPUT_ID).unwrap(),
+                tag: "invoice".to_string(),
+            },
+        ]
+    );
+    assert_eq!(
+        serde_json::to_value(&matches[1]).unwrap(),
+        serde_json::json!({ "type": "outputTag", "outputId": OUTPUT_ID, "tag": "invoice" })
+    );
+    assert!(labels.search("bob").is_empty());
+}
+
+#[tokio::test]
+async fn account_labels() -> Result<()> {
+    let storage_path = "test-storage/account_labels";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let account_address = *account.addresses().await?[0].address();
+
+    // Contacts are renamed instead of duplicated
+    account.set_contact(ADDRESS, "Bob").await?;
+    account.set_contact(ADDRESS, "Bobby").await?;
+    let contacts = account.labels().await.contacts;
+    assert_eq!(contacts.len(), 1);
+    assert_eq!(contacts[0].name, "Bobby");
+
+    // Only addresses of the account can be labeled
+    account
+        .set_address_label(account_address, Some("invoice #123".to_string()))
+        .await?;
+    assert!(matches!(
+        account
+            .set_address_label(ADDRESS, Some("invoice #124".to_string()))
+            .await,
+        Err(Error::AddressNotFoundInAccount(_))
+    ));
+    assert_eq!(
+        account.labels().await.address_label(account_address.inner()),
+        Some("invoice #123")
+    );
+
+    // Only outputs of the account can be tagged
+    let output_id = OutputId::from_str(OUTPUT_ID)?;
+    assert!(matches!(
+        account.add_output_tag(output_id, "rent").await,
+        Err(Error::OutputNotFound(id)) if id == output_id
+    ));
+
+    assert_eq!(account.search_labels("bob").await.len(), 1);
+    assert_eq!(account.search_labels("123").await.len(), 1);
+
+    account.remove_contact(ADDRESS).await?;
+    account.set_address_label(account_address, None).await?;
+    assert_eq!(account.labels().await, Labels::default());
+    assert_eq!(account.labels().await.name(&Address::try_from_bech32(ADDRESS)?), None);
+
+    tear_down(storage_path)
+}
@@ -5,6 +5,8 @@ mod account_recovery;
 mod accounts;
 mod backup_restore;
 mod balance;
+#[cfg(feature = "storage")]
+mod batch_payout;
 mod bech32_hrp_validation;
 mod burn_outputs;
 mod claim_outputs;
@@ -13,12 +15,18 @@ mod consolidation;
 mod error;
 #[cfg(feature = "events")]
 mod events;
+mod export;
+mod invoices;
+mod labels;
 #[cfg(feature = "message_interface")]
 mod message_interface;
 mod migrate_stronghold_snapshot_v2_to_v3;
 mod native_tokens;
+mod nft_collection;
 mod output_preparation;
+mod payment_uri;
 mod syncing;
+mod transaction_plan;
 mod transactions;
 #[allow(clippy::module_inception)]
 mod wallet;
@@ -0,0 +1,134 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    types::block::address::Bech32Address,
+    wallet::{
+        account::{CollectionNftParams, Irc27Metadata},
+        Error, Result,
+    },
+};
+
+use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
+
+const ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+
+fn metadata() -> Irc27Metadata {
+    Irc27Metadata::new("image/png", "https://example.com/nft.png", "Shimmer OG NFT #1")
+        .with_collection_name("Shimmer OG".to_string())
+        .with_royalty(Bech32Address::try_from_str(ADDRESS).unwrap(), 0.05)
+        .with_attribute("rarity", "rare")
+        .with_attribute("level", 3)
+}
+
+#[test]
+fn irc27_metadata_serde() {
+    let metadata = metadata();
+    let json = serde_json::to_value(&metadata).unwrap();
+
+    assert_eq!(json["standard"], "IRC27");
+    assert_eq!(json["version"], "v1.0");
+    assert_eq!(json["type"], "image/png");
+    assert_eq!(json["collectionName"], "Shimmer OG");
+    assert_eq!(json["royalties"][ADDRESS], 0.05);
+    assert_eq!(json["attributes"][1]["trait_type"], "level");
+    assert!(json.get("description").is_none());
+
+    assert_eq!(
+        Irc27Metadata::from_bytes(&metadata.to_bytes().unwrap()).unwrap(),
+        metadata
+    );
+
+    // Fields that aren't part of the standard are ignored
+    let metadata = Irc27Metadata::from_bytes(
+        br#"{"standard":"IRC27","version":"v1.0","type":"video/mp4","uri":"ipfs://nft","name":"Shimmer OG NFT #0","collectionId":"0x13c4"}"#,
+    )
+    .unwrap();
+    assert_eq!(metadata.media_type, "video/mp4");
+    assert_eq!(metadata.collection_name, None);
+}
+
+#[test]
+fn irc27_metadata_validation() {
+    metadata().validate().unwrap();
+
+    let mut invalid = Vec::new();
+
+    let mut standard = metadata();
+    standard.standard = "IRC30".to_string();
+    invalid.push(standard);
+
+    let mut version = metadata();
+    version.version = "v2.0".to_string();
+    invalid.push(version);
+
+    invalid.push(Irc27Metadata::new("png", "https://example.com/nft.png", "NFT"));
+    invalid.push(Irc27Metadata::new("image/png", "", "NFT"));
+    invalid.push(Irc27Metadata::new("image/png", "https://example.com/nft.png", ""));
+
+    let mut royalty_address = metadata();
+    royalty_address.royalties.insert("rms1invalid".to_string(), 0.1);
+    invalid.push(royalty_address);
+
+    invalid.push(metadata().with_royalty(Bech32Address::try_from_str(ADDRESS).unwrap(), 0.0));
+
+    let mut royalties_total = metadata();
+    royalties_total.royalties.insert(
+        "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+        0.96,
+    );
+    invalid.push(royalties_total);
+
+    invalid.push(metadata().with_attribute("animated", true));
+
+    for metadata in invalid {
+        assert!(
+            matches!(metadata.validate(), Err(Error::Irc27Metadata(_))),
+            "{metadata:?} should be invalid"
+        );
+    }
+}
+
+#[ignore]
+#[tokio::test]
+async fn mint_nft_collection() -> Result<()> {
+    let storage_path = "test-storage/mint_nft_collection";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let issuer_nft_id = account
+        .mint_collection_issuer(
+            Irc27Metadata::new("image/png", "https://example.com/collection.png", "Collection"),
+            None,
+        )
+        .await?;
+
+    // Invalid metadata is rejected before anything is minted
+    let invalid = vec![CollectionNftParams {
+        address: None,
+        metadata: Irc27Metadata::new("png", "https://example.com/nft.png", "NFT"),
+    }];
+    assert!(matches!(
+        account.mint_collection_nfts(issuer_nft_id, invalid, None).await,
+        Err(Error::Irc27Metadata(_))
+    ));
+
+    // More NFTs than fit into a single transaction
+    let nfts = (0..130)
+        .map(|index| CollectionNftParams {
+            address: None,
+            metadata: Irc27Metadata::new("image/png", "https://example.com/nft.png", format!("NFT #{index}"))
+                .with_collection_name("Collection".to_string()),
+        })
+        .collect::<Vec<_>>();
+    let transactions = account.mint_collection_nfts(issuer_nft_id, nfts, None).await?;
+    assert_eq!(transactions.len(), 2);
+
+    let collection_nfts = account.collection_nfts(issuer_nft_id).await?;
+    assert_eq!(collection_nfts.len(), 130);
+    assert!(collection_nfts.iter().all(|nft| nft.metadata.is_some()));
+
+    tear_down(storage_path)
+}
@@ -0,0 +1,161 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use iota_sdk::{
+    types::block::{
+        address::Bech32Address,
+        output::{NativeToken, NftId, RentStructure, TokenId},
+        protocol::ProtocolParameters,
+    },
+    wallet::{
+        account::{Assets, OutputParams, PaymentUri, Unlocks},
+        Error,
+    },
+    U256,
+};
+
+const ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+const TOKEN_ID: &str = "0x087d205988b733d97fb145ae340e27a8b19554d1ceee64574d7e5ff66c45f69e7a0100000000";
+const NFT_ID: &str = "0x5c6e1ec8fe5ad0bb6a8c0a1e6b5bbbff8d3e3d1c1e1f1c1b1a19181716151413";
+
+fn payment_uri() -> PaymentUri {
+    PaymentUri {
+        address: Bech32Address::try_from_str(ADDRESS).unwrap(),
+        amount: 1_000_000,
+        native_tokens: vec![NativeToken::new(TokenId::from_str(TOKEN_ID).unwrap(), U256::from(100)).unwrap()],
+        nft_id: Some(NftId::from_str(NFT_ID).unwrap()),
+        tag: Some("order #42".to_string()),
+        metadata: Some("coffee".to_string()),
+        expiration: Some(1_700_000_000),
+        message: Some("thanks!".to_string()),
+    }
+}
+
+fn protocol_parameters(bech32_hrp: &str) -> ProtocolParameters {
+    ProtocolParameters::new(
+        2,
+        String::from("testnet"),
+        bech32_hrp,
+        1500,
+        15,
+        RentStructure::default(),
+        1_813_620_509_061_365,
+    )
+    .unwrap()
+}
+
+#[test]
+fn payment_uri_roundtrip() {
+    let payment_uri = payment_uri();
+    let uri = payment_uri.to_uri().unwrap();
+
+    assert_eq!(
+        uri,
+        format!(
+            "smr:{ADDRESS}?amount=1000000&token={TOKEN_ID}%3A100&nft={NFT_ID}&tag=order+%2342&metadata=coffee&expiration=1700000000&message=thanks%21"
+        )
+    );
+    assert_eq!(PaymentUri::from_str(&uri).unwrap(), payment_uri);
+    assert_eq!(
+        PaymentUri::parse(&uri, &protocol_parameters("rms")).unwrap(),
+        payment_uri
+    );
+
+    let address_only = PaymentUri::new(Bech32Address::try_from_str(ADDRESS).unwrap());
+    assert_eq!(address_only.to_uri().unwrap(), format!("smr:{ADDRESS}"));
+    assert_eq!(PaymentUri::from_str(&format!("smr:{ADDRESS}")).unwrap(), address_only);
+    // The scheme is case-insensitive
+    assert_eq!(PaymentUri::from_str(&format!("SMR:{ADDRESS}")).unwrap(), address_only);
+
+    // No scheme for addresses of other networks
+    let unknown_hrp = PaymentUri::new(Bech32Address::try_new("tst", *address_only.address.inner()).unwrap());
+    assert!(matches!(unknown_hrp.to_uri(), Err(Error::PaymentUri(_))));
+}
+
+#[test]
+fn payment_uri_strict_parsing() {
+    for uri in [
+        ADDRESS.to_string(),
+        format!("iota:{ADDRESS}"),
+        format!("smr:{ADDRESS}?"),
+        "smr:rms1invalid".to_string(),
+        format!("smr:{ADDRESS}?amount=1&amount=2"),
+        format!("smr:{ADDRESS}?amount=+1"),
+        format!("smr:{ADDRESS}?amount=0"),
+        format!("smr:{ADDRESS}?amount=1.5"),
+        format!("smr:{ADDRESS}?amount=18446744073709551616"),
+        format!("smr:{ADDRESS}?tag="),
+        format!("smr:{ADDRESS}?token={TOKEN_ID}"),
+        format!("smr:{ADDRESS}?token={TOKEN_ID}:0"),
+        format!("smr:{ADDRESS}?token={TOKEN_ID}:1&token={TOKEN_ID}:2"),
+        format!("smr:{ADDRESS}?nft=0x01"),
+        format!("smr:{ADDRESS}?expiration=-1"),
+        format!("smr:{ADDRESS}?label=coffee"),
+    ] {
+        assert!(
+            matches!(PaymentUri::from_str(&uri), Err(Error::PaymentUri(_))),
+            "{uri} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn payment_uri_validation() {
+    let payment_uri = payment_uri();
+
+    payment_uri.validate(&protocol_parameters("rms")).unwrap();
+    // Shimmer mainnet address expected
+    assert!(payment_uri.validate(&protocol_parameters("smr")).is_err());
+
+    let mut too_much = payment_uri.clone();
+    too_much.amount = 1_813_620_509_061_366;
+    assert!(matches!(
+        too_much.validate(&protocol_parameters("rms")),
+        Err(Error::PaymentUri(_))
+    ));
+
+    let mut long_tag = payment_uri;
+    long_tag.tag = Some("a".repeat(65));
+    assert!(matches!(
+        PaymentUri::parse(&long_tag.to_uri().unwrap(), &protocol_parameters("rms")),
+        Err(Error::PaymentUri(_))
+    ));
+}
+
+#[test]
+fn payment_uri_into_output_params() {
+    let output_params = OutputParams::from(payment_uri());
+
+    assert_eq!(output_params.recipient_address.to_string(), ADDRESS);
+    assert_eq!(output_params.amount, 1_000_000);
+    assert_eq!(
+        output_params.assets,
+        Some(Assets {
+            native_tokens: Some(vec![NativeToken::new(
+                TokenId::from_str(TOKEN_ID).unwrap(),
+                U256::from(100)
+            )
+            .unwrap()]),
+            nft_id: Some(NftId::from_str(NFT_ID).unwrap()),
+        })
+    );
+    let features = output_params.features.unwrap();
+    assert_eq!(features.tag.as_deref(), Some("0x6f7264657220233432"));
+    assert_eq!(features.metadata.as_deref(), Some("0x636f66666565"));
+    assert_eq!(
+        output_params.unlocks,
+        Some(Unlocks {
+            expiration_unix_time: Some(1_700_000_000),
+            timelock_unix_time: None,
+        })
+    );
+    assert_eq!(output_params.storage_deposit, None);
+
+    let output_params = OutputParams::from(PaymentUri::new(Bech32Address::try_from_str(ADDRESS).unwrap()));
+    assert_eq!(output_params.amount, 0);
+    assert_eq!(output_params.assets, None);
+    assert_eq!(output_params.features, None);
+    assert_eq!(output_params.unlocks, None);
+}
@@ -1,7 +1,17 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
+#[cfg(feature = "events")]
+use iota_sdk::wallet::events::types::WalletEventType;
 use iota_sdk::{
+    client::{
+        api::GetAddressesOptions,
+        constants::SHIMMER_COIN_TYPE,
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+        Client,
+    },
     types::block::output::{
         unlock_condition::{
             AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
@@ -9,7 +19,10 @@ use iota_sdk::{
         },
         AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, UnlockCondition,
     },
-    wallet::{account::SyncOptions, Result},
+    wallet::{
+        account::{CancellationToken, SyncOptions},
+        Error, Result, SendAmountParams,
+    },
 };
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
@@ -45,6 +58,74 @@ async fn updated_default_sync_options() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+#[cfg(feature = "rocksdb")]
+async fn cancelled_sync() -> Result<()> {
+    let storage_path = "test-storage/cancelled_sync";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let cancellation = CancellationToken::new();
+    cancellation.cancel();
+    assert!(matches!(
+        account.sync_with_cancellation(None, &cancellation).await,
+        Err(Error::SyncCancelled)
+    ));
+    assert!(account.unspent_outputs(None).await?.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+#[cfg(feature = "events")]
+async fn cancelled_sync_in_progress() -> Result<()> {
+    let storage_path = "test-storage/cancelled_sync_in_progress";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let tx = account_0
+        .send_amount(
+            [SendAmountParams::new(
+                *account_1.addresses().await?[0].address(),
+                1_000_000,
+            )?],
+            None,
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    // Cancel the sync once it reports its first progress
+    let cancellation = CancellationToken::new();
+    let token = cancellation.clone();
+    wallet
+        .listen([WalletEventType::SyncProgress], move |_event| token.cancel())
+        .await;
+
+    assert!(matches!(
+        account_1.sync_with_cancellation(None, &cancellation).await,
+        Err(Error::SyncCancelled)
+    ));
+    // Nothing of the cancelled sync was applied
+    assert!(account_1.unspent_outputs(None).await?.is_empty());
+    assert_eq!(account_1.balance().await?.base_coin().total(), 0);
+
+    // A new sync finds the output
+    wallet.clear_listeners([]).await;
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn sync_only_most_basic_outputs() -> Result<()> {
@@ -148,3 +229,96 @@ async fn sync_only_most_basic_outputs() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn incremental_sync() -> Result<()> {
+    let storage_path = "test-storage/incremental_sync";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let sync_options = SyncOptions {
+        incremental: true,
+        force_syncing: true,
+        ..Default::default()
+    };
+
+    assert!(account_1.synced_milestone().await.is_none());
+    // The first sync is a full sync
+    account_1.sync(Some(sync_options.clone())).await?;
+    let synced_milestone = account_1.synced_milestone().await.unwrap();
+
+    let tx = account_0
+        .send_amount(
+            [SendAmountParams::new(
+                *account_1.addresses().await?[0].address(),
+                1_000_000,
+            )?],
+            None,
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    // The output is found from the UTXO changes of the milestones since the last sync
+    let balance = account_1.sync(Some(sync_options.clone())).await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    assert!(account_1.synced_milestone().await.unwrap().index > synced_milestone.index);
+
+    // New addresses require a full sync again
+    account_1.generate_ed25519_addresses(1, None).await?;
+    let balance = account_1.sync(Some(sync_options)).await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    assert_eq!(account_1.synced_milestone().await.unwrap().address_count, 2);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn sync_with_address_gap_limit() -> Result<()> {
+    let storage_path = "test-storage/sync_with_address_gap_limit";
+    setup(storage_path)?;
+
+    let mnemonic = Client::generate_mnemonic()?;
+    let client = Client::builder()
+        .with_node(crate::wallet::common::NODE_LOCAL)?
+        .finish()
+        .await?;
+
+    let secret_manager = SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(&mnemonic)?);
+
+    let addresses = secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::from_client(&client)
+                .await?
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_account_index(0)
+                .with_range(2..3),
+        )
+        .await?;
+
+    // Add funds to the address with key_index 2, which isn't generated by the account yet
+    iota_sdk::client::request_funds_from_faucet(crate::wallet::common::FAUCET_URL, &addresses[0]).await?;
+
+    // Wait for faucet transaction
+    tokio::time::sleep(Duration::new(10, 0)).await;
+
+    let wallet = make_wallet(storage_path, Some(&mnemonic), None).await?;
+    let account = wallet.create_account().with_address_gap_limit(3).finish().await?;
+
+    let balance = account.sync(None).await?;
+    assert!(balance.base_coin().total() > 0);
+
+    // 3 unused public addresses after the used one with key_index 2, and 3 unused internal addresses
+    let addresses = account.addresses().await?;
+    assert_eq!(addresses.iter().filter(|a| !a.internal()).count(), 6);
+    assert_eq!(addresses.iter().filter(|a| *a.internal()).count(), 3);
+
+    tear_down(storage_path)
+}
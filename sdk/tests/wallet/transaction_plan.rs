@@ -0,0 +1,109 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    types::block::{
+        address::{Address, Ed25519Address},
+        input::INPUT_COUNT_MAX,
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Output, OUTPUT_COUNT_MAX},
+        protocol::protocol_parameters,
+    },
+    wallet::account::{TransactionOptions, TransactionPlan, TransactionPlanStepKind},
+};
+
+fn basic_outputs(count: usize, amount: u64) -> Vec<Output> {
+    let token_supply = protocol_parameters().token_supply();
+
+    (0..count)
+        .map(|_| {
+            BasicOutputBuilder::new_with_amount(amount)
+                .add_unlock_condition(AddressUnlockCondition::new(Address::Ed25519(Ed25519Address::new(
+                    [0; Ed25519Address::LENGTH],
+                ))))
+                .finish_output(token_supply)
+                .unwrap()
+        })
+        .collect()
+}
+
+fn send_batch_sizes(transaction_plan: &TransactionPlan) -> Vec<usize> {
+    transaction_plan
+        .steps
+        .iter()
+        .filter_map(|step| match &step.kind {
+            TransactionPlanStepKind::Send(outputs) => Some(outputs.len()),
+            TransactionPlanStepKind::Consolidation => None,
+        })
+        .collect()
+}
+
+fn consolidation_count(transaction_plan: &TransactionPlan) -> usize {
+    transaction_plan
+        .steps
+        .iter()
+        .filter(|step| step.kind == TransactionPlanStepKind::Consolidation)
+        .count()
+}
+
+#[test]
+fn plan_single_transaction() {
+    let transaction_plan = TransactionPlan::new(basic_outputs(10, 1_000_000), None, [20_000_000, 1_000_000]);
+
+    assert_eq!(transaction_plan.steps.len(), 1);
+    assert_eq!(send_batch_sizes(&transaction_plan), [10]);
+    assert!(!transaction_plan.is_finished());
+}
+
+#[test]
+fn plan_batches_outputs() {
+    let transaction_plan = TransactionPlan::new(basic_outputs(300, 1_000_000), None, [300_000_000]);
+    let batch_size = OUTPUT_COUNT_MAX as usize - 1;
+
+    assert_eq!(consolidation_count(&transaction_plan), 0);
+    assert_eq!(
+        send_batch_sizes(&transaction_plan),
+        [batch_size, batch_size, 300 - 2 * batch_size]
+    );
+}
+
+#[test]
+fn plan_consolidates_inputs() {
+    // Exactly INPUT_COUNT_MAX inputs are needed, no consolidation
+    let transaction_plan = TransactionPlan::new(
+        basic_outputs(1, INPUT_COUNT_MAX as u64 * 1_000_000),
+        None,
+        vec![1_000_000; 300],
+    );
+    assert_eq!(consolidation_count(&transaction_plan), 0);
+
+    // 300 inputs are needed, each consolidation merges INPUT_COUNT_MAX of them into one
+    let transaction_plan = TransactionPlan::new(basic_outputs(1, 300_000_000), None, vec![1_000_000; 300]);
+    assert_eq!(consolidation_count(&transaction_plan), 2);
+    assert_eq!(transaction_plan.steps[0].kind, TransactionPlanStepKind::Consolidation);
+    assert_eq!(send_batch_sizes(&transaction_plan), [1]);
+
+    // Large inputs are used first
+    let mut available_amounts = vec![1_000_000; 300];
+    available_amounts.push(250_000_000);
+    let transaction_plan = TransactionPlan::new(basic_outputs(1, 300_000_000), None, available_amounts);
+    assert_eq!(consolidation_count(&transaction_plan), 0);
+}
+
+#[test]
+fn transaction_plan_serde() {
+    let transaction_plan = TransactionPlan::new(
+        basic_outputs(200, 1_000_000),
+        TransactionOptions {
+            note: Some("payout".to_string()),
+            ..Default::default()
+        },
+        vec![1_000_000; 300],
+    );
+
+    let json = serde_json::to_string(&transaction_plan).unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<TransactionPlan>(&json).unwrap(),
+        transaction_plan
+    );
+}
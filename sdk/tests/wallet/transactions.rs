@@ -1,7 +1,10 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::TransactionOptions, MintNftParams, Result, SendAmountParams, SendNftParams};
+use iota_sdk::wallet::{
+    account::{types::InclusionState, HistoryEntryKind, HistoryOptions, SyncOptions, TransactionOptions},
+    Error, MintNftParams, Result, SendAmountParams, SendNftParams,
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -162,3 +165,162 @@ async fn send_nft() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn cancel_transaction() -> Result<()> {
+    let storage_path = "test-storage/cancel_transaction";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let tx = account_0
+        .send_amount(
+            [SendAmountParams::new(
+                *account_1.addresses().await?[0].address(),
+                1_000_000,
+            )?],
+            None,
+        )
+        .await?;
+
+    let replacement = account_0.cancel_transaction(&tx.transaction_id, None).await?;
+    assert_eq!(replacement.inputs.len(), tx.inputs.len());
+
+    // Can't be replaced twice
+    assert!(matches!(
+        account_0.cancel_transaction(&tx.transaction_id, None).await,
+        Err(Error::TransactionNotReplaceable(..))
+    ));
+
+    account_0
+        .retry_transaction_until_included(&replacement.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+
+    let tx = account_0.get_transaction(&tx.transaction_id).await.unwrap();
+    assert_eq!(tx.inclusion_state, InclusionState::Conflicting);
+    assert!(account_0.pending_transactions().await.is_empty());
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 0);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_with_unconfirmed_inputs() -> Result<()> {
+    let storage_path = "test-storage/send_with_unconfirmed_inputs";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let amount = 1_000_000;
+
+    let tx_0 = account_0
+        .send_amount(
+            [SendAmountParams::new(
+                *account_1.addresses().await?[0].address(),
+                amount,
+            )?],
+            None,
+        )
+        .await?;
+
+    // The remainder of the pending transaction is spent right away
+    let tx_1 = account_0
+        .send_amount(
+            [SendAmountParams::new(
+                *account_1.addresses().await?[0].address(),
+                amount,
+            )?],
+            TransactionOptions {
+                allow_unconfirmed_inputs: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert!(tx_1
+        .inputs
+        .iter()
+        .any(|input| input.metadata.transaction_id == tx_0.transaction_id.to_string()));
+
+    account_0
+        .retry_transaction_until_included(&tx_1.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+    assert!(account_0.pending_transactions().await.is_empty());
+    assert!(account_0.transactions_to_replan().await.is_empty());
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 2 * amount);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn history() -> Result<()> {
+    let storage_path = "test-storage/history";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let sender_address = *account_0.addresses().await?[0].address();
+    let recipient_address = *account_1.addresses().await?[0].address();
+
+    let amount = 1_000_000;
+    let tx = account_0
+        .send_amount([SendAmountParams::new(recipient_address, amount)?], None)
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let sync_options = SyncOptions {
+        sync_incoming_transactions: true,
+        ..Default::default()
+    };
+    account_0.sync(Some(sync_options.clone())).await?;
+    account_1.sync(Some(sync_options)).await?;
+
+    let outgoing = account_0
+        .history(HistoryOptions {
+            kinds: Some([HistoryEntryKind::Outgoing].into()),
+            ..Default::default()
+        })
+        .await?;
+    assert_eq!(outgoing.total, 1);
+    let entry = &outgoing.entries[0];
+    assert_eq!(entry.transaction_id, tx.transaction_id);
+    assert_eq!(entry.counterparties, [recipient_address]);
+    assert!(entry.balance_delta[0].is_decrease());
+
+    let incoming = account_1.history(HistoryOptions::default()).await?;
+    assert_eq!(incoming.total, 1);
+    let entry = &incoming.entries[0];
+    assert_eq!(entry.kind, HistoryEntryKind::Incoming);
+    assert_eq!(entry.counterparties, [sender_address]);
+    assert_eq!(entry.balance_delta[0].created, amount.into());
+
+    // Pagination
+    let page = account_0
+        .history(HistoryOptions {
+            offset: 1,
+            limit: Some(1),
+            ..Default::default()
+        })
+        .await?;
+    assert_eq!(page.total, 1);
+    assert!(page.entries.is_empty());
+
+    tear_down(storage_path)
+}